@@ -0,0 +1,470 @@
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+    str::FromStr,
+};
+
+use clap::Args;
+use raphael_sim::{Action, Settings};
+use raphael_solver::{AtomicFlag, MacroSolver, SolverSettings};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    OutputFormat,
+    craft_args::{CraftArgs, CraftConfig, parse_consumable},
+    json::{JsonCraftOutput, JsonSolverStats, print_json_list},
+};
+
+#[derive(Copy, Clone, clap::ValueEnum, Debug, PartialEq, Eq)]
+pub enum InputFormat {
+    /// Comma-separated values with a header row containing the job field names
+    Csv,
+    /// One JSON object per line
+    Jsonl,
+}
+
+#[derive(Args, Debug)]
+pub struct BatchArgs {
+    /// Path to the job file. Each job has the fields `id`, `recipe_id`, `item_id`, `craftsmanship`, `control`, `cp`, `level`, `food`, `potion`, `manipulation`, `heart_and_soul`, `quick_innovation`, `stellar_steady_hand`, `initial_quality`, `hq_ingredients`, `target_quality`, `adversarial` and `backload_progress`. All fields except `craftsmanship`, `control`, `cp` and one of `recipe_id` or `item_id` are optional
+    ///
+    /// `food` and `potion` use the format '<ITEM_ID>[,HQ]'. In CSV files, `hq_ingredients` is a space-separated list of amounts
+    #[arg(value_name = "PATH")]
+    pub input: PathBuf,
+
+    /// Format of the job file. Detected from the file extension if not provided
+    #[arg(long, value_enum, ignore_case = true)]
+    pub input_format: Option<InputFormat>,
+
+    /// Maximum number of threads used to solve jobs
+    #[arg(long)]
+    pub threads: Option<usize>,
+
+    /// Output format. `text` outputs one CSV row per job
+    #[arg(long, value_enum, ignore_case = true, default_value_t = OutputFormat::Ndjson)]
+    pub format: OutputFormat,
+
+    /// The delimiter the text output uses between fields
+    #[arg(long, alias = "OFS", default_value = ",", env = "OFS")]
+    output_field_separator: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BatchJob {
+    pub id: Option<String>,
+    pub recipe_id: Option<u32>,
+    pub item_id: Option<u32>,
+    pub craftsmanship: Option<u16>,
+    pub control: Option<u16>,
+    pub cp: Option<u16>,
+    pub level: u8,
+    pub food: Option<String>,
+    pub potion: Option<String>,
+    pub manipulation: bool,
+    pub heart_and_soul: bool,
+    pub quick_innovation: bool,
+    pub stellar_steady_hand: Option<u8>,
+    pub initial_quality: Option<u16>,
+    pub hq_ingredients: Option<Vec<u8>>,
+    pub target_quality: Option<u16>,
+    pub adversarial: bool,
+    pub backload_progress: bool,
+}
+
+impl Default for BatchJob {
+    fn default() -> Self {
+        Self {
+            id: None,
+            recipe_id: None,
+            item_id: None,
+            craftsmanship: None,
+            control: None,
+            cp: None,
+            level: 100,
+            food: None,
+            potion: None,
+            manipulation: false,
+            heart_and_soul: false,
+            quick_innovation: false,
+            stellar_steady_hand: None,
+            initial_quality: None,
+            hq_ingredients: None,
+            target_quality: None,
+            adversarial: false,
+            backload_progress: false,
+        }
+    }
+}
+
+/// Result of a single job. Exactly one of `error` and `result` is present.
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchResult {
+    /// One-based line number of the job in the input file.
+    pub line: usize,
+    pub id: Option<String>,
+    /// Either `ok` or `error`.
+    pub status: &'static str,
+    pub error: Option<String>,
+    pub result: Option<JsonCraftOutput>,
+}
+
+fn parse_field<T: FromStr>(column: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Invalid value for `{column}`: {value}"))
+}
+
+fn parse_bool_field(column: &str, value: &str) -> Result<bool, String> {
+    match value.to_ascii_lowercase().as_str() {
+        "1" | "true" | "yes" => Ok(true),
+        "0" | "false" | "no" => Ok(false),
+        _ => Err(format!("Invalid value for `{column}`: {value}")),
+    }
+}
+
+/// Splits a CSV line into fields. Fields may be wrapped in double quotes, in which case they can contain commas and escaped (doubled) quotes.
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => fields.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+fn parse_csv_job(header: &[String], line: &str) -> Result<BatchJob, String> {
+    let fields = split_csv_line(line);
+    if fields.len() != header.len() {
+        return Err(format!(
+            "Expected {} fields, found {}",
+            header.len(),
+            fields.len()
+        ));
+    }
+    let mut job = BatchJob::default();
+    for (column, value) in header.iter().zip(fields) {
+        let value = value.trim();
+        if value.is_empty() {
+            continue;
+        }
+        match column.as_str() {
+            "id" => job.id = Some(value.to_owned()),
+            "recipe_id" => job.recipe_id = Some(parse_field(column, value)?),
+            "item_id" => job.item_id = Some(parse_field(column, value)?),
+            "craftsmanship" => job.craftsmanship = Some(parse_field(column, value)?),
+            "control" => job.control = Some(parse_field(column, value)?),
+            "cp" => job.cp = Some(parse_field(column, value)?),
+            "level" => job.level = parse_field(column, value)?,
+            "food" => job.food = Some(value.to_owned()),
+            "potion" => job.potion = Some(value.to_owned()),
+            "manipulation" => job.manipulation = parse_bool_field(column, value)?,
+            "heart_and_soul" => job.heart_and_soul = parse_bool_field(column, value)?,
+            "quick_innovation" => job.quick_innovation = parse_bool_field(column, value)?,
+            "stellar_steady_hand" => job.stellar_steady_hand = Some(parse_field(column, value)?),
+            "initial_quality" => job.initial_quality = Some(parse_field(column, value)?),
+            "hq_ingredients" => {
+                job.hq_ingredients = Some(
+                    value
+                        .split_whitespace()
+                        .map(|amount| parse_field(column, amount))
+                        .collect::<Result<_, _>>()?,
+                );
+            }
+            "target_quality" => job.target_quality = Some(parse_field(column, value)?),
+            "adversarial" => job.adversarial = parse_bool_field(column, value)?,
+            "backload_progress" => job.backload_progress = parse_bool_field(column, value)?,
+            _ => return Err(format!("Unknown column: {column}")),
+        }
+    }
+    Ok(job)
+}
+
+/// Reads all jobs from the input. Each job is paired with its line number.
+fn read_jobs(text: &str, format: InputFormat) -> Vec<(usize, Result<BatchJob, String>)> {
+    let mut lines = text
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line))
+        .filter(|(_, line)| !line.trim().is_empty());
+    match format {
+        InputFormat::Jsonl => lines
+            .map(|(line_number, line)| {
+                let job = serde_json::from_str(line).map_err(|err| err.to_string());
+                (line_number, job)
+            })
+            .collect(),
+        InputFormat::Csv => {
+            let Some((_, header)) = lines.next() else {
+                return Vec::new();
+            };
+            let header: Vec<String> = split_csv_line(header)
+                .into_iter()
+                .map(|column| column.trim().to_owned())
+                .collect();
+            lines
+                .map(|(line_number, line)| (line_number, parse_csv_job(&header, line)))
+                .collect()
+        }
+    }
+}
+
+impl BatchJob {
+    pub fn craft_config(&self) -> Result<CraftConfig, String> {
+        let required = |field: &str, value: Option<u16>| {
+            value.ok_or_else(|| format!("Missing field `{field}`"))
+        };
+        CraftArgs {
+            recipe_id: self.recipe_id,
+            item_id: self.item_id,
+            custom_recipe: Vec::new(),
+            override_base_increases: Vec::new(),
+            craftsmanship: Some(required("craftsmanship", self.craftsmanship)?),
+            control: Some(required("control", self.control)?),
+            cp: Some(required("cp", self.cp)?),
+            stats: Vec::new(),
+            gearset: None,
+            level: self.level,
+            food: self.food.as_deref().map(parse_consumable).transpose()?,
+            potion: self.potion.as_deref().map(parse_consumable).transpose()?,
+            manipulation: self.manipulation,
+            heart_and_soul: self.heart_and_soul,
+            quick_innovation: self.quick_innovation,
+            stellar_steady_hand: self.stellar_steady_hand,
+            initial_quality: self.initial_quality,
+            hq_ingredients: self.hq_ingredients.clone(),
+            skip_map_and_clamp_hq_ingredients: false,
+        }
+        .craft_config()
+    }
+}
+
+/// A job with the settings the solver is run with.
+struct PreparedJob {
+    config: CraftConfig,
+    /// Simulator settings with `max_quality` adjusted to the target quality.
+    settings: Settings,
+    target_quality: u16,
+}
+
+impl PreparedJob {
+    fn new(job: &BatchJob) -> Result<Self, String> {
+        let config = job.craft_config()?;
+        let mut settings = config.settings;
+        settings.adversarial = job.adversarial;
+        settings.backload_progress = job.backload_progress;
+        let target_quality = match job.target_quality {
+            Some(target) => target.clamp(0, settings.max_quality),
+            None => settings.max_quality,
+        };
+        settings.max_quality = target_quality.saturating_sub(config.initial_quality);
+        Ok(Self {
+            config,
+            settings,
+            target_quality,
+        })
+    }
+
+    fn output(&self, actions: &[Action], solver_stats: JsonSolverStats) -> JsonCraftOutput {
+        JsonCraftOutput::new(
+            &self.config,
+            &self.settings,
+            actions,
+            Some(self.target_quality),
+            Some(solver_stats),
+        )
+    }
+}
+
+/// Solved actions and the runtime stats of the solver.
+type Solution = (Vec<Action>, JsonSolverStats);
+
+/// Solves the given simulator settings. `progress_callback` is called with the number of processed nodes.
+fn solve_settings(
+    settings: Settings,
+    progress_callback: Box<dyn Fn(usize) + '_>,
+    interrupt_signal: AtomicFlag,
+) -> Result<Solution, String> {
+    let solver_settings = SolverSettings {
+        simulator_settings: settings,
+        allow_non_max_quality_solutions: true,
+    };
+    let mut solver = MacroSolver::new(
+        solver_settings,
+        Box::new(|_| {}),
+        progress_callback,
+        interrupt_signal,
+    );
+    let actions = solver
+        .solve()
+        .map_err(|exception| format!("{exception:?}"))?;
+    Ok((actions, JsonSolverStats::new(&solver)))
+}
+
+/// Solves a single job. `progress_callback` is called with the number of processed nodes.
+pub fn solve_job(
+    job: &BatchJob,
+    progress_callback: Box<dyn Fn(usize) + '_>,
+    interrupt_signal: AtomicFlag,
+) -> Result<JsonCraftOutput, String> {
+    let job = PreparedJob::new(job)?;
+    let (actions, solver_stats) =
+        solve_settings(job.settings, progress_callback, interrupt_signal)?;
+    Ok(job.output(&actions, solver_stats))
+}
+
+fn print_text_results(results: &[BatchResult], separator: &str) {
+    let quote = |field: String| match field.contains(separator) || field.contains('"') {
+        true => format!("\"{}\"", field.replace('"', "\"\"")),
+        false => field,
+    };
+    println!(
+        "{}",
+        [
+            "line",
+            "id",
+            "status",
+            "error",
+            "recipe_id",
+            "initial_quality",
+            "final_quality",
+            "target_quality",
+            "steps",
+            "duration",
+            "actions",
+            "action_ids",
+        ]
+        .join(separator)
+    );
+    for result in results {
+        let mut fields = vec![
+            result.line.to_string(),
+            result.id.clone().unwrap_or_default(),
+            result.status.to_owned(),
+            result.error.clone().unwrap_or_default(),
+        ];
+        match &result.result {
+            Some(output) => fields.extend([
+                output.recipe.recipe_id.to_string(),
+                output.initial_quality.to_string(),
+                output.final_state.quality.to_string(),
+                output.target_quality.unwrap_or_default().to_string(),
+                output.actions.len().to_string(),
+                output.duration.to_string(),
+                output
+                    .actions
+                    .iter()
+                    .map(|action| action.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(" "),
+                output
+                    .actions
+                    .iter()
+                    .map(|action| action.id.to_string())
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            ]),
+            None => fields.extend(std::iter::repeat_n(String::new(), 8)),
+        }
+        let fields: Vec<String> = fields.into_iter().map(quote).collect();
+        println!("{}", fields.join(separator));
+    }
+}
+
+pub fn execute(args: &BatchArgs) {
+    if let Some(threads) = args.threads {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build_global()
+            .unwrap();
+    }
+
+    let input_format = args.input_format.unwrap_or_else(|| {
+        match args
+            .input
+            .extension()
+            .and_then(|extension| extension.to_str())
+        {
+            Some("csv") => InputFormat::Csv,
+            Some("jsonl" | "ndjson" | "json") => InputFormat::Jsonl,
+            _ => panic!(
+                "Unable to detect the format of {}, use `--input-format` to specify it",
+                args.input.display()
+            ),
+        }
+    });
+    let text = std::fs::read_to_string(&args.input)
+        .unwrap_or_else(|err| panic!("Unable to read {}: {err}", args.input.display()));
+    let jobs: Vec<(usize, Option<String>, Result<PreparedJob, String>)> =
+        read_jobs(&text, input_format)
+            .into_iter()
+            .map(|(line, job)| {
+                let id = job.as_ref().ok().and_then(|job| job.id.clone());
+                (line, id, job.and_then(|job| PreparedJob::new(&job)))
+            })
+            .collect();
+
+    // The solution only depends on the settings, so jobs with identical settings are solved once.
+    let distinct_settings: HashSet<Settings> = jobs
+        .iter()
+        .filter_map(|(_, _, job)| job.as_ref().ok())
+        .map(|job| job.settings)
+        .collect();
+    log::info!(
+        "Solving {} distinct settings for {} jobs",
+        distinct_settings.len(),
+        jobs.len()
+    );
+    let solutions: HashMap<Settings, Result<Solution, String>> = distinct_settings
+        .into_par_iter()
+        .map(|settings| {
+            let solution = solve_settings(settings, Box::new(|_| {}), AtomicFlag::new());
+            (settings, solution)
+        })
+        .collect();
+
+    let results: Vec<BatchResult> = jobs
+        .into_iter()
+        .map(|(line, id, job)| {
+            let output = job.and_then(|job| {
+                let (actions, solver_stats) = solutions[&job.settings].clone()?;
+                Ok(job.output(&actions, solver_stats))
+            });
+            match output {
+                Ok(output) => BatchResult {
+                    line,
+                    id,
+                    status: "ok",
+                    error: None,
+                    result: Some(output),
+                },
+                Err(error) => {
+                    log::error!("Line {line}: {error}");
+                    BatchResult {
+                        line,
+                        id,
+                        status: "error",
+                        error: Some(error),
+                        result: None,
+                    }
+                }
+            }
+        })
+        .collect();
+
+    match args.format {
+        OutputFormat::Text => print_text_results(&results, &args.output_field_separator),
+        OutputFormat::Json | OutputFormat::Ndjson => print_json_list(&results, args.format),
+    }
+}
@@ -0,0 +1,226 @@
+use std::path::PathBuf;
+
+use clap::Args;
+use raphael_sim::{Action, ActionError, Settings, SimulationState};
+
+use super::{
+    OutputFormat,
+    craft_args::CraftArgs,
+    json::{JsonComparison, print_json},
+    simulate::{final_state, parse_actions, read_macro_file, simulate_steps, total_quality},
+};
+
+#[derive(Args, Debug)]
+pub struct CompareArgs {
+    #[command(flatten)]
+    pub craft_args: CraftArgs,
+
+    /// Comma-separated list of actions of a rotation. Can be provided multiple times to compare multiple rotations
+    #[arg(short, long, value_name = "ACTIONS")]
+    pub actions: Vec<String>,
+
+    /// Path to a text file containing an in-game macro. Can be provided multiple times to compare multiple rotations
+    #[arg(long, value_name = "PATH")]
+    pub macro_file: Vec<PathBuf>,
+
+    /// Enable adversarial simulator (quality is calculated assuming the worst possible sequence of conditions)
+    #[arg(long, default_value_t = false)]
+    pub adversarial: bool,
+
+    /// The delimiter used to separate the fields of the output
+    #[arg(long, alias = "OFS", default_value = "\t", env = "OFS")]
+    output_field_separator: String,
+
+    /// Output format
+    #[arg(long, value_enum, ignore_case = true, default_value_t = OutputFormat::Text)]
+    pub format: OutputFormat,
+}
+
+/// A rotation that is compared with other rotations for the same settings.
+#[derive(Debug, Clone)]
+pub struct ComparedRotation {
+    /// `actions <N>` for rotations from `--actions`, or the path of the macro file.
+    pub name: String,
+    pub actions: Vec<Action>,
+    /// State after each action and whether the action could be used.
+    /// Actions that cannot be used leave the state unchanged.
+    pub steps: Vec<(SimulationState, Result<(), ActionError>)>,
+    pub final_state: SimulationState,
+}
+
+impl ComparedRotation {
+    pub fn new(name: String, actions: Vec<Action>, settings: &Settings) -> Self {
+        let steps = simulate_steps(settings, &actions);
+        let final_state = final_state(settings, &steps);
+        Self {
+            name,
+            actions,
+            steps,
+            final_state,
+        }
+    }
+
+    pub fn duration(&self) -> u32 {
+        self.actions
+            .iter()
+            .map(|action| u32::from(action.time_cost()))
+            .sum()
+    }
+
+    pub fn error_count(&self) -> usize {
+        self.steps
+            .iter()
+            .filter(|(_, result)| result.is_err())
+            .count()
+    }
+
+    /// State after the given zero-based step. Steps after the last action keep the final state.
+    pub fn state_after(&self, step_index: usize) -> SimulationState {
+        self.steps
+            .get(step_index)
+            .map_or(self.final_state, |(state, _)| *state)
+    }
+}
+
+/// Zero-based index of the first step after which the states of the rotations differ.
+/// Rotations of different lengths always differ after the last step of the shortest rotation.
+pub fn first_difference(rotations: &[ComparedRotation]) -> Option<usize> {
+    let max_steps = rotations
+        .iter()
+        .map(|rotation| rotation.actions.len())
+        .max()?;
+    (0..max_steps).find(|step_index| {
+        let differs_from_first = |rotation: &ComparedRotation| {
+            rotation.actions.len() <= *step_index
+                || rotation.state_after(*step_index) != rotations[0].state_after(*step_index)
+        };
+        rotations.iter().any(differs_from_first)
+    })
+}
+
+fn read_rotations(
+    args: &CompareArgs,
+    settings: &Settings,
+) -> Result<Vec<ComparedRotation>, String> {
+    let mut rotations = Vec::new();
+    for (index, actions) in args.actions.iter().enumerate() {
+        let actions: Vec<&str> = actions.split(',').collect();
+        rotations.push(ComparedRotation::new(
+            format!("actions {}", index + 1),
+            parse_actions(&actions)?,
+            settings,
+        ));
+    }
+    for path in &args.macro_file {
+        rotations.push(ComparedRotation::new(
+            path.display().to_string(),
+            read_macro_file(path)?,
+            settings,
+        ));
+    }
+    if rotations.len() < 2 {
+        return Err(
+            "At least two rotations must be provided with '--actions' or '--macro-file'".to_owned(),
+        );
+    }
+    Ok(rotations)
+}
+
+pub fn execute(args: &CompareArgs) {
+    let config = args
+        .craft_args
+        .craft_config()
+        .unwrap_or_else(|err| panic!("{err}"));
+    let mut settings = config.settings;
+    settings.adversarial = args.adversarial;
+    let initial_quality = config.initial_quality;
+
+    let rotations = read_rotations(args, &settings).unwrap_or_else(|err| panic!("{err}"));
+    let first_difference = first_difference(&rotations);
+
+    if args.format != OutputFormat::Text {
+        let output = JsonComparison::new(&config, &settings, &rotations, first_difference);
+        print_json(&output, args.format);
+        return;
+    }
+
+    let separator = &args.output_field_separator;
+    println!(
+        "{}",
+        [
+            "Rotation",
+            "Progress",
+            "Quality",
+            "Durability",
+            "CP",
+            "Steps",
+            "Duration",
+            "Errors"
+        ]
+        .join(separator)
+    );
+    for rotation in &rotations {
+        let final_state = &rotation.final_state;
+        println!(
+            "{}",
+            [
+                rotation.name.clone(),
+                format!("{}/{}", final_state.progress, settings.max_progress),
+                format!(
+                    "{}/{}",
+                    total_quality(final_state, initial_quality, settings.max_quality),
+                    settings.max_quality
+                ),
+                format!("{}/{}", final_state.durability, settings.max_durability),
+                format!("{}/{}", final_state.cp, settings.max_cp),
+                rotation.actions.len().to_string(),
+                rotation.duration().to_string(),
+                rotation.error_count().to_string(),
+            ]
+            .join(separator)
+        );
+    }
+
+    let Some(first_difference) = first_difference else {
+        println!();
+        println!("The rotations are identical");
+        return;
+    };
+    println!();
+    println!(
+        "The rotations differ starting at step {}",
+        first_difference + 1
+    );
+    // Each rotation is shown as `<ACTION> <PROGRESS>/<QUALITY>/<DURABILITY>/<CP>` after each step.
+    let mut header = vec!["Step".to_owned()];
+    header.extend(rotations.iter().map(|rotation| rotation.name.clone()));
+    println!("{}", header.join(separator));
+    let max_steps = rotations
+        .iter()
+        .map(|rotation| rotation.actions.len())
+        .max()
+        .unwrap_or_default();
+    for step_index in first_difference..max_steps {
+        let mut row = vec![(step_index + 1).to_string()];
+        for rotation in &rotations {
+            row.push(match rotation.actions.get(step_index) {
+                Some(action) => {
+                    let (state, result) = rotation.steps[step_index];
+                    let error = match result {
+                        Ok(()) => String::new(),
+                        Err(err) => format!(" ({err:?})"),
+                    };
+                    format!(
+                        "{action:?} {}/{}/{}/{}{error}",
+                        state.progress,
+                        total_quality(&state, initial_quality, settings.max_quality),
+                        state.durability,
+                        state.cp
+                    )
+                }
+                None => "-".to_owned(),
+            });
+        }
+        println!("{}", row.join(separator));
+    }
+}
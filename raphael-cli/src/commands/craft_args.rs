@@ -0,0 +1,352 @@
+use std::path::{Path, PathBuf};
+
+use clap::Args;
+use raphael_data::{
+    Consumable, CrafterStats, CustomRecipeOverrides, Gearset, Recipe, game_data, get_game_settings,
+    get_recipe,
+};
+use raphael_sim::Settings;
+
+/// Recipe, crafter and consumable arguments shared by all commands that need game settings.
+#[derive(Args, Debug)]
+pub struct CraftArgs {
+    /// Recipe ID
+    #[arg(short, long, /*required_unless_present_any(["item_id", "custom_recipe"]),*/ conflicts_with_all(["item_id", "custom_recipe"]))]
+    pub recipe_id: Option<u32>,
+
+    /// Item ID, in case multiple recipes for the same item exist, the one with the lowest recipe ID is selected
+    #[arg(short, long, /*required_unless_present_any(["recipe_id, custom_recipe"]),*/ conflicts_with = "custom_recipe")]
+    pub item_id: Option<u32>,
+
+    /// Custom recipe. <EXPERT> is optional and must be >0 if the custom recipe is expert, if 0 or not provided, the recipe is assumed to not be an expert recipe
+    #[arg(long, num_args = 4..=5, value_names = ["RLVL", "PROGRESS", "QUALITY", "DURABILITY", "EXPERT"], /*required_unless_present_any(["recipe_id", "item_id"])*/)]
+    pub custom_recipe: Vec<u16>,
+
+    /// Overrides base progress/quality, i.e. "progress/quality per 100% efficiency". rlvl, crafstamnship, and control are ignored if this argument is provided
+    #[arg(long, num_args = 3, value_names = ["LEVEL", "BASE_PROGRESS", "BASE_QUALITY"], requires = "custom_recipe")]
+    pub override_base_increases: Vec<u16>,
+
+    /// Craftsmanship rating
+    #[arg(short, long, requires_all(["control", "cp"]), required_unless_present_any(["stats", "gearset"]))]
+    pub craftsmanship: Option<u16>,
+
+    /// Control rating
+    #[arg(short = 'o', long, requires_all(["craftsmanship", "cp"]), required_unless_present_any(["stats", "gearset"]))]
+    pub control: Option<u16>,
+
+    /// Crafting points
+    #[arg(short = 'p', long, requires_all(["craftsmanship", "control"]), required_unless_present_any(["stats", "gearset"]))]
+    pub cp: Option<u16>,
+
+    /// Complete stats, conflicts with setting one or more of the stats separately
+    #[arg(short, long, num_args = 3, value_names = ["CRAFTSMANSHIP", "CONTROL", "CP"], required_unless_present_any(["craftsmanship", "gearset"]), conflicts_with_all(["craftsmanship", "control", "cp"]))]
+    pub stats: Vec<u16>,
+
+    /// Path to a JSON gear set file with the fields `base_stats` (stats without gear) and `pieces` (equipped items, each with `item_id`, `hq` and the item IDs of the melded `materia`). Craftsmanship, control and CP are calculated from the gear set
+    #[arg(long, value_name = "PATH", conflicts_with_all(["craftsmanship", "control", "cp", "stats"]))]
+    pub gearset: Option<PathBuf>,
+
+    /// Crafter level
+    #[arg(short, long, default_value_t = 100)]
+    pub level: u8,
+
+    /// Food to use, in the format '<ITEM_ID>[,HQ]'
+    #[arg(long, value_parser = parse_consumable)]
+    pub food: Option<ConsumableArg>,
+
+    /// Potion to use, in the format '<ITEM_ID>[,HQ]'
+    #[arg(long, value_parser = parse_consumable)]
+    pub potion: Option<ConsumableArg>,
+
+    /// Enable Manipulation
+    #[arg(short, long, default_value_t = false)]
+    pub manipulation: bool,
+
+    /// Enable Heart and Soul
+    #[arg(long, default_value_t = false)]
+    pub heart_and_soul: bool,
+
+    /// Enable Quick Innovation
+    #[arg(long, default_value_t = false)]
+    pub quick_innovation: bool,
+
+    /// Set the maximum number of Stellar Steady Hand uses
+    #[arg(long)]
+    pub stellar_steady_hand: Option<u8>,
+
+    /// Set initial quality, value is clamped to 100% quality
+    #[arg(long, alias = "initial")]
+    pub initial_quality: Option<u16>,
+
+    /// Set HQ ingredient amounts and calculate initial quality from them
+    #[arg(long, num_args = 1..=6, value_name = "AMOUNT", conflicts_with_all = ["initial_quality", "custom_recipe"])]
+    pub hq_ingredients: Option<Vec<u8>>,
+
+    /// Skip mapping HQ ingredients to entries that can actually be HQ and clamping the amount to the max allowed for the recipe
+    #[arg(long, default_value_t = false, requires = "hq_ingredients")]
+    pub skip_map_and_clamp_hq_ingredients: bool,
+}
+
+/// Everything derived from [`CraftArgs`] that is needed to simulate or solve a craft.
+#[derive(Debug, Clone, Copy)]
+pub struct CraftConfig {
+    pub recipe_id: u32,
+    pub recipe: Recipe,
+    pub food: Option<Consumable>,
+    pub potion: Option<Consumable>,
+    pub crafter_stats: CrafterStats,
+    pub settings: Settings,
+    pub initial_quality: u16,
+}
+
+pub fn parse_consumable(s: &str) -> Result<ConsumableArg, String> {
+    const PARSE_ERROR_STRING: &str =
+        "Consumable is not parsable. Consumables must have the format '<ITEM_ID>[,HQ]'";
+    let segments: Vec<&str> = s.split(",").collect();
+    let item_id_str = segments.first();
+    let item_id = match item_id_str {
+        Some(&str) => str.parse().map_err(|_| PARSE_ERROR_STRING.to_owned())?,
+        None => return Err(PARSE_ERROR_STRING.to_owned()),
+    };
+    match segments.len() {
+        1 => Ok(ConsumableArg::NQ(item_id)),
+        2 => {
+            let hq_str = segments.get(1).unwrap().to_owned();
+            match hq_str {
+                "HQ" => Ok(ConsumableArg::HQ(item_id)),
+                _ => Err(PARSE_ERROR_STRING.to_owned()),
+            }
+        }
+        _ => Err(PARSE_ERROR_STRING.to_owned()),
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum ConsumableArg {
+    /// NQ Consumable
+    NQ(u32),
+    /// HQ Consumable
+    HQ(u32),
+}
+
+impl ConsumableArg {
+    fn find_in(self, consumables: &[Consumable]) -> Option<Consumable> {
+        let (item_id, is_hq) = match self {
+            Self::NQ(id) => (id, false),
+            Self::HQ(id) => (id, true),
+        };
+        consumables
+            .iter()
+            .find(|m| (m.item_id == item_id) && (m.hq == is_hq))
+            .copied()
+    }
+
+    fn item_id(self) -> u32 {
+        match self {
+            Self::NQ(id) | Self::HQ(id) => id,
+        }
+    }
+}
+
+fn map_and_clamp_hq_ingredients(
+    recipe: &raphael_data::Recipe,
+    hq_ingredients: [u8; 6],
+) -> Result<[u8; 6], String> {
+    let ingredients: Vec<(raphael_data::Item, u32)> = recipe
+        .ingredients
+        .iter()
+        .filter(|ingredient| ingredient.item_id != 0)
+        .map(|ingredient| {
+            let item = game_data().item(ingredient.item_id).ok_or_else(|| {
+                format!(
+                    "Unable to find ingredient with item ID: {}",
+                    ingredient.item_id
+                )
+            })?;
+            Ok((*item, ingredient.amount))
+        })
+        .collect::<Result<_, String>>()?;
+
+    let mut modified_hq_ingredients: [u8; 6] = [0; 6];
+    let mut hq_ingredient_index: usize = 0;
+    for (index, (_item_id, max_amount)) in ingredients.into_iter().enumerate() {
+        modified_hq_ingredients[index] =
+            hq_ingredients[hq_ingredient_index].clamp(0, max_amount as u8);
+        hq_ingredient_index = hq_ingredient_index.saturating_add(1);
+    }
+
+    Ok(modified_hq_ingredients)
+}
+
+pub fn read_gearset(path: &Path) -> Result<Gearset, String> {
+    let file = std::fs::File::open(path)
+        .map_err(|error| format!("Unable to read gear set {}: {error}", path.display()))?;
+    serde_json::from_reader(std::io::BufReader::new(file))
+        .map_err(|error| format!("Unable to parse gear set {}: {error}", path.display()))
+}
+
+/// HQ ingredient amounts with every ingredient of the recipe HQ.
+pub fn max_hq_ingredients(recipe: &Recipe) -> [u8; 6] {
+    let mut hq_ingredients = [0; 6];
+    for (index, ingredient) in recipe
+        .ingredients
+        .iter()
+        .filter(|ingredient| ingredient.item_id != 0)
+        .enumerate()
+    {
+        hq_ingredients[index] = ingredient.amount as u8;
+    }
+    hq_ingredients
+}
+
+impl CraftArgs {
+    /// Resolves the recipe, consumables and game settings described by the arguments.
+    pub fn craft_config(&self) -> Result<CraftConfig, String> {
+        if self.recipe_id.is_none() && self.item_id.is_none() && self.custom_recipe.is_empty() {
+            return Err(
+                "One of the arguments '--recipe-id', '--item-id', or '--custom-recipe' must be provided"
+                    .to_owned(),
+            );
+        }
+
+        let use_custom_recipe = !self.custom_recipe.is_empty();
+        let mut recipe: raphael_data::Recipe = if use_custom_recipe {
+            raphael_data::Recipe {
+                job_id: 0,
+                item_id: 0,
+                max_level_scaling: 0,
+                recipe_level: self.custom_recipe[0],
+                progress_factor: 0,
+                quality_factor: 0,
+                durability_factor: 0,
+                material_factor: 0,
+                ingredients: Default::default(),
+                is_expert: match self.custom_recipe.get(4) {
+                    Some(value) => *value != 0,
+                    None => false,
+                },
+                req_craftsmanship: 0,
+                req_control: 0,
+            }
+        } else if let Some(recipe_id) = self.recipe_id {
+            *get_recipe(recipe_id)
+                .ok_or_else(|| format!("Unable to find Recipe with ID: {}", recipe_id))?
+        } else {
+            let item_id = self.item_id.unwrap();
+            log::warn!(
+                "Item IDs do not uniquely corresponds to a specific recipe config. Consider using the recipe ID instead.\nThe first match, i.e. the recipe with the lowest ID, will be selected."
+            );
+            *game_data()
+                .recipes()
+                .map(|(_, recipe)| recipe)
+                .find(|recipe| recipe.item_id == item_id)
+                .ok_or_else(|| {
+                    format!(
+                        "Unable to find Recipe for an item with item ID: {}",
+                        item_id
+                    )
+                })?
+        };
+        let recipe_id = game_data()
+            .recipes()
+            .find(|(_, entry_recipe)| **entry_recipe == recipe)
+            .map(|(recipe_id, _)| recipe_id)
+            .unwrap_or_default();
+        let food = self
+            .food
+            .map(|food_arg| {
+                food_arg.find_in(game_data().meals()).ok_or_else(|| {
+                    format!("Unable to find Food with item ID: {}", food_arg.item_id())
+                })
+            })
+            .transpose()?;
+        let potion = self
+            .potion
+            .map(|potion_arg| {
+                potion_arg.find_in(game_data().potions()).ok_or_else(|| {
+                    format!(
+                        "Unable to find Potion with item ID: {}",
+                        potion_arg.item_id()
+                    )
+                })
+            })
+            .transpose()?;
+
+        let crafter_stats = CrafterStats {
+            craftsmanship: self
+                .craftsmanship
+                .or(self.stats.first().copied())
+                .unwrap_or_default(),
+            control: self
+                .control
+                .or(self.stats.get(1).copied())
+                .unwrap_or_default(),
+            cp: self.cp.or(self.stats.get(2).copied()).unwrap_or_default(),
+            level: self.level,
+            manipulation: self.manipulation,
+            heart_and_soul: self.heart_and_soul,
+            quick_innovation: self.quick_innovation,
+        };
+        let crafter_stats = match &self.gearset {
+            Some(path) => read_gearset(path)?
+                .crafter_stats(crafter_stats)
+                .map_err(|error| format!("Invalid gear set {}: {error}", path.display()))?,
+            None => crafter_stats,
+        };
+
+        let custom_recipe_overrides = if !use_custom_recipe {
+            None
+        } else if self.override_base_increases.is_empty() {
+            Some(CustomRecipeOverrides {
+                max_progress_override: self.custom_recipe[1],
+                max_quality_override: self.custom_recipe[2],
+                max_durability_override: self.custom_recipe[3],
+                ..Default::default()
+            })
+        } else {
+            recipe.recipe_level =
+                game_data().level_adjust_table()[self.override_base_increases[0] as usize];
+            Some(CustomRecipeOverrides {
+                max_progress_override: self.custom_recipe[1],
+                max_quality_override: self.custom_recipe[2],
+                max_durability_override: self.custom_recipe[3],
+                base_progress_override: Some(self.override_base_increases[1]),
+                base_quality_override: Some(self.override_base_increases[2]),
+            })
+        };
+        let mut settings =
+            get_game_settings(recipe, custom_recipe_overrides, crafter_stats, food, potion);
+        if let Some(stellar_steady_hand) = self.stellar_steady_hand {
+            settings.stellar_steady_hand_charges = stellar_steady_hand.min(3);
+        }
+
+        let initial_quality = match self.initial_quality {
+            Some(initial) => initial.clamp(0, settings.max_quality),
+            None => match self.hq_ingredients.clone() {
+                Some(mut hq_ingredients) => {
+                    hq_ingredients.resize(6, 0);
+                    let amount_array = hq_ingredients.try_into().unwrap();
+                    raphael_data::get_initial_quality(
+                        crafter_stats,
+                        recipe,
+                        match self.skip_map_and_clamp_hq_ingredients {
+                            true => amount_array,
+                            false => map_and_clamp_hq_ingredients(&recipe, amount_array)?,
+                        },
+                    )
+                }
+                None => 0,
+            },
+        };
+
+        Ok(CraftConfig {
+            recipe_id,
+            recipe,
+            food,
+            potion,
+            crafter_stats,
+            settings,
+            initial_quality,
+        })
+    }
+}
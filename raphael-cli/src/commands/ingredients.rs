@@ -1,7 +1,12 @@
 use clap::Args;
-use raphael_data::{RECIPES, get_job_name, get_raw_item_name};
+use raphael_data::{
+    CraftingTreeNode, Locale, get_crafting_tree, get_job_name, get_raw_item_name, get_recipe,
+};
 
-use crate::commands::Language;
+use crate::commands::{
+    Language, OutputFormat,
+    json::{JsonIngredients, print_json},
+};
 
 #[derive(Args, Debug)]
 pub struct IngredientsArgs {
@@ -16,13 +21,46 @@ pub struct IngredientsArgs {
     /// The delimiter the output uses between fields
     #[arg(long, alias = "OFS", default_value = " ", env = "OFS")]
     output_field_separator: String,
+
+    /// Output format
+    #[arg(long, value_enum, ignore_case = true, default_value_t = OutputFormat::Text)]
+    pub format: OutputFormat,
+
+    /// Print the full crafting tree, including the ingredients of intermediate items
+    #[arg(short, long, default_value_t = false)]
+    pub tree: bool,
+
+    /// Number of items to craft. Used to calculate the amounts in the crafting tree
+    #[arg(short, long, default_value_t = 1, requires = "tree")]
+    pub amount: u32,
+}
+
+fn print_tree_node(node: &CraftingTreeNode, depth: usize, separator: &str, locale: Locale) {
+    let name = get_raw_item_name(node.item_id, locale).unwrap_or("Unknown item");
+    let recipe = match (node.recipe_id, node.job_id) {
+        (Some(recipe_id), Some(job_id)) => format!(
+            "{separator}({job_name} recipe {recipe_id}, {crafts}x)",
+            job_name = get_job_name(job_id, locale),
+            crafts = node.crafts,
+        ),
+        _ => String::new(),
+    };
+    println!(
+        "{indent}{amount}{separator}{item_id}{separator}{name}{recipe}",
+        indent = "  ".repeat(depth),
+        amount = node.amount,
+        item_id = node.item_id,
+    );
+    for ingredient in &node.ingredients {
+        print_tree_node(ingredient, depth + 1, separator, locale);
+    }
 }
 
 pub fn execute(args: &IngredientsArgs) {
     let locale = args.language.into();
 
     // Get the recipe by ID
-    let recipe = match RECIPES.get(args.recipe_id) {
+    let recipe = match get_recipe(args.recipe_id) {
         Some(recipe) => recipe,
         None => {
             println!("Recipe with ID {} not found", args.recipe_id);
@@ -30,6 +68,23 @@ pub fn execute(args: &IngredientsArgs) {
         }
     };
 
+    let tree = args
+        .tree
+        .then(|| get_crafting_tree(args.recipe_id, args.amount).unwrap());
+
+    if args.format != OutputFormat::Text {
+        print_json(
+            &JsonIngredients::new(args.recipe_id, recipe, tree.as_ref(), locale),
+            args.format,
+        );
+        return;
+    }
+
+    if let Some(tree) = &tree {
+        print_tree_node(tree, 0, &args.output_field_separator, locale);
+        return;
+    }
+
     // Get the recipe item name
     let recipe_name = get_raw_item_name(recipe.item_id, locale).unwrap_or("Unknown item");
     let job_name = get_job_name(recipe.job_id, locale);
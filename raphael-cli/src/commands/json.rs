@@ -0,0 +1,714 @@
+//! JSON output of the CLI commands.
+//!
+//! All commands accept `--format json` (pretty-printed) or `--format ndjson` (one compact JSON value per line).
+//! Commands that output a list (e.g. `search`) print a JSON array with `json` and one element per line with `ndjson`.
+//!
+//! The structs in this module define the output schema. Fields are only ever added, never renamed or removed,
+//! without bumping [`SCHEMA_VERSION`]. Quality values always include the initial quality and are capped at the max quality.
+
+use raphael_data::{
+    Consumable, CrafterStats, CraftingTreeNode, Gearset, Locale, Recipe, StellarMission,
+    get_job_name, get_raw_item_name, get_recipe, get_stellar_mission_name,
+};
+use raphael_sim::{Action, ActionError, Effects, Settings, SimulationState};
+use raphael_solver::MacroSolver;
+use serde::Serialize;
+use strum::IntoEnumIterator;
+
+use super::{
+    OutputFormat,
+    compare::ComparedRotation,
+    craft_args::CraftConfig,
+    meld::MeldResult,
+    plan::{CraftingPlan, PlannedCraft, ShoppingListItem},
+    rotations::SavedRotation,
+    simulate::{final_state, simulate_steps, total_quality},
+};
+
+pub const SCHEMA_VERSION: u32 = 1;
+
+pub fn print_json<T: Serialize>(value: &T, format: OutputFormat) {
+    let output = match format {
+        OutputFormat::Ndjson => serde_json::to_string(value),
+        OutputFormat::Json | OutputFormat::Text => serde_json::to_string_pretty(value),
+    };
+    println!("{}", output.expect("Failed to serialize output"));
+}
+
+pub fn print_json_list<T: Serialize>(values: &[T], format: OutputFormat) {
+    match format {
+        OutputFormat::Ndjson => {
+            for value in values {
+                print_json(value, format);
+            }
+        }
+        OutputFormat::Json | OutputFormat::Text => print_json(&values, format),
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonAction {
+    /// Name of the `Action` enum variant, e.g. `BasicSynthesis`.
+    pub name: String,
+    pub id: u32,
+}
+
+impl From<Action> for JsonAction {
+    fn from(action: Action) -> Self {
+        Self {
+            name: format!("{action:?}"),
+            id: action.action_id(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonIngredient {
+    pub item_id: u32,
+    pub item_name: Option<String>,
+    pub amount: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonRecipe {
+    /// `0` for custom recipes.
+    pub recipe_id: u32,
+    pub item_id: u32,
+    pub item_name: Option<String>,
+    pub job_id: u8,
+    pub job_name: String,
+    pub recipe_level: u16,
+    pub max_level_scaling: u8,
+    pub is_expert: bool,
+    pub req_craftsmanship: u16,
+    pub req_control: u16,
+    pub ingredients: Vec<JsonIngredient>,
+}
+
+impl JsonRecipe {
+    pub fn new(recipe_id: u32, recipe: &Recipe, locale: Locale) -> Self {
+        Self {
+            recipe_id,
+            item_id: recipe.item_id,
+            item_name: get_raw_item_name(recipe.item_id, locale).map(str::to_owned),
+            job_id: recipe.job_id,
+            job_name: get_job_name(recipe.job_id, locale).to_owned(),
+            recipe_level: recipe.recipe_level,
+            max_level_scaling: recipe.max_level_scaling,
+            is_expert: recipe.is_expert,
+            req_craftsmanship: recipe.req_craftsmanship,
+            req_control: recipe.req_control,
+            ingredients: recipe
+                .ingredients
+                .iter()
+                .filter(|ingredient| ingredient.item_id != 0)
+                .map(|ingredient| JsonIngredient {
+                    item_id: ingredient.item_id,
+                    item_name: get_raw_item_name(ingredient.item_id, locale).map(str::to_owned),
+                    amount: ingredient.amount,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Search result of the `search` commands with `--any-language`.
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonSearchMatch<T> {
+    #[serde(flatten)]
+    pub entry: T,
+    /// Short code of the language whose name matched the pattern, e.g. `JP`.
+    /// `null` if the entry was not found by the pattern.
+    pub matched_language: Option<&'static str>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonCraftingTreeNode {
+    pub item_id: u32,
+    pub item_name: Option<String>,
+    pub amount: u32,
+    /// `null` if the item cannot be crafted.
+    pub recipe_id: Option<u32>,
+    pub job_id: Option<u8>,
+    pub job_name: Option<String>,
+    pub crafts: u32,
+    pub ingredients: Vec<Self>,
+}
+
+impl JsonCraftingTreeNode {
+    pub fn new(node: &CraftingTreeNode, locale: Locale) -> Self {
+        Self {
+            item_id: node.item_id,
+            item_name: get_raw_item_name(node.item_id, locale).map(str::to_owned),
+            amount: node.amount,
+            recipe_id: node.recipe_id,
+            job_id: node.job_id,
+            job_name: node
+                .job_id
+                .map(|job_id| get_job_name(job_id, locale).to_owned()),
+            crafts: node.crafts,
+            ingredients: node
+                .ingredients
+                .iter()
+                .map(|ingredient| Self::new(ingredient, locale))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonStellarMission {
+    pub mission_id: u32,
+    pub name: Option<String>,
+    pub job_id: u8,
+    pub job_name: String,
+    pub recipe_ids: Vec<u32>,
+}
+
+impl JsonStellarMission {
+    pub fn new(mission_id: u32, mission: &StellarMission, locale: Locale) -> Self {
+        Self {
+            mission_id,
+            name: get_stellar_mission_name(mission_id, locale).map(str::to_owned),
+            job_id: mission.job_id,
+            job_name: get_job_name(mission.job_id, locale).to_owned(),
+            recipe_ids: mission.recipe_ids.to_vec(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct JsonCrafterStats {
+    pub craftsmanship: u16,
+    pub control: u16,
+    pub cp: u16,
+    pub level: u8,
+    pub manipulation: bool,
+    pub heart_and_soul: bool,
+    pub quick_innovation: bool,
+}
+
+impl From<CrafterStats> for JsonCrafterStats {
+    fn from(stats: CrafterStats) -> Self {
+        Self {
+            craftsmanship: stats.craftsmanship,
+            control: stats.control,
+            cp: stats.cp,
+            level: stats.level,
+            manipulation: stats.manipulation,
+            heart_and_soul: stats.heart_and_soul,
+            quick_innovation: stats.quick_innovation,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct JsonConsumable {
+    pub item_id: u32,
+    pub hq: bool,
+}
+
+impl From<Consumable> for JsonConsumable {
+    fn from(consumable: Consumable) -> Self {
+        Self {
+            item_id: consumable.item_id,
+            hq: consumable.hq,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonSettings {
+    pub max_cp: u16,
+    pub max_durability: u16,
+    pub max_progress: u16,
+    pub max_quality: u16,
+    pub base_progress: u16,
+    pub base_quality: u16,
+    pub job_level: u8,
+    pub allowed_actions: Vec<String>,
+    pub adversarial: bool,
+    pub backload_progress: bool,
+    pub stellar_steady_hand_charges: u8,
+}
+
+impl From<Settings> for JsonSettings {
+    fn from(settings: Settings) -> Self {
+        Self {
+            max_cp: settings.max_cp,
+            max_durability: settings.max_durability,
+            max_progress: settings.max_progress,
+            max_quality: settings.max_quality,
+            base_progress: settings.base_progress,
+            base_quality: settings.base_quality,
+            job_level: settings.job_level,
+            allowed_actions: Action::iter()
+                .filter(|action| settings.allowed_actions.has(*action))
+                .map(|action| format!("{action:?}"))
+                .collect(),
+            adversarial: settings.adversarial,
+            backload_progress: settings.backload_progress,
+            stellar_steady_hand_charges: settings.stellar_steady_hand_charges,
+        }
+    }
+}
+
+/// Remaining duration or stacks of each effect. `0` or `false` means the effect is not active.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct JsonEffects {
+    pub inner_quiet: u8,
+    pub waste_not: u8,
+    pub innovation: u8,
+    pub veneration: u8,
+    pub great_strides: u8,
+    pub muscle_memory: u8,
+    pub manipulation: u8,
+    pub stellar_steady_hand: u8,
+    pub stellar_steady_hand_charges: u8,
+    pub trained_perfection_active: bool,
+    pub heart_and_soul_active: bool,
+    pub expedience: bool,
+}
+
+impl From<Effects> for JsonEffects {
+    fn from(effects: Effects) -> Self {
+        Self {
+            inner_quiet: effects.inner_quiet(),
+            waste_not: effects.waste_not(),
+            innovation: effects.innovation(),
+            veneration: effects.veneration(),
+            great_strides: effects.great_strides(),
+            muscle_memory: effects.muscle_memory(),
+            manipulation: effects.manipulation(),
+            stellar_steady_hand: effects.stellar_steady_hand(),
+            stellar_steady_hand_charges: effects.stellar_steady_hand_charges(),
+            trained_perfection_active: effects.trained_perfection_active(),
+            heart_and_soul_active: effects.heart_and_soul_active(),
+            expedience: effects.expedience(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct JsonState {
+    pub progress: u16,
+    pub quality: u16,
+    pub durability: u16,
+    pub cp: u16,
+    pub effects: JsonEffects,
+}
+
+impl JsonState {
+    pub fn new(state: &SimulationState, initial_quality: u16, max_quality: u16) -> Self {
+        Self {
+            progress: state.progress,
+            quality: total_quality(state, initial_quality, max_quality),
+            durability: state.durability,
+            cp: state.cp,
+            effects: state.effects.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonStep {
+    /// One-based step index.
+    pub step: usize,
+    pub action: JsonAction,
+    /// Debug name of the `ActionError` if the action could not be used. The state is unchanged in that case.
+    pub error: Option<String>,
+    /// State after the action has been used.
+    pub state: JsonState,
+}
+
+/// Converts the steps returned by [`simulate_steps`] to their JSON output.
+pub fn json_steps(
+    actions: &[Action],
+    steps: &[(SimulationState, Result<(), ActionError>)],
+    initial_quality: u16,
+    max_quality: u16,
+) -> Vec<JsonStep> {
+    actions
+        .iter()
+        .zip(steps)
+        .enumerate()
+        .map(|(index, (action, (state, error)))| JsonStep {
+            step: index + 1,
+            action: (*action).into(),
+            error: error.err().map(|err| format!("{err:?}")),
+            state: JsonState::new(state, initial_quality, max_quality),
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct JsonSolverStats {
+    pub inserted_nodes: usize,
+    pub processed_nodes: usize,
+    pub finish_solver_states: usize,
+    pub finish_solver_values: usize,
+    pub quality_ub_states: usize,
+    pub quality_ub_values: usize,
+    pub step_lb_states: usize,
+    pub step_lb_values: usize,
+}
+
+/// Output of the `solve` and `simulate` commands.
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonCraftOutput {
+    pub schema_version: u32,
+    pub recipe: JsonRecipe,
+    pub crafter_stats: JsonCrafterStats,
+    pub food: Option<JsonConsumable>,
+    pub potion: Option<JsonConsumable>,
+    /// Settings used by the simulator. `max_quality` is the max quality of the recipe.
+    pub settings: JsonSettings,
+    pub initial_quality: u16,
+    /// Only present in the output of the `solve` command with `--optimize-hq-ingredients`.
+    pub hq_ingredients: Option<[u8; 6]>,
+    /// Only present in the output of the `solve` command.
+    pub target_quality: Option<u16>,
+    pub actions: Vec<JsonAction>,
+    pub steps: Vec<JsonStep>,
+    pub final_state: JsonState,
+    /// Total duration of the actions in seconds.
+    pub duration: u32,
+    /// Number of actions that could not be used.
+    pub errors: usize,
+    /// Only present in the output of the `solve` command.
+    pub solver_stats: Option<JsonSolverStats>,
+}
+
+impl JsonSolverStats {
+    pub fn new(solver: &MacroSolver) -> Self {
+        let stats = solver.runtime_stats();
+        Self {
+            inserted_nodes: stats.search_queue_stats.inserted_nodes,
+            processed_nodes: stats.search_queue_stats.processed_nodes,
+            finish_solver_states: stats.finish_solver_stats.states,
+            finish_solver_values: stats.finish_solver_stats.values,
+            quality_ub_states: stats.quality_ub_stats.states_on_main
+                + stats.quality_ub_stats.states_on_shards,
+            quality_ub_values: stats.quality_ub_stats.values,
+            step_lb_states: stats.step_lb_stats.states_on_main
+                + stats.step_lb_stats.states_on_shards,
+            step_lb_values: stats.step_lb_stats.values,
+        }
+    }
+}
+
+impl JsonCraftOutput {
+    /// `settings` are the settings the actions were simulated with.
+    /// For solves, this is expected to have its `max_quality` adjusted to the target quality.
+    pub fn new(
+        config: &CraftConfig,
+        settings: &Settings,
+        actions: &[Action],
+        target_quality: Option<u16>,
+        solver_stats: Option<JsonSolverStats>,
+    ) -> Self {
+        let initial_quality = config.initial_quality;
+        let max_quality = config.settings.max_quality;
+        let steps = simulate_steps(settings, actions);
+        Self {
+            schema_version: SCHEMA_VERSION,
+            recipe: JsonRecipe::new(config.recipe_id, &config.recipe, Locale::EN),
+            crafter_stats: config.crafter_stats.into(),
+            food: config.food.map(Into::into),
+            potion: config.potion.map(Into::into),
+            settings: JsonSettings::from(Settings {
+                max_quality,
+                ..*settings
+            }),
+            initial_quality,
+            hq_ingredients: None,
+            target_quality,
+            actions: actions.iter().map(|action| (*action).into()).collect(),
+            steps: json_steps(actions, &steps, initial_quality, max_quality),
+            final_state: JsonState::new(
+                &final_state(settings, &steps),
+                initial_quality,
+                max_quality,
+            ),
+            duration: actions
+                .iter()
+                .map(|action| u32::from(action.time_cost()))
+                .sum(),
+            errors: steps.iter().filter(|(_, error)| error.is_err()).count(),
+            solver_stats,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonShoppingListItem {
+    pub item_id: u32,
+    pub item_name: Option<String>,
+    pub amount: u32,
+    pub hq_amount: u32,
+}
+
+impl JsonShoppingListItem {
+    pub fn new(item: &ShoppingListItem, locale: Locale) -> Self {
+        Self {
+            item_id: item.item_id,
+            item_name: get_raw_item_name(item.item_id, locale).map(str::to_owned),
+            amount: item.amount,
+            hq_amount: item.hq_amount,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonPlannedCraft {
+    pub amount: u32,
+    pub crafts: u32,
+    /// Number of crafted items that need to be HQ for the crafts that use them.
+    pub hq_amount: u32,
+    pub hq_ingredients: [u8; 6],
+    /// Output of a single craft. Does not contain solver stats.
+    pub craft: JsonCraftOutput,
+}
+
+impl From<&PlannedCraft> for JsonPlannedCraft {
+    fn from(craft: &PlannedCraft) -> Self {
+        Self {
+            amount: craft.amount,
+            crafts: craft.crafts,
+            hq_amount: craft.hq_amount,
+            hq_ingredients: craft.hq_ingredients,
+            craft: JsonCraftOutput::new(
+                &craft.config,
+                &craft.settings,
+                &craft.actions,
+                Some(craft.target_quality),
+                None,
+            ),
+        }
+    }
+}
+
+/// Output of the `ingredients` command.
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonIngredients {
+    pub schema_version: u32,
+    pub recipe: JsonRecipe,
+    /// `null` unless `--tree` is set.
+    pub crafting_tree: Option<JsonCraftingTreeNode>,
+}
+
+impl JsonIngredients {
+    pub fn new(
+        recipe_id: u32,
+        recipe: &Recipe,
+        crafting_tree: Option<&CraftingTreeNode>,
+        locale: Locale,
+    ) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            recipe: JsonRecipe::new(recipe_id, recipe, locale),
+            crafting_tree: crafting_tree.map(|node| JsonCraftingTreeNode::new(node, locale)),
+        }
+    }
+}
+
+/// Output of the `plan` command.
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonCraftingPlan {
+    pub schema_version: u32,
+    pub shopping_list: Vec<JsonShoppingListItem>,
+    /// Crafts in the order they need to be done in, i.e. the final item is crafted last.
+    pub crafts: Vec<JsonPlannedCraft>,
+}
+
+impl JsonCraftingPlan {
+    pub fn new(plan: &CraftingPlan, locale: Locale) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            shopping_list: plan
+                .shopping_list
+                .iter()
+                .map(|item| JsonShoppingListItem::new(item, locale))
+                .collect(),
+            crafts: plan.crafts.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonMeldRecipe {
+    pub recipe_id: u32,
+    pub item_name: Option<String>,
+    /// Whether the solver reaches max quality with the melded gear set.
+    pub max_quality: bool,
+}
+
+/// Output of the `meld` command.
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonMeldResult {
+    pub schema_version: u32,
+    /// Gear set with the best melds, in the format of the `gearset` argument.
+    pub gearset: Gearset,
+    pub crafter_stats: JsonCrafterStats,
+    pub recipes: Vec<JsonMeldRecipe>,
+    /// Number of meld allocations that are not worse than another allocation in every stat.
+    pub candidates: usize,
+    pub solver_runs: usize,
+}
+
+impl JsonMeldResult {
+    pub fn new(result: &MeldResult, locale: Locale) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            gearset: result.gearset.clone(),
+            crafter_stats: result.crafter_stats.into(),
+            recipes: result
+                .recipes
+                .iter()
+                .map(|(recipe_id, max_quality)| JsonMeldRecipe {
+                    recipe_id: *recipe_id,
+                    item_name: get_recipe(*recipe_id).and_then(|recipe| {
+                        get_raw_item_name(recipe.item_id, locale).map(str::to_owned)
+                    }),
+                    max_quality: *max_quality,
+                })
+                .collect(),
+            candidates: result.candidates,
+            solver_runs: result.solver_runs,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonComparedRotation {
+    pub name: String,
+    pub actions: Vec<JsonAction>,
+    pub steps: Vec<JsonStep>,
+    pub final_state: JsonState,
+    /// Total duration of the actions in seconds.
+    pub duration: u32,
+    /// Number of actions that could not be used.
+    pub errors: usize,
+}
+
+/// Output of the `compare` command.
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonComparison {
+    pub schema_version: u32,
+    pub recipe: JsonRecipe,
+    pub crafter_stats: JsonCrafterStats,
+    /// Settings used by the simulator for all rotations.
+    pub settings: JsonSettings,
+    pub initial_quality: u16,
+    pub rotations: Vec<JsonComparedRotation>,
+    /// One-based index of the first step after which the states of the rotations differ.
+    /// `null` if the rotations are identical.
+    pub first_difference: Option<usize>,
+}
+
+impl JsonComparison {
+    pub fn new(
+        config: &CraftConfig,
+        settings: &Settings,
+        rotations: &[ComparedRotation],
+        first_difference: Option<usize>,
+    ) -> Self {
+        let initial_quality = config.initial_quality;
+        Self {
+            schema_version: SCHEMA_VERSION,
+            recipe: JsonRecipe::new(config.recipe_id, &config.recipe, Locale::EN),
+            crafter_stats: config.crafter_stats.into(),
+            settings: (*settings).into(),
+            initial_quality,
+            rotations: rotations
+                .iter()
+                .map(|rotation| JsonComparedRotation {
+                    name: rotation.name.clone(),
+                    actions: rotation
+                        .actions
+                        .iter()
+                        .map(|action| (*action).into())
+                        .collect(),
+                    steps: json_steps(
+                        &rotation.actions,
+                        &rotation.steps,
+                        initial_quality,
+                        settings.max_quality,
+                    ),
+                    final_state: JsonState::new(
+                        &rotation.final_state,
+                        initial_quality,
+                        settings.max_quality,
+                    ),
+                    duration: rotation.duration(),
+                    errors: rotation.error_count(),
+                })
+                .collect(),
+            first_difference: first_difference.map(|step_index| step_index + 1),
+        }
+    }
+}
+
+/// A rotation of a rotations file, output of the `rotations` command.
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonSavedRotation {
+    pub unique_id: u64,
+    pub name: String,
+    pub pinned: bool,
+    pub solver: String,
+    /// `null` for custom recipes and for rotations saved before v0.21.0.
+    pub recipe_id: Option<u32>,
+    /// Empty if the rotation is not in a folder.
+    pub folder: String,
+    pub tags: Vec<String>,
+    pub notes: String,
+    pub crafter_stats: JsonCrafterStats,
+    pub food: Option<JsonConsumable>,
+    pub potion: Option<JsonConsumable>,
+    pub actions: Vec<JsonAction>,
+    /// Total duration of the actions in seconds.
+    pub duration: u32,
+    /// Settings the rotation was solved with. `null` if the rotations file does not contain them.
+    pub settings: Option<JsonSettings>,
+    pub initial_quality: Option<u16>,
+    /// State after simulating the rotation with `settings`.
+    pub final_state: Option<JsonState>,
+}
+
+impl JsonSavedRotation {
+    pub fn new(rotation: &SavedRotation, pinned: bool) -> Self {
+        let consumable = |(item_id, hq): (u32, bool)| JsonConsumable { item_id, hq };
+        let solve_info = rotation.solve_info.as_ref();
+        Self {
+            unique_id: rotation.unique_id,
+            name: rotation.name.clone(),
+            pinned,
+            solver: rotation.solver.clone(),
+            recipe_id: rotation.recipe_id(),
+            folder: rotation.folder.clone(),
+            tags: rotation.tags.clone(),
+            notes: rotation.notes.clone(),
+            crafter_stats: rotation.crafter_stats.into(),
+            food: rotation.food.map(consumable),
+            potion: rotation.potion.map(consumable),
+            actions: rotation
+                .actions
+                .iter()
+                .copied()
+                .map(JsonAction::from)
+                .collect(),
+            duration: rotation.duration(),
+            settings: solve_info.map(|solve_info| solve_info.game_settings.into()),
+            initial_quality: solve_info.map(|solve_info| solve_info.initial_quality),
+            final_state: solve_info
+                .zip(rotation.final_state())
+                .map(|(solve_info, state)| {
+                    JsonState::new(
+                        &state,
+                        solve_info.initial_quality,
+                        solve_info.game_settings.max_quality,
+                    )
+                }),
+        }
+    }
+}
@@ -0,0 +1,456 @@
+use std::path::{Path, PathBuf};
+
+use clap::Args;
+use raphael_data::{
+    CrafterStats, CraftingStat, GearPiece, GearStats, Gearset, GearsetError, Locale, find_gear,
+    find_materia, game_data, get_initial_quality, get_raw_item_name, has_gear_data,
+};
+use raphael_sim::SimulationState;
+
+use super::{
+    Language, OutputFormat,
+    craft_args::{ConsumableArg, CraftArgs, max_hq_ingredients, parse_consumable, read_gearset},
+    json::{JsonMeldResult, print_json},
+    solve::solve_actions,
+};
+
+#[derive(Args, Debug)]
+pub struct MeldArgs {
+    /// Path to a JSON gear set file with the equipped items, in the same format as the `gearset` argument of `solve`. Materia that is already melded is ignored
+    #[arg(short, long, value_name = "PATH")]
+    pub gearset: PathBuf,
+
+    /// Recipe IDs of the target recipes
+    #[arg(short, long, num_args = 1.., required = true)]
+    pub recipe_id: Vec<u32>,
+
+    /// Item IDs of the materia that can be melded. Defaults to the highest grade craftsmanship, control and CP materia
+    #[arg(long, num_args = 1.., value_name = "ITEM_ID")]
+    pub materia: Vec<u32>,
+
+    /// Only fill the regular materia slots of each item
+    #[arg(long, default_value_t = false)]
+    pub no_overmeld: bool,
+
+    /// Crafter level
+    #[arg(short, long, default_value_t = 100)]
+    pub level: u8,
+
+    /// Food to use for all recipes, in the format '<ITEM_ID>[,HQ]'
+    #[arg(long, value_parser = parse_consumable)]
+    pub food: Option<ConsumableArg>,
+
+    /// Potion to use for all recipes, in the format '<ITEM_ID>[,HQ]'
+    #[arg(long, value_parser = parse_consumable)]
+    pub potion: Option<ConsumableArg>,
+
+    /// Enable Manipulation
+    #[arg(short, long, default_value_t = false)]
+    pub manipulation: bool,
+
+    /// Enable Heart and Soul
+    #[arg(long, default_value_t = false)]
+    pub heart_and_soul: bool,
+
+    /// Enable Quick Innovation
+    #[arg(long, default_value_t = false)]
+    pub quick_innovation: bool,
+
+    /// Use HQ versions of all ingredients
+    #[arg(long, default_value_t = false)]
+    pub hq_ingredients: bool,
+
+    /// Enable adversarial simulator (ensure 100% reliability)
+    #[arg(long, default_value_t = false)]
+    pub adversarial: bool,
+
+    /// Only use Progress-increasing actions at the end of the macro
+    #[arg(long, default_value_t = false)]
+    pub backload_progress: bool,
+
+    /// Maximum number of threads available to the solver
+    #[arg(long)]
+    pub threads: Option<usize>,
+
+    /// Write the gear set with the best melds to a JSON file that can be used as `gearset` argument
+    #[arg(long, value_name = "PATH")]
+    pub output: Option<PathBuf>,
+
+    /// The language the output uses
+    #[arg(long, alias = "locale", value_enum, ignore_case = true, default_value_t = Language::EN)]
+    pub language: Language,
+
+    /// Output format
+    #[arg(long, value_enum, ignore_case = true, default_value_t = OutputFormat::Text)]
+    pub format: OutputFormat,
+}
+
+#[derive(Debug, Clone)]
+pub struct MeldResult {
+    /// The gear set with the melds that reach max quality on the most recipes.
+    pub gearset: Gearset,
+    pub crafter_stats: CrafterStats,
+    /// Target recipes and whether the solver reaches max quality with the melded gear set.
+    pub recipes: Vec<(u32, bool)>,
+    /// Number of meld allocations that are not worse than another allocation in every stat.
+    pub candidates: usize,
+    pub solver_runs: usize,
+}
+
+impl MeldArgs {
+    fn crafter_stats(&self, stats: GearStats) -> CrafterStats {
+        CrafterStats {
+            craftsmanship: stats.craftsmanship,
+            control: stats.control,
+            cp: stats.cp,
+            level: self.level,
+            manipulation: self.manipulation,
+            heart_and_soul: self.heart_and_soul,
+            quick_innovation: self.quick_innovation,
+        }
+    }
+
+    fn craft_args(&self, recipe_id: u32, crafter_stats: CrafterStats) -> CraftArgs {
+        CraftArgs {
+            recipe_id: Some(recipe_id),
+            item_id: None,
+            custom_recipe: Vec::new(),
+            override_base_increases: Vec::new(),
+            craftsmanship: Some(crafter_stats.craftsmanship),
+            control: Some(crafter_stats.control),
+            cp: Some(crafter_stats.cp),
+            stats: Vec::new(),
+            gearset: None,
+            level: crafter_stats.level,
+            food: self.food,
+            potion: self.potion,
+            manipulation: self.manipulation,
+            heart_and_soul: self.heart_and_soul,
+            quick_innovation: self.quick_innovation,
+            stellar_steady_hand: None,
+            initial_quality: None,
+            hq_ingredients: None,
+            skip_map_and_clamp_hq_ingredients: false,
+        }
+    }
+
+    /// Item IDs of the materia that can be melded.
+    fn materia(&self) -> Result<Vec<u32>, String> {
+        if self.materia.is_empty() {
+            return Ok([
+                CraftingStat::Craftsmanship,
+                CraftingStat::Control,
+                CraftingStat::Cp,
+            ]
+            .into_iter()
+            .filter_map(|stat| {
+                game_data()
+                    .materia()
+                    .iter()
+                    .filter(|materia| materia.stat == stat)
+                    .max_by_key(|materia| materia.grade)
+                    .map(|materia| materia.item_id)
+            })
+            .collect());
+        }
+        for item_id in &self.materia {
+            if find_materia(*item_id).is_none() {
+                return Err(format!("Item {item_id} is not materia with crafting stats"));
+            }
+        }
+        Ok(self.materia.clone())
+    }
+}
+
+fn add_stats(lhs: GearStats, rhs: GearStats) -> GearStats {
+    GearStats {
+        craftsmanship: lhs.craftsmanship.saturating_add(rhs.craftsmanship),
+        control: lhs.control.saturating_add(rhs.control),
+        cp: lhs.cp.saturating_add(rhs.cp),
+    }
+}
+
+fn stats_sum(stats: GearStats) -> u32 {
+    u32::from(stats.craftsmanship) + u32::from(stats.control) + u32::from(stats.cp)
+}
+
+/// Whether `lhs` is at least as good as `rhs` in every stat.
+fn dominates(lhs: GearStats, rhs: GearStats) -> bool {
+    lhs.craftsmanship >= rhs.craftsmanship && lhs.control >= rhs.control && lhs.cp >= rhs.cp
+}
+
+/// Removes all entries whose stats are dominated by the stats of another entry.
+/// The remaining entries are sorted by their total stats in descending order.
+fn pareto_front<T>(mut entries: Vec<(GearStats, T)>) -> Vec<(GearStats, T)> {
+    // An entry can only be dominated by an entry with the same or a higher total,
+    // and entries with the same total only dominate each other if their stats are equal.
+    entries.sort_by_key(|(stats, _)| std::cmp::Reverse(stats_sum(*stats)));
+    let mut front: Vec<(GearStats, T)> = Vec::new();
+    for (stats, value) in entries {
+        if !front.iter().any(|(other, _)| dominates(*other, stats)) {
+            front.push((stats, value));
+        }
+    }
+    front
+}
+
+/// All ways to choose `count` materia from `materia`, ignoring the order.
+fn materia_combinations(materia: &[u32], count: usize) -> Vec<Vec<u32>> {
+    if count == 0 {
+        return vec![Vec::new()];
+    }
+    let mut combinations = Vec::new();
+    for (index, item_id) in materia.iter().enumerate() {
+        for mut combination in materia_combinations(&materia[index..], count - 1) {
+            combination.insert(0, *item_id);
+            combinations.push(combination);
+        }
+    }
+    combinations
+}
+
+/// Total stats of a meld allocation and the materia melded into each piece of the gear set.
+type MeldAllocation = (GearStats, Vec<Vec<u32>>);
+
+/// Meld allocations of the whole gear set, keyed by the total stats they result in.
+/// Only allocations that are not worse than another allocation in every stat are kept.
+fn meld_candidates(
+    skeleton: &Gearset,
+    materia: &[u32],
+    no_overmeld: bool,
+) -> Result<Vec<MeldAllocation>, String> {
+    let mut candidates = vec![(skeleton.base_stats, Vec::new())];
+    for piece in &skeleton.pieces {
+        let gear = find_gear(piece.item_id)
+            .ok_or_else(|| format!("Item {} is not gear with crafting stats", piece.item_id))?;
+        let slots = if no_overmeld {
+            gear.materia_slots
+        } else {
+            gear.max_materia()
+        };
+        let mut options = Vec::new();
+        for combination in materia_combinations(materia, usize::from(slots)) {
+            let melded_piece = GearPiece {
+                materia: combination,
+                ..piece.clone()
+            };
+            let stats = melded_piece.stats().map_err(|error| error.to_string())?;
+            options.push((stats, melded_piece.materia));
+        }
+        let options = pareto_front(options);
+
+        let mut combined = Vec::with_capacity(candidates.len() * options.len());
+        for (stats, melds) in &candidates {
+            for (piece_stats, piece_melds) in &options {
+                let mut melds = melds.clone();
+                melds.push(piece_melds.clone());
+                combined.push((add_stats(*stats, *piece_stats), melds));
+            }
+        }
+        candidates = pareto_front(combined);
+    }
+    Ok(candidates)
+}
+
+/// Stats with which a recipe is known to be reachable or unreachable.
+///
+/// Higher stats never make a recipe harder, so any stats that dominate reachable stats are reachable as well,
+/// and any stats that are dominated by unreachable stats are unreachable as well.
+#[derive(Debug, Default)]
+struct ReachableStats {
+    reachable: Vec<GearStats>,
+    unreachable: Vec<GearStats>,
+}
+
+impl ReachableStats {
+    fn get(&self, stats: GearStats) -> Option<bool> {
+        if self.reachable.iter().any(|other| dominates(stats, *other)) {
+            Some(true)
+        } else if self
+            .unreachable
+            .iter()
+            .any(|other| dominates(*other, stats))
+        {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    fn insert(&mut self, stats: GearStats, reachable: bool) {
+        match reachable {
+            true => self.reachable.push(stats),
+            false => self.unreachable.push(stats),
+        }
+    }
+}
+
+/// Whether the solver reaches max quality on the recipe with the given stats.
+fn reaches_max_quality(
+    args: &MeldArgs,
+    recipe_id: u32,
+    crafter_stats: CrafterStats,
+) -> Result<bool, String> {
+    let mut config = args.craft_args(recipe_id, crafter_stats).craft_config()?;
+    if args.hq_ingredients {
+        config.initial_quality = get_initial_quality(
+            config.crafter_stats,
+            config.recipe,
+            max_hq_ingredients(&config.recipe),
+        );
+    }
+    let mut settings = config.settings;
+    settings.adversarial = args.adversarial;
+    settings.backload_progress = args.backload_progress;
+    settings.max_quality = settings.max_quality.saturating_sub(config.initial_quality);
+
+    let actions = solve_actions(settings)?;
+    let final_state =
+        SimulationState::from_macro(&settings, &actions).map_err(|error| format!("{error:?}"))?;
+    Ok(
+        final_state.progress >= settings.max_progress
+            && final_state.quality >= settings.max_quality,
+    )
+}
+
+/// Finds the meld allocation with which the solver reaches max quality on the most recipes.
+/// Ties are broken in favor of the allocation with the highest total stats.
+pub fn optimize_melds(args: &MeldArgs) -> Result<MeldResult, String> {
+    if !has_gear_data() {
+        return Err(GearsetError::NoGearData.to_string());
+    }
+    let mut skeleton = read_gearset(&args.gearset)?;
+    for piece in &mut skeleton.pieces {
+        piece.materia.clear();
+    }
+    skeleton
+        .crafter_stats(args.crafter_stats(GearStats::default()))
+        .map_err(|error| format!("Invalid gear set {}: {error}", args.gearset.display()))?;
+
+    let materia = args.materia()?;
+    if materia.is_empty() {
+        return Err("No materia with crafting stats available".to_owned());
+    }
+    let candidates = meld_candidates(&skeleton, &materia, args.no_overmeld)?;
+    log::info!("Evaluating {} meld allocations", candidates.len());
+
+    let recipe_count = args.recipe_id.len();
+    let mut known_stats: Vec<ReachableStats> = args
+        .recipe_id
+        .iter()
+        .map(|_| ReachableStats::default())
+        .collect();
+    let mut solver_runs = 0;
+    let mut best: Option<(usize, Vec<bool>)> = None;
+    for (index, (stats, _)) in candidates.iter().enumerate() {
+        let crafter_stats = args.crafter_stats(*stats);
+        // Candidates that cannot beat the best candidate so far are abandoned as early as possible.
+        let allowed_failures = best.as_ref().map_or(recipe_count, |(_, reachable)| {
+            reachable.iter().filter(|reachable| !**reachable).count()
+        });
+        let mut reachable = Vec::with_capacity(recipe_count);
+        let mut failures = 0;
+        let mut abandoned = false;
+        for (recipe_id, known_stats) in args.recipe_id.iter().zip(&mut known_stats) {
+            let recipe_reachable = match known_stats.get(*stats) {
+                Some(recipe_reachable) => recipe_reachable,
+                None => {
+                    solver_runs += 1;
+                    let recipe_reachable = reaches_max_quality(args, *recipe_id, crafter_stats)?;
+                    known_stats.insert(*stats, recipe_reachable);
+                    recipe_reachable
+                }
+            };
+            reachable.push(recipe_reachable);
+            if !recipe_reachable {
+                failures += 1;
+            }
+            if best.is_some() && failures >= allowed_failures {
+                abandoned = true;
+                break;
+            }
+        }
+        if !abandoned {
+            best = Some((index, reachable));
+            if failures == 0 {
+                break;
+            }
+        }
+    }
+
+    let (index, reachable) = best.expect("There is always at least one candidate");
+    let mut gearset = skeleton;
+    for (piece, melds) in gearset.pieces.iter_mut().zip(&candidates[index].1) {
+        piece.materia.clone_from(melds);
+    }
+    let crafter_stats = gearset
+        .crafter_stats(args.crafter_stats(GearStats::default()))
+        .map_err(|error| error.to_string())?;
+    Ok(MeldResult {
+        gearset,
+        crafter_stats,
+        recipes: args.recipe_id.iter().copied().zip(reachable).collect(),
+        candidates: candidates.len(),
+        solver_runs,
+    })
+}
+
+fn write_gearset(path: &Path, gearset: &Gearset) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(gearset).map_err(|error| error.to_string())?;
+    std::fs::write(path, json)
+        .map_err(|error| format!("Unable to write gear set {}: {error}", path.display()))
+}
+
+pub fn execute(args: &MeldArgs) {
+    if let Some(threads) = args.threads {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build_global()
+            .unwrap();
+    }
+
+    let result = optimize_melds(args).unwrap_or_else(|err| panic!("{err}"));
+    if let Some(path) = &args.output {
+        write_gearset(path, &result.gearset).unwrap_or_else(|err| panic!("{err}"));
+    }
+    let locale: Locale = args.language.into();
+
+    if args.format != OutputFormat::Text {
+        print_json(&JsonMeldResult::new(&result, locale), args.format);
+        return;
+    }
+
+    let item_name = |item_id| get_raw_item_name(item_id, locale).unwrap_or("Unknown item");
+    let reachable_count = result.recipes.iter().filter(|(_, r)| *r).count();
+    println!(
+        "Max quality reached on {}/{} recipes",
+        reachable_count,
+        result.recipes.len()
+    );
+    println!(
+        "Stats: {}/{}/{}",
+        result.crafter_stats.craftsmanship, result.crafter_stats.control, result.crafter_stats.cp
+    );
+    for piece in &result.gearset.pieces {
+        let materia: Vec<&str> = piece
+            .materia
+            .iter()
+            .map(|item_id| item_name(*item_id))
+            .collect();
+        println!("{}: {}", item_name(piece.item_id), materia.join(", "));
+    }
+    for (recipe_id, reachable) in &result.recipes {
+        println!(
+            "Recipe {recipe_id}: {}",
+            if *reachable {
+                "max quality"
+            } else {
+                "not max quality"
+            }
+        );
+    }
+    println!(
+        "Evaluated {} meld allocations with {} solver runs",
+        result.candidates, result.solver_runs
+    );
+}
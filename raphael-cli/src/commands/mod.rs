@@ -1,8 +1,17 @@
 use raphael_data::Locale;
 
+pub mod batch;
+pub mod compare;
+pub mod craft_args;
 pub mod ingredients;
+pub mod json;
+pub mod meld;
+pub mod plan;
+pub mod rotations;
 pub mod search_mission;
 pub mod search_recipe;
+pub mod serve;
+pub mod simulate;
 pub mod solve;
 
 #[derive(Copy, Clone, clap::ValueEnum, Debug)]
@@ -29,3 +38,13 @@ impl From<Language> for Locale {
         }
     }
 }
+
+#[derive(Copy, Clone, clap::ValueEnum, Debug, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable text
+    Text,
+    /// Pretty-printed JSON
+    Json,
+    /// Newline-delimited JSON, one compact JSON value per line
+    Ndjson,
+}
@@ -0,0 +1,400 @@
+use std::collections::HashMap;
+
+use clap::Args;
+use raphael_data::{
+    CrafterStats, CraftingTreeNode, Ingredient, Item, JOB_NAMES_EN, Locale, Recipe, game_data,
+    get_crafting_tree, get_initial_quality, get_job_name, get_raw_item_name, get_result_amount,
+    optimize_hq_ingredients,
+};
+use raphael_sim::{Action, Settings, SimulationState};
+
+use super::{
+    Language, OutputFormat,
+    craft_args::{ConsumableArg, CraftArgs, CraftConfig, max_hq_ingredients, parse_consumable},
+    json::{JsonCraftingPlan, print_json},
+    solve::solve_actions,
+};
+
+#[derive(Args, Debug)]
+pub struct PlanArgs {
+    /// Recipe ID of the final item
+    #[arg(short, long)]
+    pub recipe_id: u32,
+
+    /// Number of final items to craft
+    #[arg(short, long, default_value_t = 1)]
+    pub amount: u32,
+
+    /// Stats of a job, in the format '<JOB>:<CRAFTSMANSHIP>,<CONTROL>,<CP>[,<LEVEL>]'. <JOB> is the English job abbreviation, e.g. 'CRP'. Must be provided for every job that is needed to craft the final item
+    #[arg(short, long, value_parser = parse_job_stats, required = true)]
+    pub job_stats: Vec<JobStatsArg>,
+
+    /// Food to use for all crafts, in the format '<ITEM_ID>[,HQ]'
+    #[arg(long, value_parser = parse_consumable)]
+    pub food: Option<ConsumableArg>,
+
+    /// Potion to use for all crafts, in the format '<ITEM_ID>[,HQ]'
+    #[arg(long, value_parser = parse_consumable)]
+    pub potion: Option<ConsumableArg>,
+
+    /// Enable Manipulation
+    #[arg(short, long, default_value_t = false)]
+    pub manipulation: bool,
+
+    /// Enable Heart and Soul
+    #[arg(long, default_value_t = false)]
+    pub heart_and_soul: bool,
+
+    /// Enable Quick Innovation
+    #[arg(long, default_value_t = false)]
+    pub quick_innovation: bool,
+
+    /// Target quality of the final item, value is clamped to 100% quality
+    #[arg(long, alias = "target")]
+    pub target_quality: Option<u16>,
+
+    /// Enable adversarial simulator (ensure 100% reliability)
+    #[arg(long, default_value_t = false)]
+    pub adversarial: bool,
+
+    /// Only use Progress-increasing actions at the end of the macro
+    #[arg(long, default_value_t = false)]
+    pub backload_progress: bool,
+
+    /// Maximum number of threads available to the solver
+    #[arg(long)]
+    pub threads: Option<usize>,
+
+    /// The language the output uses
+    #[arg(short, long, alias = "locale", value_enum, ignore_case = true, default_value_t = Language::EN)]
+    pub language: Language,
+
+    /// Output format
+    #[arg(long, value_enum, ignore_case = true, default_value_t = OutputFormat::Text)]
+    pub format: OutputFormat,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct JobStatsArg {
+    pub job_id: u8,
+    pub crafter_stats: CrafterStats,
+}
+
+fn parse_job_stats(s: &str) -> Result<JobStatsArg, String> {
+    const PARSE_ERROR_STRING: &str = "Job stats are not parsable. Job stats must have the format '<JOB>:<CRAFTSMANSHIP>,<CONTROL>,<CP>[,<LEVEL>]'";
+    let (job_name, stats) = s.split_once(':').ok_or(PARSE_ERROR_STRING)?;
+    let job_id = JOB_NAMES_EN
+        .iter()
+        .position(|name| name.eq_ignore_ascii_case(job_name.trim()))
+        .ok_or_else(|| format!("Unknown job '{job_name}'"))?;
+    let stats: Vec<u16> = stats
+        .split(',')
+        .map(|value| value.trim().parse())
+        .collect::<Result<_, _>>()
+        .map_err(|_| PARSE_ERROR_STRING)?;
+    let level = match stats[..] {
+        [_, _, _] => 100,
+        [_, _, _, level] => u8::try_from(level).map_err(|_| PARSE_ERROR_STRING)?,
+        _ => return Err(PARSE_ERROR_STRING.to_owned()),
+    };
+    Ok(JobStatsArg {
+        job_id: job_id as u8,
+        crafter_stats: CrafterStats {
+            craftsmanship: stats[0],
+            control: stats[1],
+            cp: stats[2],
+            level,
+            ..Default::default()
+        },
+    })
+}
+
+/// An item that cannot be crafted and needs to be obtained some other way.
+#[derive(Debug, Clone, Copy)]
+pub struct ShoppingListItem {
+    pub item_id: u32,
+    pub amount: u32,
+    /// Number of items that need to be HQ.
+    pub hq_amount: u32,
+}
+
+/// A distinct recipe of the crafting tree together with the rotation used to craft it.
+#[derive(Debug, Clone)]
+pub struct PlannedCraft {
+    pub config: CraftConfig,
+    /// Total amount of the item that is needed.
+    pub amount: u32,
+    pub crafts: u32,
+    /// Number of crafted items that need to be HQ for the crafts that use them.
+    pub hq_amount: u32,
+    pub hq_ingredients: [u8; 6],
+    pub target_quality: u16,
+    /// Settings the rotation was solved with. `max_quality` is adjusted to the target quality.
+    pub settings: Settings,
+    pub actions: Vec<Action>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CraftingPlan {
+    pub shopping_list: Vec<ShoppingListItem>,
+    /// Crafts in the order they need to be done in, i.e. the final item is crafted last.
+    pub crafts: Vec<PlannedCraft>,
+}
+
+impl PlanArgs {
+    fn craft_args(&self, recipe_id: u32, crafter_stats: CrafterStats) -> CraftArgs {
+        CraftArgs {
+            recipe_id: Some(recipe_id),
+            item_id: None,
+            custom_recipe: Vec::new(),
+            override_base_increases: Vec::new(),
+            craftsmanship: Some(crafter_stats.craftsmanship),
+            control: Some(crafter_stats.control),
+            cp: Some(crafter_stats.cp),
+            stats: Vec::new(),
+            gearset: None,
+            level: crafter_stats.level,
+            food: self.food,
+            potion: self.potion,
+            manipulation: self.manipulation,
+            heart_and_soul: self.heart_and_soul,
+            quick_innovation: self.quick_innovation,
+            stellar_steady_hand: None,
+            initial_quality: None,
+            hq_ingredients: None,
+            skip_map_and_clamp_hq_ingredients: false,
+        }
+    }
+}
+
+/// Ingredients that affect the initial quality, in the order used by [`get_initial_quality`].
+fn hq_able_ingredients(recipe: &Recipe) -> impl Iterator<Item = (&Ingredient, &Item)> {
+    recipe
+        .ingredients
+        .iter()
+        .filter_map(|ingredient| Some((ingredient, game_data().item(ingredient.item_id)?)))
+}
+
+/// Solves a single craft. If the target quality cannot be reached without HQ ingredients,
+/// the HQ ingredients needed to make up the difference are added to the craft.
+fn plan_craft(
+    args: &PlanArgs,
+    mut config: CraftConfig,
+    target_quality: u16,
+) -> Result<(CraftConfig, [u8; 6], Settings, Vec<Action>), String> {
+    let mut settings = config.settings;
+    settings.adversarial = args.adversarial;
+    settings.backload_progress = args.backload_progress;
+    settings.max_quality = target_quality;
+
+    let actions = solve_actions(settings)?;
+    let quality = SimulationState::from_macro(&settings, &actions)
+        .map_err(|error| format!("{error:?}"))?
+        .quality;
+    if quality >= target_quality {
+        return Ok((config, [0; 6], settings, actions));
+    }
+
+    let hq_ingredients = optimize_hq_ingredients(
+        config.crafter_stats,
+        config.recipe,
+        target_quality - quality,
+        [1; 6],
+    )
+    .unwrap_or_else(|| max_hq_ingredients(&config.recipe));
+    config.initial_quality =
+        get_initial_quality(config.crafter_stats, config.recipe, hq_ingredients);
+    settings.max_quality = target_quality.saturating_sub(config.initial_quality);
+    let actions = solve_actions(settings)?;
+    Ok((config, hq_ingredients, settings, actions))
+}
+
+fn add_to_shopping_list(shopping_list: &mut Vec<ShoppingListItem>, item_id: u32, amount: u32) {
+    match shopping_list
+        .iter_mut()
+        .find(|item| item.item_id == item_id)
+    {
+        Some(item) => item.amount += amount,
+        None => shopping_list.push(ShoppingListItem {
+            item_id,
+            amount,
+            hq_amount: 0,
+        }),
+    }
+}
+
+/// Solves every distinct recipe of the crafting tree of the given recipe.
+///
+/// Intermediate items are only crafted HQ if the craft that uses them cannot reach its target quality otherwise,
+/// in which case the fewest HQ ingredients that are needed to reach the target quality are used.
+pub fn create_plan(args: &PlanArgs) -> Result<CraftingPlan, String> {
+    let tree = get_crafting_tree(args.recipe_id, args.amount)
+        .ok_or_else(|| format!("Unable to find Recipe with ID: {}", args.recipe_id))?;
+
+    // Nodes of the same recipe are merged into the first occurrence.
+    // Because ingredients always come before the items that use them, so do the merged nodes.
+    let mut nodes: Vec<&CraftingTreeNode> = Vec::new();
+    for node in tree.crafted_nodes() {
+        if !nodes.iter().any(|other| other.recipe_id == node.recipe_id) {
+            nodes.push(node);
+        }
+    }
+    let node_index_by_item: HashMap<u32, usize> = nodes
+        .iter()
+        .enumerate()
+        .map(|(index, node)| (node.item_id, index))
+        .collect();
+
+    let mut job_stats: [Option<CrafterStats>; 8] = [None; 8];
+    for job_stats_arg in &args.job_stats {
+        job_stats[job_stats_arg.job_id as usize] = Some(job_stats_arg.crafter_stats);
+    }
+
+    // Crafts are planned starting from the final item so that the total amount and the HQ requirements
+    // of an intermediate item are known by the time it is planned.
+    // The number of crafts is calculated from the total amount, so that surplus items of recipes
+    // that produce more than one item are used by all crafts that need them.
+    let mut amounts = vec![0; nodes.len()];
+    amounts[nodes.len() - 1] = args.amount;
+    let mut hq_amounts = vec![0; nodes.len()];
+    let mut shopping_list = Vec::new();
+    let mut crafts = Vec::with_capacity(nodes.len());
+    for (index, node) in nodes.iter().enumerate().rev() {
+        let amount = amounts[index];
+        let recipe_id = node.recipe_id.unwrap();
+        let craft_count = amount.div_ceil(get_result_amount(node.item_id, recipe_id));
+        let job_id = node.job_id.unwrap();
+        let crafter_stats = job_stats[job_id as usize].ok_or_else(|| {
+            format!(
+                "Missing stats for {}, which is needed to craft recipe {}",
+                get_job_name(job_id, Locale::EN),
+                recipe_id
+            )
+        })?;
+        let config = args.craft_args(recipe_id, crafter_stats).craft_config()?;
+
+        for ingredient in &config.recipe.ingredients {
+            if ingredient.item_id == 0 {
+                continue;
+            }
+            let ingredient_amount = ingredient.amount * craft_count;
+            // Ingredients that come after the craft are part of a cycle and can't be crafted.
+            match node_index_by_item.get(&ingredient.item_id) {
+                Some(&ingredient_index) if ingredient_index < index => {
+                    amounts[ingredient_index] += ingredient_amount;
+                }
+                _ => {
+                    add_to_shopping_list(&mut shopping_list, ingredient.item_id, ingredient_amount)
+                }
+            }
+        }
+
+        let max_quality = config.settings.max_quality;
+        let target_quality = if index == nodes.len() - 1 {
+            args.target_quality
+                .map_or(max_quality, |target| target.min(max_quality))
+        } else if hq_amounts[index] != 0 {
+            max_quality
+        } else {
+            0
+        };
+        let (config, hq_ingredients, settings, actions) = plan_craft(args, config, target_quality)?;
+
+        for ((ingredient, _), hq_amount) in hq_able_ingredients(&config.recipe).zip(hq_ingredients)
+        {
+            let hq_amount = u32::from(hq_amount) * craft_count;
+            if hq_amount == 0 {
+                continue;
+            }
+            match node_index_by_item.get(&ingredient.item_id) {
+                Some(&ingredient_index) => hq_amounts[ingredient_index] += hq_amount,
+                None => {
+                    if let Some(item) = shopping_list
+                        .iter_mut()
+                        .find(|item| item.item_id == ingredient.item_id)
+                    {
+                        item.hq_amount += hq_amount;
+                    }
+                }
+            }
+        }
+
+        crafts.push(PlannedCraft {
+            config,
+            amount,
+            crafts: craft_count,
+            hq_amount: hq_amounts[index],
+            hq_ingredients,
+            target_quality,
+            settings,
+            actions,
+        });
+    }
+    crafts.reverse();
+
+    Ok(CraftingPlan {
+        shopping_list,
+        crafts,
+    })
+}
+
+pub fn execute(args: &PlanArgs) {
+    if let Some(threads) = args.threads {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build_global()
+            .unwrap();
+    }
+
+    let plan = create_plan(args).unwrap_or_else(|err| panic!("{err}"));
+    let locale = args.language.into();
+
+    if args.format != OutputFormat::Text {
+        print_json(&JsonCraftingPlan::new(&plan, locale), args.format);
+        return;
+    }
+
+    let item_name = |item_id| get_raw_item_name(item_id, locale).unwrap_or("Unknown item");
+    println!("Shopping list:");
+    for item in &plan.shopping_list {
+        match item.hq_amount {
+            0 => println!("{} {}", item.amount, item_name(item.item_id)),
+            hq_amount => println!(
+                "{} {} ({} HQ)",
+                item.amount,
+                item_name(item.item_id),
+                hq_amount
+            ),
+        }
+    }
+
+    for (index, craft) in plan.crafts.iter().enumerate() {
+        let final_state = SimulationState::from_macro(&craft.settings, &craft.actions).unwrap();
+        println!(
+            "\n{}. {} {} ({} recipe {}, {} crafts)",
+            index + 1,
+            craft.amount,
+            item_name(craft.config.recipe.item_id),
+            get_job_name(craft.config.recipe.job_id, locale),
+            craft.config.recipe_id,
+            craft.crafts
+        );
+        if craft.hq_amount != 0 {
+            println!("HQ items needed: {}", craft.hq_amount);
+        }
+        if craft.hq_ingredients != [0; 6] {
+            println!("HQ ingredients: {:?}", craft.hq_ingredients);
+        }
+        println!(
+            "Quality: {}/{}",
+            final_state.quality + craft.config.initial_quality,
+            craft.target_quality
+        );
+        let actions: Vec<String> = craft
+            .actions
+            .iter()
+            .map(|action| format!("{action:?}"))
+            .collect();
+        println!("Actions: {}", actions.join(", "));
+    }
+}
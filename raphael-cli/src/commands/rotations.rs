@@ -0,0 +1,249 @@
+use std::path::{Path, PathBuf};
+
+use clap::Args;
+use raphael_data::CrafterStats;
+use raphael_sim::{Action, Settings, SimulationState};
+use serde::{Deserialize, de::IgnoredAny};
+
+use super::{
+    OutputFormat,
+    json::{JsonSavedRotation, print_json_list},
+    simulate::total_quality,
+};
+
+/// Latest version of the rotations file format that can be read.
+pub const ROTATIONS_FILE_VERSION: u32 = 1;
+
+#[derive(Args, Debug)]
+pub struct RotationsArgs {
+    /// Path to a rotations file exported from the saved rotations of the GUI
+    #[arg(value_name = "PATH")]
+    pub file: PathBuf,
+
+    /// Only show the rotation with this unique ID
+    #[arg(long)]
+    pub id: Option<u64>,
+
+    /// Only show pinned rotations
+    #[arg(long, default_value_t = false)]
+    pub pinned: bool,
+
+    /// The delimiter used to separate the fields of each rotation
+    #[arg(long, alias = "OFS", default_value = "\t", env = "OFS")]
+    output_field_separator: String,
+
+    /// Output format
+    #[arg(long, value_enum, ignore_case = true, default_value_t = OutputFormat::Text)]
+    pub format: OutputFormat,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub enum RecipeInfo {
+    NormalRecipe(u32),
+    /// The recipe and overrides of custom recipes are not shown.
+    CustomRecipe(IgnoredAny, IgnoredAny),
+}
+
+/// Settings a rotation was solved with.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SolveInfo {
+    pub game_settings: Settings,
+    pub initial_quality: u16,
+}
+
+/// A rotation of a rotations file. Fields that are only used by the GUI are ignored.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SavedRotation {
+    pub unique_id: u64,
+    pub name: String,
+    /// Solver version and options, e.g. `Raphael v0.28.2 +backload`.
+    pub solver: String,
+    pub actions: Vec<Action>,
+    /// Missing for rotations saved before v0.21.0.
+    #[serde(default)]
+    pub recipe_info: Option<RecipeInfo>,
+    #[serde(default)]
+    pub solve_info: Option<SolveInfo>,
+    pub food: Option<(u32, bool)>,
+    pub potion: Option<(u32, bool)>,
+    pub crafter_stats: CrafterStats,
+    /// Folder in the saved rotations of the GUI. Empty if the rotation is not in a folder.
+    #[serde(default)]
+    pub folder: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub notes: String,
+}
+
+impl SavedRotation {
+    pub fn recipe_id(&self) -> Option<u32> {
+        match self.recipe_info {
+            Some(RecipeInfo::NormalRecipe(recipe_id)) => Some(recipe_id),
+            Some(RecipeInfo::CustomRecipe(..)) | None => None,
+        }
+    }
+
+    pub fn duration(&self) -> u32 {
+        self.actions
+            .iter()
+            .map(|action| u32::from(action.time_cost()))
+            .sum()
+    }
+
+    /// Final state when simulating the rotation with the settings it was solved with.
+    pub fn final_state(&self) -> Option<SimulationState> {
+        let solve_info = self.solve_info.as_ref()?;
+        let (state, _) =
+            SimulationState::from_macro_continue_on_error(&solve_info.game_settings, &self.actions);
+        Some(state)
+    }
+}
+
+/// Rotations exported from the saved rotations of the GUI.
+/// The version is checked by [`read_rotations_file`] before the rotations are parsed.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RotationsFile {
+    pub pinned: Vec<SavedRotation>,
+    pub solve_history: Vec<SavedRotation>,
+}
+
+impl RotationsFile {
+    /// All rotations and whether they are pinned.
+    pub fn rotations(&self) -> impl Iterator<Item = (&SavedRotation, bool)> {
+        let pinned = self.pinned.iter().map(|rotation| (rotation, true));
+        let history = self.solve_history.iter().map(|rotation| (rotation, false));
+        pinned.chain(history)
+    }
+}
+
+pub fn read_rotations_file(path: &Path) -> Result<RotationsFile, String> {
+    #[derive(Deserialize)]
+    struct Header {
+        version: u32,
+    }
+    let text = std::fs::read_to_string(path)
+        .map_err(|err| format!("Unable to read rotations file {}: {err}", path.display()))?;
+    let header: Header = serde_json::from_str(&text)
+        .map_err(|err| format!("{} is not a rotations file: {err}", path.display()))?;
+    if header.version > ROTATIONS_FILE_VERSION {
+        return Err(format!(
+            "Unsupported rotations file version {} (latest supported version is {})",
+            header.version, ROTATIONS_FILE_VERSION
+        ));
+    }
+    serde_json::from_str(&text)
+        .map_err(|err| format!("Invalid rotations file {}: {err}", path.display()))
+}
+
+pub fn execute(args: &RotationsArgs) {
+    let file = read_rotations_file(&args.file).unwrap_or_else(|err| panic!("{err}"));
+    let rotations: Vec<(&SavedRotation, bool)> = file
+        .rotations()
+        .filter(|(rotation, pinned)| {
+            args.id.is_none_or(|id| rotation.unique_id == id) && (*pinned || !args.pinned)
+        })
+        .collect();
+    if let Some(id) = args.id
+        && rotations.is_empty()
+    {
+        panic!("No rotation with ID {id}");
+    }
+
+    if args.format != OutputFormat::Text {
+        let output: Vec<JsonSavedRotation> = rotations
+            .iter()
+            .map(|(rotation, pinned)| JsonSavedRotation::new(rotation, *pinned))
+            .collect();
+        print_json_list(&output, args.format);
+        return;
+    }
+
+    let separator = &args.output_field_separator;
+    println!(
+        "{}",
+        [
+            "ID",
+            "Pinned",
+            "Name",
+            "Folder",
+            "Tags",
+            "Recipe ID",
+            "Steps",
+            "Duration",
+            "Progress",
+            "Quality",
+            "Solver",
+            "Actions"
+        ]
+        .join(separator)
+    );
+    for (rotation, pinned) in rotations {
+        // Only known if the rotations file contains the settings the rotation was solved with
+        let (progress, quality) = match (rotation.final_state(), &rotation.solve_info) {
+            (Some(state), Some(solve_info)) => (
+                state.progress.to_string(),
+                total_quality(
+                    &state,
+                    solve_info.initial_quality,
+                    solve_info.game_settings.max_quality,
+                )
+                .to_string(),
+            ),
+            _ => (String::new(), String::new()),
+        };
+        let actions: Vec<String> = rotation
+            .actions
+            .iter()
+            .map(|action| format!("{action:?}"))
+            .collect();
+        println!(
+            "{}",
+            [
+                rotation.unique_id.to_string(),
+                pinned.to_string(),
+                rotation.name.clone(),
+                rotation.folder.clone(),
+                rotation.tags.join(","),
+                rotation
+                    .recipe_id()
+                    .map_or(String::new(), |recipe_id| recipe_id.to_string()),
+                rotation.actions.len().to_string(),
+                rotation.duration().to_string(),
+                progress,
+                quality,
+                rotation.solver.clone(),
+                actions.join(","),
+            ]
+            .join(separator)
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotations_file_fixture() {
+        // The same file is read by the saved rotations of the GUI.
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../tests/fixtures/rotations.json");
+        let file = read_rotations_file(&path).unwrap();
+        let rotations: Vec<(&SavedRotation, bool)> = file.rotations().collect();
+        let [(pinned, true), (solved, false)] = rotations.as_slice() else {
+            panic!("Expected one pinned rotation and one rotation in the solve history");
+        };
+        assert_eq!(pinned.unique_id, 42);
+        assert_eq!(pinned.recipe_id(), Some(36183));
+        assert_eq!(pinned.duration(), 11);
+        assert_eq!(pinned.folder, "Endgame");
+        assert_eq!(pinned.tags, ["expert"]);
+        let final_state = pinned.final_state().unwrap();
+        assert!(final_state.progress > 0);
+        // Rotations saved by older versions don't have the optional fields.
+        assert_eq!(solved.unique_id, 7);
+        assert_eq!(solved.recipe_id(), None);
+        assert!(solved.final_state().is_none());
+        assert!(solved.folder.is_empty() && solved.tags.is_empty() && solved.notes.is_empty());
+    }
+}
@@ -1,9 +1,15 @@
+use std::collections::HashMap;
+
 use clap::Args;
 use raphael_data::{
-    RECIPES, STELLAR_MISSIONS, StellarMission, get_job_name, get_stellar_mission_name,
+    StellarMission, game_data, get_job_name, get_recipe, get_stellar_mission,
+    get_stellar_mission_name,
 };
 
-use crate::commands::Language;
+use crate::commands::{
+    Language, OutputFormat,
+    json::{JsonSearchMatch, JsonStellarMission, print_json_list},
+};
 
 #[derive(Args, Debug)]
 pub struct SearchArgs {
@@ -30,45 +36,85 @@ pub struct SearchArgs {
     /// The language the input pattern and output use
     #[arg(short, long, alias = "locale", value_enum, ignore_case = true, default_value_t = Language::EN)]
     language: Language,
+
+    /// Match the pattern against the names of all languages. The output still uses `language` and includes the language that matched
+    #[arg(long, default_value_t = false, requires = "pattern")]
+    any_language: bool,
+
+    /// Output format
+    #[arg(long, value_enum, ignore_case = true, default_value_t = OutputFormat::Text)]
+    pub format: OutputFormat,
 }
 
 pub fn execute(args: &SearchArgs) {
     let locale = args.language.into();
     let mut matches: Vec<(u32, &StellarMission)> = Vec::new();
+    let mut matched_locales = HashMap::new();
 
     if let Some(mission_id_arg) = args.mission_id {
-        matches.extend(
-            STELLAR_MISSIONS
-                .entries()
-                .find(|(mission_id, _)| *mission_id == mission_id_arg),
-        );
+        matches
+            .extend(get_stellar_mission(mission_id_arg).map(|mission| (mission_id_arg, mission)));
     }
-    if let Some(pattern_arg) = &args.pattern {
+    if let Some(pattern_arg) = &args.pattern
+        && args.any_language
+    {
+        for (mission_id, mission, matched_locale) in
+            raphael_data::find_stellar_missions_any_locale(pattern_arg)
+        {
+            matches.push((mission_id, mission));
+            matched_locales.insert(mission_id, matched_locale);
+        }
+    } else if let Some(pattern_arg) = &args.pattern {
         matches.extend(raphael_data::find_stellar_missions(pattern_arg, locale));
     }
     if let Some(recipe_id_arg) = args.recipe_id {
         matches.extend(
-            STELLAR_MISSIONS
-                .entries()
+            game_data()
+                .stellar_missions()
                 .filter(|(_, mission)| mission.recipe_ids.contains(&recipe_id_arg)),
         );
     }
     if let Some(item_id_arg) = args.item_id {
-        matches.extend(STELLAR_MISSIONS.entries().filter(|(mission_id, mission)| {
-            mission.recipe_ids.iter().any(|recipe_id| {
-                if let Some(recipe) = RECIPES.get(*recipe_id) {
-                    recipe.item_id == item_id_arg
-                } else {
-                    log::warn!(
-                        "Mission {} references missing recipe id {}",
-                        mission_id,
-                        recipe_id
-                    );
-                    false
-                }
-            })
-        }));
+        matches.extend(
+            game_data()
+                .stellar_missions()
+                .filter(|(mission_id, mission)| {
+                    mission.recipe_ids.iter().any(|recipe_id| {
+                        if let Some(recipe) = get_recipe(*recipe_id) {
+                            recipe.item_id == item_id_arg
+                        } else {
+                            log::warn!(
+                                "Mission {} references missing recipe id {}",
+                                mission_id,
+                                recipe_id
+                            );
+                            false
+                        }
+                    })
+                }),
+        );
     };
+    if args.format != OutputFormat::Text && args.any_language {
+        let matches: Vec<JsonSearchMatch<JsonStellarMission>> = matches
+            .into_iter()
+            .map(|(mission_id, mission)| JsonSearchMatch {
+                entry: JsonStellarMission::new(mission_id, mission, locale),
+                matched_language: matched_locales
+                    .get(&mission_id)
+                    .map(|locale| locale.short_code()),
+            })
+            .collect();
+        print_json_list(&matches, args.format);
+        return;
+    }
+    if args.format != OutputFormat::Text {
+        let matches: Vec<JsonStellarMission> = matches
+            .into_iter()
+            .map(|(mission_id, mission)| JsonStellarMission::new(mission_id, mission, locale))
+            .collect();
+        print_json_list(&matches, args.format);
+        return;
+    }
     if matches.is_empty() {
         println!("No matches found");
         return;
@@ -77,8 +123,18 @@ pub fn execute(args: &SearchArgs) {
     for (mission_id, mission) in matches {
         let mission_name =
             get_stellar_mission_name(mission_id, locale).unwrap_or("Unknown Mission");
+        let matched_language = match matched_locales.get(&mission_id) {
+            Some(matched_locale) => {
+                format!(
+                    "{}{}",
+                    args.output_field_separator,
+                    matched_locale.short_code()
+                )
+            }
+            None => String::new(),
+        };
         println!(
-            "{mission_id}{separator}{job_name}{separator}{mission_name}{separator}{recipe_id_0}{separator}{recipe_id_1}{separator}{recipe_id_2}{separator}{recipe_id_3}{separator}{recipe_id_4}",
+            "{mission_id}{separator}{job_name}{separator}{mission_name}{separator}{recipe_id_0}{separator}{recipe_id_1}{separator}{recipe_id_2}{separator}{recipe_id_3}{separator}{recipe_id_4}{matched_language}",
             job_name = get_job_name(mission.job_id, locale),
             separator = args.output_field_separator,
             recipe_id_0 = mission
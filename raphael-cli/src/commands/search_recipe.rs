@@ -1,26 +1,116 @@
-use clap::Args;
-use raphael_data::{RECIPES, Recipe, STELLAR_MISSIONS, get_job_name, get_raw_item_name};
+use std::collections::HashMap;
 
-use crate::commands::Language;
+use clap::{ArgGroup, Args};
+use raphael_data::{
+    JOB_NAMES_EN, Recipe, RecipeFilter, RecipeSortOrder, game_data, get_job_name,
+    get_raw_item_name, get_recipe, get_stellar_mission,
+};
+
+use crate::commands::{
+    Language, OutputFormat,
+    json::{JsonRecipe, JsonSearchMatch, print_json_list},
+};
+
+#[derive(Copy, Clone, clap::ValueEnum, Debug)]
+pub enum SortOrder {
+    /// Best name matches first
+    Relevance,
+    /// Lowest recipe level first
+    Rlvl,
+    /// Highest recipe level first
+    RlvlDesc,
+}
+
+impl From<SortOrder> for RecipeSortOrder {
+    fn from(val: SortOrder) -> Self {
+        match val {
+            SortOrder::Relevance => Self::Relevance,
+            SortOrder::Rlvl => Self::RecipeLevelAscending,
+            SortOrder::RlvlDesc => Self::RecipeLevelDescending,
+        }
+    }
+}
+
+fn parse_job(s: &str) -> Result<u8, String> {
+    JOB_NAMES_EN
+        .iter()
+        .position(|name| name.eq_ignore_ascii_case(s))
+        .map(|job_id| job_id as u8)
+        .ok_or_else(|| {
+            format!(
+                "Unknown job '{s}', expected one of {}",
+                JOB_NAMES_EN.join(", ")
+            )
+        })
+}
 
 #[derive(Args, Debug)]
+#[command(group(
+    ArgGroup::new("query")
+        .required(true)
+        .multiple(true)
+        .args([
+            "pattern", "recipe_id", "item_id", "mission_id", "job", "min_rlvl", "max_rlvl",
+            "job_level", "expert", "max_level_scaling", "stellar", "craftsmanship", "control",
+        ])
+))]
 pub struct SearchArgs {
     /// Search string to use, can be partial name
-    #[arg(short, long, required_unless_present_any(["recipe_id", "item_id", "mission_id"]), conflicts_with_all(["recipe_id", "item_id"]))]
+    #[arg(short, long, conflicts_with_all(["recipe_id", "item_id"]))]
     pub pattern: Option<String>,
 
     /// Recipe ID to search for
-    #[arg(short, long, required_unless_present_any(["pattern", "item_id", "mission_id"]), conflicts_with = "item_id")]
+    #[arg(short, long, conflicts_with = "item_id")]
     pub recipe_id: Option<u32>,
 
     /// Item ID to search for
-    #[arg(short, long, required_unless_present_any(["pattern", "recipe_id", "mission_id"]))]
+    #[arg(short, long)]
     pub item_id: Option<u32>,
 
     /// Stellar mission ID to list associated recipes for
-    #[arg(long, required_unless_present_any(["pattern", "recipe_id", "item_id"]), conflicts_with_all(["pattern", "recipe_id", "item_id"]))]
+    #[arg(long, conflicts_with_all(["pattern", "recipe_id", "item_id"]))]
     pub mission_id: Option<u32>,
 
+    /// Only show recipes of this job, e.g. 'CRP'
+    #[arg(long, value_parser = parse_job)]
+    pub job: Option<u8>,
+
+    /// Only show recipes with at least this recipe level
+    #[arg(long)]
+    pub min_rlvl: Option<u16>,
+
+    /// Only show recipes with at most this recipe level
+    #[arg(long)]
+    pub max_rlvl: Option<u16>,
+
+    /// Only show recipes of this job level
+    #[arg(long)]
+    pub job_level: Option<u8>,
+
+    /// Only show expert recipes (true) or non-expert recipes (false)
+    #[arg(long)]
+    pub expert: Option<bool>,
+
+    /// Only show recipes that scale with the job level (true) or recipes that do not (false)
+    #[arg(long)]
+    pub max_level_scaling: Option<bool>,
+
+    /// Only show recipes that are part of a stellar mission (true) or recipes that are not (false)
+    #[arg(long)]
+    pub stellar: Option<bool>,
+
+    /// Only show recipes whose craftsmanship requirement is met by this craftsmanship
+    #[arg(long)]
+    pub craftsmanship: Option<u16>,
+
+    /// Only show recipes whose control requirement is met by this control
+    #[arg(long)]
+    pub control: Option<u16>,
+
+    /// Order of the results
+    #[arg(long, value_enum, ignore_case = true, default_value_t = SortOrder::Relevance)]
+    pub sort: SortOrder,
+
     /// The delimiter the output uses between fields
     #[arg(long, alias = "OFS", default_value = " ", env = "OFS")]
     output_field_separator: String,
@@ -28,17 +118,44 @@ pub struct SearchArgs {
     /// The language the input pattern and output use
     #[arg(short, long, alias = "locale", value_enum, ignore_case = true, default_value_t = Language::EN)]
     language: Language,
+
+    /// Match the pattern against the names of all languages. The output still uses `language` and includes the language that matched
+    #[arg(long, default_value_t = false, requires = "pattern")]
+    any_language: bool,
+
+    /// Output format
+    #[arg(long, value_enum, ignore_case = true, default_value_t = OutputFormat::Text)]
+    pub format: OutputFormat,
+}
+
+impl SearchArgs {
+    fn recipe_filter(&self) -> RecipeFilter {
+        RecipeFilter {
+            job_id: self.job,
+            min_recipe_level: self.min_rlvl,
+            max_recipe_level: self.max_rlvl,
+            job_level: self.job_level,
+            is_expert: self.expert,
+            has_max_level_scaling: self.max_level_scaling,
+            is_stellar_mission_recipe: self.stellar,
+            craftsmanship: self.craftsmanship,
+            control: self.control,
+            sort_order: self.sort.into(),
+        }
+    }
 }
 
 pub fn execute(args: &SearchArgs) {
     let locale = args.language.into();
+    let filter = args.recipe_filter();
     let mut matches: Vec<(u32, &Recipe)> = Vec::new();
+    let mut matched_locales = HashMap::new();
 
     if let Some(mission_id_arg) = args.mission_id {
-        match STELLAR_MISSIONS.get(mission_id_arg) {
+        match get_stellar_mission(mission_id_arg) {
             Some(mission) => {
-                for recipe_id in mission.recipe_ids {
-                    match RECIPES.get(*recipe_id) {
+                for recipe_id in &*mission.recipe_ids {
+                    match get_recipe(*recipe_id) {
                         Some(recipe) => matches.push((*recipe_id, recipe)),
                         None => log::warn!(
                             "Mission {} references missing recipe id {}",
@@ -54,32 +171,58 @@ pub fn execute(args: &SearchArgs) {
             }
         }
     }
-    if let Some(pattern_arg) = &args.pattern {
+    if let Some(pattern_arg) = &args.pattern
+        && args.any_language
+    {
+        for (recipe_id, recipe, matched_locale) in
+            raphael_data::find_recipes_any_locale(pattern_arg)
+        {
+            matches.push((recipe_id, recipe));
+            matched_locales.insert(recipe_id, matched_locale);
+        }
+    } else if let Some(pattern_arg) = &args.pattern {
         matches.extend(raphael_data::find_recipes(pattern_arg, locale));
+    } else if args.recipe_id.is_none() && args.item_id.is_none() && args.mission_id.is_none() {
+        // Only filters were given, so search all recipes.
+        matches.extend(game_data().recipes());
     }
     if let Some(recipe_id_arg) = args.recipe_id {
-        matches.extend(
-            RECIPES
-                .get(recipe_id_arg)
-                .map(|recipe| (recipe_id_arg, recipe)),
-        );
+        matches.extend(get_recipe(recipe_id_arg).map(|recipe| (recipe_id_arg, recipe)));
     }
     if let Some(item_id_arg) = args.item_id {
         log::warn!(
             "Item IDs do not uniquely corresponds to a specific recipe config. Consider using the recipe ID instead."
         );
-        matches.extend(
-            raphael_data::RECIPES
-                .entries()
-                .filter_map(|(recipe_id, recipe)| {
-                    if recipe.item_id == item_id_arg {
-                        Some((recipe_id, recipe))
-                    } else {
-                        None
-                    }
-                }),
-        );
+        matches.extend(game_data().recipes().filter_map(|(recipe_id, recipe)| {
+            if recipe.item_id == item_id_arg {
+                Some((recipe_id, recipe))
+            } else {
+                None
+            }
+        }));
     };
+    filter.apply(&mut matches);
+    if args.format != OutputFormat::Text && args.any_language {
+        let matches: Vec<JsonSearchMatch<JsonRecipe>> = matches
+            .into_iter()
+            .map(|(recipe_id, recipe)| JsonSearchMatch {
+                entry: JsonRecipe::new(recipe_id, recipe, locale),
+                matched_language: matched_locales
+                    .get(&recipe_id)
+                    .map(|locale| locale.short_code()),
+            })
+            .collect();
+        print_json_list(&matches, args.format);
+        return;
+    }
+    if args.format != OutputFormat::Text {
+        let matches: Vec<JsonRecipe> = matches
+            .into_iter()
+            .map(|(recipe_id, recipe)| JsonRecipe::new(recipe_id, recipe, locale))
+            .collect();
+        print_json_list(&matches, args.format);
+        return;
+    }
     if matches.is_empty() {
         println!("No matches found");
         return;
@@ -87,8 +230,18 @@ pub fn execute(args: &SearchArgs) {
 
     for (recipe_id, recipe) in matches {
         let name = get_raw_item_name(recipe.item_id, locale).unwrap_or("Unknown item");
+        let matched_language = match matched_locales.get(&recipe_id) {
+            Some(matched_locale) => {
+                format!(
+                    "{}{}",
+                    args.output_field_separator,
+                    matched_locale.short_code()
+                )
+            }
+            None => String::new(),
+        };
         println!(
-            "{recipe_id}{separator}{job_name}{separator}{item_id}{separator}{name}",
+            "{recipe_id}{separator}{job_name}{separator}{item_id}{separator}{name}{matched_language}",
             job_name = get_job_name(recipe.job_id, locale),
             item_id = recipe.item_id,
             separator = args.output_field_separator,
@@ -0,0 +1,534 @@
+use std::{
+    collections::HashMap,
+    io::{BufRead, BufReader, Read, Write},
+    net::{IpAddr, Ipv4Addr, TcpListener, TcpStream},
+    panic::AssertUnwindSafe,
+    sync::{
+        Arc, Mutex, MutexGuard, PoisonError,
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        mpsc::{Receiver, sync_channel},
+    },
+    time::{Duration, Instant},
+};
+
+use clap::{Args, ValueEnum};
+use raphael_data::{Locale, find_recipes, get_recipe, parse_macro};
+use raphael_sim::Action;
+use raphael_solver::AtomicFlag;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    Language,
+    batch::{BatchJob, solve_job},
+    json::{JsonCraftOutput, JsonRecipe},
+    simulate::parse_action,
+};
+
+/// Requests with a larger body are rejected with `413 Payload Too Large`.
+const MAX_BODY_SIZE: usize = 1024 * 1024;
+/// Requests with a larger request line and headers are rejected with `431 Request Header Fields Too Large`.
+const MAX_HEADER_SIZE: u64 = 16 * 1024;
+/// Requests with more headers are rejected with `431 Request Header Fields Too Large`.
+const MAX_HEADER_COUNT: usize = 100;
+/// Read and write timeout of a connection.
+const CONNECTION_TIMEOUT: Duration = Duration::from_secs(30);
+/// Solve requests are rejected with `503 Service Unavailable` while this many jobs are running.
+const MAX_RUNNING_JOBS: usize = 4;
+/// Number of threads handling connections. Further connections wait until a thread is free.
+const CONNECTION_WORKERS: usize = 8;
+/// Finished jobs are removed this long after they finish, unless they are removed with `DELETE` before that.
+const FINISHED_JOB_RETENTION: Duration = Duration::from_secs(10 * 60);
+
+#[derive(Args, Debug)]
+pub struct ServeArgs {
+    /// Address to bind to. Only bind to a non-local address on trusted networks, there is no authentication
+    #[arg(long, default_value_t = IpAddr::V4(Ipv4Addr::LOCALHOST))]
+    pub address: IpAddr,
+
+    /// Port to listen on
+    #[arg(short, long, default_value_t = 3690)]
+    pub port: u16,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum JobStatus {
+    Running,
+    Done,
+    Failed,
+    Cancelled,
+}
+
+struct Job {
+    status: JobStatus,
+    processed_nodes: Arc<AtomicUsize>,
+    interrupt_signal: AtomicFlag,
+    result: Option<JsonCraftOutput>,
+    error: Option<String>,
+    /// `None` while the job is running.
+    finished_at: Option<Instant>,
+}
+
+#[derive(Serialize)]
+struct JobInfo<'a> {
+    job_id: u64,
+    status: JobStatus,
+    /// Number of search nodes processed by the solver so far.
+    processed_nodes: usize,
+    error: Option<&'a str>,
+    result: Option<&'a JsonCraftOutput>,
+}
+
+impl Job {
+    fn info(&self, job_id: u64) -> JobInfo<'_> {
+        JobInfo {
+            job_id,
+            status: self.status,
+            processed_nodes: self.processed_nodes.load(Ordering::Relaxed),
+            error: self.error.as_deref(),
+            result: self.result.as_ref(),
+        }
+    }
+}
+
+#[derive(Default)]
+struct ServerState {
+    next_job_id: AtomicU64,
+    jobs: Mutex<HashMap<u64, Job>>,
+}
+
+impl ServerState {
+    /// Locks the jobs, removing jobs that finished more than [`FINISHED_JOB_RETENTION`] ago.
+    fn jobs(&self) -> MutexGuard<'_, HashMap<u64, Job>> {
+        // A poisoned lock only means that a thread panicked while holding it, the jobs themselves are still valid.
+        let mut jobs = self.jobs.lock().unwrap_or_else(PoisonError::into_inner);
+        jobs.retain(|_, job| {
+            job.finished_at
+                .is_none_or(|finished_at| finished_at.elapsed() < FINISHED_JOB_RETENTION)
+        });
+        jobs
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SimulateRequest {
+    job: BatchJob,
+    /// Action names or IDs, see `raphael-cli simulate --help`.
+    #[serde(default)]
+    actions: Vec<String>,
+    /// In-game macro text. Used instead of `actions` if present.
+    #[serde(default, rename = "macro")]
+    macro_text: Option<String>,
+}
+
+struct Request {
+    method: String,
+    path: String,
+    query: HashMap<String, String>,
+    body: Vec<u8>,
+}
+
+struct Response {
+    status: u16,
+    body: String,
+}
+
+impl Response {
+    fn json<T: Serialize>(status: u16, value: &T) -> Self {
+        Self {
+            status,
+            body: serde_json::to_string(value).expect("Failed to serialize response"),
+        }
+    }
+
+    fn error(status: u16, message: impl Into<String>) -> Self {
+        Self::json(status, &serde_json::json!({ "error": message.into() }))
+    }
+}
+
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'+' => decoded.push(b' '),
+            b'%' if index + 2 < bytes.len() => {
+                let high = char::from(bytes[index + 1]).to_digit(16);
+                let low = char::from(bytes[index + 2]).to_digit(16);
+                match (high, low) {
+                    (Some(high), Some(low)) => {
+                        decoded.push((high * 16 + low) as u8);
+                        index += 2;
+                    }
+                    _ => decoded.push(b'%'),
+                }
+            }
+            byte => decoded.push(byte),
+        }
+        index += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn read_request(stream: &mut TcpStream) -> Result<Request, Response> {
+    let bad_request = |err: std::io::Error| Response::error(400, err.to_string());
+    let mut reader = BufReader::new(stream);
+    // The request line and headers share a single size limit.
+    let mut head = reader.by_ref().take(MAX_HEADER_SIZE);
+    let mut read_head_line = |line: &mut String| -> Result<(), Response> {
+        head.read_line(line).map_err(bad_request)?;
+        if line.ends_with('\n') {
+            Ok(())
+        } else if head.limit() == 0 {
+            Err(Response::error(
+                431,
+                format!("Request headers are larger than {MAX_HEADER_SIZE} bytes"),
+            ))
+        } else {
+            Err(Response::error(400, "Unexpected end of request"))
+        }
+    };
+    let mut request_line = String::new();
+    read_head_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let method = parts
+        .next()
+        .ok_or_else(|| Response::error(400, "Missing method"))?
+        .to_owned();
+    let target = parts
+        .next()
+        .ok_or_else(|| Response::error(400, "Missing request target"))?;
+
+    let mut content_length = 0;
+    let mut header_count = 0;
+    loop {
+        let mut header = String::new();
+        read_head_line(&mut header)?;
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        header_count += 1;
+        if header_count > MAX_HEADER_COUNT {
+            return Err(Response::error(
+                431,
+                format!("Request has more than {MAX_HEADER_COUNT} headers"),
+            ));
+        }
+        if let Some((name, value)) = header.split_once(':')
+            && name.trim().eq_ignore_ascii_case("content-length")
+        {
+            content_length = value
+                .trim()
+                .parse()
+                .map_err(|_| Response::error(400, "Invalid Content-Length"))?;
+        }
+    }
+    if content_length > MAX_BODY_SIZE {
+        return Err(Response::error(
+            413,
+            format!("Request body is larger than {MAX_BODY_SIZE} bytes"),
+        ));
+    }
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).map_err(bad_request)?;
+
+    let (path, query_string) = target.split_once('?').unwrap_or((target, ""));
+    let query = query_string
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (percent_decode(key), percent_decode(value))
+        })
+        .collect();
+    Ok(Request {
+        method,
+        path: percent_decode(path),
+        query,
+        body,
+    })
+}
+
+fn write_response(stream: &mut TcpStream, response: &Response) -> std::io::Result<()> {
+    let reason = match response.status {
+        200 => "OK",
+        202 => "Accepted",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        413 => "Payload Too Large",
+        431 => "Request Header Fields Too Large",
+        503 => "Service Unavailable",
+        _ => "Internal Server Error",
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        reason,
+        response.body.len(),
+        response.body
+    )?;
+    stream.flush()
+}
+
+fn query_locale(request: &Request) -> Result<Locale, Response> {
+    match request.query.get("language") {
+        Some(language) => Language::from_str(language, true)
+            .map(Locale::from)
+            .map_err(|err| Response::error(400, err)),
+        None => Ok(Locale::EN),
+    }
+}
+
+fn handle_search(request: &Request) -> Response {
+    let locale = match query_locale(request) {
+        Ok(locale) => locale,
+        Err(response) => return response,
+    };
+    let Some(pattern) = request.query.get("pattern") else {
+        return Response::error(400, "Missing query parameter `pattern`");
+    };
+    let recipes: Vec<JsonRecipe> = find_recipes(pattern, locale)
+        .map(|(recipe_id, recipe)| JsonRecipe::new(recipe_id, recipe, locale))
+        .collect();
+    Response::json(200, &recipes)
+}
+
+fn handle_recipe(request: &Request, recipe_id: &str) -> Response {
+    let locale = match query_locale(request) {
+        Ok(locale) => locale,
+        Err(response) => return response,
+    };
+    let Ok(recipe_id) = recipe_id.parse() else {
+        return Response::error(400, format!("Invalid recipe ID: {recipe_id}"));
+    };
+    match get_recipe(recipe_id) {
+        Some(recipe) => Response::json(200, &JsonRecipe::new(recipe_id, recipe, locale)),
+        None => Response::error(404, format!("Unable to find Recipe with ID: {recipe_id}")),
+    }
+}
+
+fn handle_simulate(request: &Request) -> Response {
+    let simulate_request: SimulateRequest = match serde_json::from_slice(&request.body) {
+        Ok(simulate_request) => simulate_request,
+        Err(err) => return Response::error(400, err.to_string()),
+    };
+    let actions: Vec<Action> = match &simulate_request.macro_text {
+        Some(macro_text) => match parse_macro(macro_text) {
+            Ok(actions) => actions,
+            Err(errors) => {
+                let errors: Vec<String> = errors.iter().map(ToString::to_string).collect();
+                return Response::error(400, errors.join("\n"));
+            }
+        },
+        None => match simulate_request
+            .actions
+            .iter()
+            .map(|action| parse_action(action).ok_or_else(|| format!("Unknown action: {action}")))
+            .collect()
+        {
+            Ok(actions) => actions,
+            Err(err) => return Response::error(400, err),
+        },
+    };
+    let config = match simulate_request.job.craft_config() {
+        Ok(config) => config,
+        Err(err) => return Response::error(400, err),
+    };
+    let mut settings = config.settings;
+    settings.adversarial = simulate_request.job.adversarial;
+    Response::json(
+        200,
+        &JsonCraftOutput::new(&config, &settings, &actions, None, None),
+    )
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown cause"
+    }
+}
+
+fn handle_solve(request: &Request, state: &Arc<ServerState>) -> Response {
+    let job: BatchJob = match serde_json::from_slice(&request.body) {
+        Ok(job) => job,
+        Err(err) => return Response::error(400, err.to_string()),
+    };
+    // Validate the job before starting the solver so that invalid input is reported immediately.
+    if let Err(err) = job.craft_config() {
+        return Response::error(400, err);
+    }
+
+    let mut jobs = state.jobs();
+    let running_jobs = jobs
+        .values()
+        .filter(|job| job.status == JobStatus::Running)
+        .count();
+    if running_jobs >= MAX_RUNNING_JOBS {
+        return Response::error(
+            503,
+            format!(
+                "Too many running jobs, at most {MAX_RUNNING_JOBS} jobs can run at the same time"
+            ),
+        );
+    }
+    let job_id = state.next_job_id.fetch_add(1, Ordering::Relaxed);
+    let processed_nodes = Arc::new(AtomicUsize::new(0));
+    let interrupt_signal = AtomicFlag::new();
+    jobs.insert(
+        job_id,
+        Job {
+            status: JobStatus::Running,
+            processed_nodes: processed_nodes.clone(),
+            interrupt_signal: interrupt_signal.clone(),
+            result: None,
+            error: None,
+            finished_at: None,
+        },
+    );
+    drop(jobs);
+
+    let state = state.clone();
+    std::thread::spawn(move || {
+        // Catch panics so that the job does not stay `Running` forever if the solver panics.
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+            solve_job(
+                &job,
+                Box::new(|nodes| processed_nodes.store(nodes, Ordering::Relaxed)),
+                interrupt_signal.clone(),
+            )
+        }))
+        .unwrap_or_else(|payload| Err(format!("Solver panicked: {}", panic_message(&*payload))));
+        let mut jobs = state.jobs();
+        if let Some(job) = jobs.get_mut(&job_id) {
+            job.finished_at = Some(Instant::now());
+            match result {
+                Ok(output) => {
+                    job.status = JobStatus::Done;
+                    job.result = Some(output);
+                }
+                Err(_) if interrupt_signal.is_set() => job.status = JobStatus::Cancelled,
+                Err(err) => {
+                    job.status = JobStatus::Failed;
+                    job.error = Some(err);
+                }
+            }
+        }
+    });
+
+    Response::json(202, &serde_json::json!({ "job_id": job_id }))
+}
+
+fn handle_job(request: &Request, state: &ServerState, job_id: &str) -> Response {
+    let Ok(job_id) = job_id.parse() else {
+        return Response::error(400, format!("Invalid job ID: {job_id}"));
+    };
+    let mut jobs = state.jobs();
+    match request.method.as_str() {
+        "GET" => match jobs.get(&job_id) {
+            Some(job) => Response::json(200, &job.info(job_id)),
+            None => Response::error(404, format!("No such job: {job_id}")),
+        },
+        // Cancels a running job, or removes a finished job.
+        // Finished jobs are also removed automatically after `FINISHED_JOB_RETENTION`.
+        "DELETE" => {
+            let Some(job) = jobs.get(&job_id) else {
+                return Response::error(404, format!("No such job: {job_id}"));
+            };
+            if job.status == JobStatus::Running {
+                job.interrupt_signal.set();
+                return Response::json(200, &job.info(job_id));
+            }
+            let job = jobs.remove(&job_id).unwrap();
+            Response::json(200, &job.info(job_id))
+        }
+        _ => Response::error(405, "Method not allowed"),
+    }
+}
+
+fn handle_request(request: &Request, state: &Arc<ServerState>) -> Response {
+    let segments: Vec<&str> = request
+        .path
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect();
+    match (request.method.as_str(), segments.as_slice()) {
+        ("GET", ["search"]) => handle_search(request),
+        ("GET", ["recipe", recipe_id]) => handle_recipe(request, recipe_id),
+        ("POST", ["simulate"]) => handle_simulate(request),
+        ("POST", ["solve"]) => handle_solve(request, state),
+        (_, ["jobs", job_id]) => handle_job(request, state, job_id),
+        (_, ["search" | "recipe" | "simulate" | "solve", ..]) => {
+            Response::error(405, "Method not allowed")
+        }
+        _ => Response::error(404, format!("No such endpoint: {}", request.path)),
+    }
+}
+
+fn handle_connection(mut stream: TcpStream, state: &Arc<ServerState>) {
+    if let Err(err) = stream
+        .set_read_timeout(Some(CONNECTION_TIMEOUT))
+        .and_then(|()| stream.set_write_timeout(Some(CONNECTION_TIMEOUT)))
+    {
+        log::warn!("Failed to set connection timeout: {err}");
+        return;
+    }
+    let response = match read_request(&mut stream) {
+        Ok(request) => {
+            log::debug!("{} {}", request.method, request.path);
+            handle_request(&request, state)
+        }
+        Err(response) => response,
+    };
+    if let Err(err) = write_response(&mut stream, &response) {
+        log::warn!("Failed to write response: {err}");
+    }
+}
+
+fn connection_worker(connections: &Mutex<Receiver<TcpStream>>, state: &Arc<ServerState>) {
+    loop {
+        let stream = connections
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .recv();
+        let Ok(stream) = stream else {
+            return;
+        };
+        // Keep the worker alive if handling a single connection panics.
+        if std::panic::catch_unwind(AssertUnwindSafe(|| handle_connection(stream, state))).is_err()
+        {
+            log::error!("Panicked while handling a connection");
+        }
+    }
+}
+
+pub fn execute(args: &ServeArgs) {
+    let listener = TcpListener::bind((args.address, args.port))
+        .unwrap_or_else(|err| panic!("Unable to bind to {}:{}: {err}", args.address, args.port));
+    log::info!("Listening on http://{}:{}", args.address, args.port);
+    let state = Arc::new(ServerState::default());
+    // Accepting blocks while all workers are busy and the queue is full.
+    let (sender, receiver) = sync_channel(CONNECTION_WORKERS);
+    let receiver = Arc::new(Mutex::new(receiver));
+    for _ in 0..CONNECTION_WORKERS {
+        let receiver = receiver.clone();
+        let state = state.clone();
+        std::thread::spawn(move || connection_worker(&receiver, &state));
+    }
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => sender
+                .send(stream)
+                .expect("Connection workers exited unexpectedly"),
+            Err(err) => log::warn!("Failed to accept connection: {err}"),
+        }
+    }
+}
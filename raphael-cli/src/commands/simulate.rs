@@ -0,0 +1,236 @@
+use std::path::{Path, PathBuf};
+
+use clap::Args;
+use raphael_data::{find_action_by_name, parse_macro};
+use raphael_sim::{Action, ActionError, Condition, Effects, Settings, SimulationState};
+use strum::IntoEnumIterator;
+
+use super::{
+    OutputFormat,
+    craft_args::{CraftArgs, CraftConfig},
+    json::{JsonCraftOutput, print_json},
+};
+
+#[derive(Args, Debug)]
+pub struct SimulateArgs {
+    #[command(flatten)]
+    pub craft_args: CraftArgs,
+
+    /// Comma-separated list of actions. Each action can be an action name (e.g. `BasicSynthesis` or `"Basic Synthesis"`) or an action ID
+    #[arg(
+        short,
+        long,
+        value_delimiter = ',',
+        required_unless_present = "macro_file",
+        conflicts_with = "macro_file"
+    )]
+    pub actions: Vec<String>,
+
+    /// Path to a text file containing an in-game macro, e.g. `/ac "Basic Synthesis" <wait.3>`
+    #[arg(long, value_name = "PATH")]
+    pub macro_file: Option<PathBuf>,
+
+    /// Enable adversarial simulator (quality is calculated assuming the worst possible sequence of conditions)
+    #[arg(long, default_value_t = false)]
+    pub adversarial: bool,
+
+    /// The delimiter used to separate the fields of each simulation step
+    #[arg(long, alias = "OFS", default_value = "\t", env = "OFS")]
+    output_field_separator: String,
+
+    /// Output format
+    #[arg(long, value_enum, ignore_case = true, default_value_t = OutputFormat::Text)]
+    pub format: OutputFormat,
+}
+
+/// Parses an action from its enum name (e.g. `BasicSynthesis`), its in-game name in any locale, or its action ID.
+pub fn parse_action(s: &str) -> Option<Action> {
+    let s = s.trim();
+    if let Ok(action_id) = s.parse::<u32>() {
+        return Action::iter().find(|action| action.action_id() == action_id);
+    }
+    Action::iter()
+        .find(|action| format!("{action:?}").eq_ignore_ascii_case(s))
+        .or_else(|| find_action_by_name(s.trim_matches('"')))
+}
+
+/// Parses a comma-separated list of actions.
+pub fn parse_actions<S: AsRef<str>>(actions: &[S]) -> Result<Vec<Action>, String> {
+    actions
+        .iter()
+        .map(|action| {
+            let action = action.as_ref();
+            parse_action(action).ok_or_else(|| format!("Unknown action: {action}"))
+        })
+        .collect()
+}
+
+/// Reads the actions of an in-game macro from a text file.
+pub fn read_macro_file(path: &Path) -> Result<Vec<Action>, String> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|err| format!("Unable to read macro file {}: {err}", path.display()))?;
+    parse_macro(&text).map_err(|errors| {
+        for error in &errors {
+            log::error!("{error}");
+        }
+        format!("Unable to parse macro file {}", path.display())
+    })
+}
+
+fn read_actions(args: &SimulateArgs) -> Result<Vec<Action>, String> {
+    match &args.macro_file {
+        Some(path) => read_macro_file(path),
+        None => parse_actions(&args.actions),
+    }
+}
+
+/// Simulates the actions one at a time, continuing past actions that cannot be used.
+/// Returns the state after each action. Actions that cannot be used leave the state unchanged.
+pub fn simulate_steps(
+    settings: &Settings,
+    actions: &[Action],
+) -> Vec<(SimulationState, Result<(), ActionError>)> {
+    let mut state = SimulationState::new(settings);
+    let mut steps = Vec::with_capacity(actions.len());
+    for action in actions {
+        match state.use_action(*action, Condition::Normal, settings) {
+            Ok(new_state) => {
+                state = new_state;
+                steps.push((state, Ok(())));
+            }
+            Err(err) => steps.push((state, Err(err))),
+        }
+    }
+    steps
+}
+
+/// Quality of the state including the initial quality, capped at the max quality.
+pub fn total_quality(state: &SimulationState, initial_quality: u16, max_quality: u16) -> u16 {
+    std::cmp::min(initial_quality.saturating_add(state.quality), max_quality)
+}
+
+/// State after the last step, or the initial state if there are no steps.
+pub fn final_state(
+    settings: &Settings,
+    steps: &[(SimulationState, Result<(), ActionError>)],
+) -> SimulationState {
+    steps
+        .last()
+        .map_or_else(|| SimulationState::new(settings), |(state, _)| *state)
+}
+
+/// Formats all active effects, e.g. `IQ 4, Innovation 2, Manipulation 5`.
+fn format_effects(effects: Effects) -> String {
+    let mut active_effects = Vec::new();
+    let counters = [
+        ("IQ", effects.inner_quiet()),
+        ("Waste Not", effects.waste_not()),
+        ("Innovation", effects.innovation()),
+        ("Veneration", effects.veneration()),
+        ("Great Strides", effects.great_strides()),
+        ("Muscle Memory", effects.muscle_memory()),
+        ("Manipulation", effects.manipulation()),
+        ("Stellar Steady Hand", effects.stellar_steady_hand()),
+    ];
+    for (name, value) in counters {
+        if value != 0 {
+            active_effects.push(format!("{name} {value}"));
+        }
+    }
+    let flags = [
+        ("Trained Perfection", effects.trained_perfection_active()),
+        ("Heart and Soul", effects.heart_and_soul_active()),
+        ("Expedience", effects.expedience()),
+    ];
+    for (name, active) in flags {
+        if active {
+            active_effects.push(name.to_owned());
+        }
+    }
+    active_effects.join(", ")
+}
+
+pub fn execute(args: &SimulateArgs) {
+    let config = args
+        .craft_args
+        .craft_config()
+        .unwrap_or_else(|err| panic!("{err}"));
+    let CraftConfig {
+        recipe_id,
+        mut settings,
+        initial_quality,
+        ..
+    } = config;
+    settings.adversarial = args.adversarial;
+
+    let actions = read_actions(args).unwrap_or_else(|err| panic!("{err}"));
+
+    if args.format != OutputFormat::Text {
+        let output = JsonCraftOutput::new(&config, &settings, &actions, None, None);
+        print_json(&output, args.format);
+        return;
+    }
+
+    let steps = simulate_steps(&settings, &actions);
+    let final_state = final_state(&settings, &steps);
+
+    let separator = &args.output_field_separator;
+    println!("Recipe ID: {}", recipe_id);
+    println!(
+        "{}",
+        [
+            "Step",
+            "Action",
+            "Progress",
+            "Quality",
+            "Durability",
+            "CP",
+            "Effects",
+            "Error"
+        ]
+        .join(separator)
+    );
+    for (index, (action, (state, error))) in actions.iter().zip(steps.iter()).enumerate() {
+        let error = match error {
+            Ok(()) => String::new(),
+            Err(err) => format!("{err:?}"),
+        };
+        println!(
+            "{}",
+            [
+                (index + 1).to_string(),
+                format!("{action:?}"),
+                state.progress.to_string(),
+                total_quality(state, initial_quality, settings.max_quality).to_string(),
+                state.durability.to_string(),
+                state.cp.to_string(),
+                format_effects(state.effects),
+                error,
+            ]
+            .join(separator)
+        );
+    }
+
+    let final_quality = total_quality(&final_state, initial_quality, settings.max_quality);
+    let duration: u32 = actions
+        .iter()
+        .map(|action| u32::from(action.time_cost()))
+        .sum();
+    println!();
+    println!(
+        "Progress: {}/{}",
+        final_state.progress, settings.max_progress
+    );
+    println!("Quality: {}/{}", final_quality, settings.max_quality);
+    println!(
+        "Durability: {}/{}",
+        final_state.durability, settings.max_durability
+    );
+    println!("CP: {}/{}", final_state.cp, settings.max_cp);
+    println!("Steps: {}", actions.len());
+    println!("Duration: {} seconds", duration);
+    println!(
+        "Errors: {}",
+        steps.iter().filter(|(_, error)| error.is_err()).count()
+    );
+}
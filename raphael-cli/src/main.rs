@@ -10,6 +10,10 @@ mod commands;
 struct Cli {
     #[command(subcommand)]
     command: Commands,
+
+    /// Load game data from a JSON data pack instead of using the built-in game data
+    #[arg(long, global = true, env = "RAPHAEL_DATA_PACK")]
+    data_pack: Option<std::path::PathBuf>,
 }
 
 #[derive(Subcommand, Debug)]
@@ -18,8 +22,22 @@ enum Commands {
     Search(SearchCli),
     /// Solve a crafting rotation
     Solve(commands::solve::SolveArgs),
+    /// Simulate a list of actions or an in-game macro
+    Simulate(commands::simulate::SimulateArgs),
+    /// Compare two or more rotations for the same recipe and stats
+    Compare(commands::compare::CompareArgs),
+    /// List the rotations of a rotations file exported from the GUI
+    Rotations(commands::rotations::RotationsArgs),
+    /// Solve many crafting rotations from a CSV or JSON lines job file
+    Batch(commands::batch::BatchArgs),
+    /// Run a local HTTP server that exposes the solver, simulator and recipe search as a JSON API
+    Serve(commands::serve::ServeArgs),
+    /// Plan all crafts needed for an item, including intermediate items, and solve a rotation for each of them
+    Plan(commands::plan::PlanArgs),
     /// Show ingredients for a recipe
     Ingredients(commands::ingredients::IngredientsArgs),
+    /// Find the materia melds for a gear set with which the solver reaches max quality on the most recipes
+    Meld(commands::meld::MeldArgs),
 }
 
 #[derive(Parser, Debug)]
@@ -40,6 +58,19 @@ enum SearchCommands {
     Mission(commands::search_mission::SearchArgs),
 }
 
+fn load_data_pack(path: &std::path::Path) {
+    let file = std::fs::File::open(path)
+        .unwrap_or_else(|err| panic!("Unable to read data pack {}: {err}", path.display()));
+    let data_pack = raphael_data::DataPack::from_json_reader(std::io::BufReader::new(file))
+        .unwrap_or_else(|err| panic!("Unable to load data pack {}: {err}", path.display()));
+    log::info!(
+        "Using data pack {} (data version {:?})",
+        path.display(),
+        data_pack.data_version
+    );
+    raphael_data::load_data_pack(data_pack).unwrap();
+}
+
 fn main() {
     env_logger::builder()
         .format_timestamp(None)
@@ -48,6 +79,10 @@ fn main() {
 
     let cli = Cli::parse();
 
+    if let Some(path) = &cli.data_pack {
+        load_data_pack(path);
+    }
+
     match &cli.command {
         Commands::Search(search_cli) => match &search_cli.command {
             Some(command) => match command {
@@ -57,6 +92,13 @@ fn main() {
             None => commands::search_recipe::execute(&search_cli.recipe_search_args),
         },
         Commands::Solve(args) => commands::solve::execute(args),
+        Commands::Simulate(args) => commands::simulate::execute(args),
+        Commands::Batch(args) => commands::batch::execute(args),
+        Commands::Compare(args) => commands::compare::execute(args),
+        Commands::Rotations(args) => commands::rotations::execute(args),
+        Commands::Serve(args) => commands::serve::execute(args),
+        Commands::Plan(args) => commands::plan::execute(args),
         Commands::Ingredients(args) => commands::ingredients::execute(args),
+        Commands::Meld(args) => commands::meld::execute(args),
     }
 }
@@ -205,6 +205,6 @@ const VALID_ITEM_ACTION_TYPE_IDS: &[u32] = &[
 ];
 
 // https://github.com/xivapi/ffxiv-datamining/blob/35e435494317723be856f18fb3b48f526316656e/csv/BaseParam.csv
-const CRAFTSMANSHIP_PARAM: u32 = 70;
-const CONTROL_PARAM: u32 = 71;
-const CP_PARAM: u32 = 11;
+pub(crate) const CRAFTSMANSHIP_PARAM: u32 = 70;
+pub(crate) const CONTROL_PARAM: u32 = 71;
+pub(crate) const CP_PARAM: u32 = 11;
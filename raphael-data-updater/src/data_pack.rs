@@ -0,0 +1,281 @@
+use std::collections::BTreeMap;
+use std::iter::repeat;
+
+use json::{JsonValue, object};
+
+use crate::{
+    Consumable, Gear, GearStats, Ingredient, Item, ItemName, Lang, LatestVersions,
+    LevelAdjustTableEntry, Materia, Recipe, RecipeLevel, StellarMission, StellarMissionName,
+    XivapiVersionInfo,
+};
+
+/// Format version of the data pack.
+/// Must match `DATA_PACK_FORMAT_VERSION` in `raphael-data` so that the generated data pack can be loaded at runtime.
+pub const DATA_PACK_FORMAT_VERSION: u32 = 1;
+
+/// All game data tables that are exported by the updater.
+pub struct GameTables<'a> {
+    pub rlvls: &'a [RecipeLevel],
+    pub level_adjust_table: &'a [LevelAdjustTableEntry],
+    pub recipes: &'a [Recipe],
+    pub items: &'a [Item],
+    pub meals: &'a [Consumable],
+    pub potions: &'a [Consumable],
+    pub gear: &'a [Gear],
+    pub materia: &'a [Materia],
+    pub stellar_missions: &'a [StellarMission],
+    pub item_names: Vec<(Lang, &'a [ItemName])>,
+    pub stellar_mission_names: Vec<(Lang, &'a [StellarMissionName])>,
+}
+
+/// All data that is exported by the updater, bundled into a single JSON document.
+///
+/// The field names and layout match the `DataPack` type of `raphael-data`.
+/// The xivapi versions are included as an additional field.
+pub struct DataPack<'a> {
+    pub latest_versions: &'a LatestVersions,
+    pub tables: &'a GameTables<'a>,
+}
+
+impl DataPack<'_> {
+    pub fn to_json(&self) -> JsonValue {
+        let versions = self.latest_versions;
+        let data_version = versions
+            .global
+            .name
+            .as_ref()
+            .unwrap_or(&versions.global.key);
+        let xivapi_versions = object! {
+            global: version_to_json(&versions.global),
+            cn: version_to_json(&versions.cn),
+            kr: version_to_json(&versions.kr),
+            tw: version_to_json(&versions.tw),
+        };
+        let recipes = map_to_json(
+            self.tables
+                .recipes
+                .iter()
+                .map(|recipe| (recipe.id, recipe_to_json(recipe))),
+        );
+        let items = map_to_json(
+            self.tables
+                .items
+                .iter()
+                .map(|item| (item.id, item_to_json(item))),
+        );
+        let item_names = names_to_json(&self.tables.item_names, |item_name| {
+            (item_name.id, &item_name.name)
+        });
+        let stellar_missions =
+            map_to_json(self.tables.stellar_missions.iter().map(|stellar_mission| {
+                (stellar_mission.id, stellar_mission_to_json(stellar_mission))
+            }));
+        let stellar_mission_names =
+            names_to_json(&self.tables.stellar_mission_names, |mission_name| {
+                (mission_name.id, &mission_name.name)
+            });
+        object! {
+            format_version: DATA_PACK_FORMAT_VERSION,
+            data_version: data_version.as_str(),
+            xivapi_versions: xivapi_versions,
+            recipes: recipes,
+            items: items,
+            item_names: item_names,
+            item_recipes: self.item_recipes_to_json(),
+            rlvls: self.rlvls_to_json(),
+            level_adjust_table: self.level_adjust_table_to_json(),
+            meals: self.tables.meals.iter().map(consumable_to_json).collect::<Vec<_>>(),
+            potions: self.tables.potions.iter().map(consumable_to_json).collect::<Vec<_>>(),
+            gear: self.tables.gear.iter().map(gear_to_json).collect::<Vec<_>>(),
+            materia: self.tables.materia.iter().map(materia_to_json).collect::<Vec<_>>(),
+            stellar_missions: stellar_missions,
+            stellar_mission_names: stellar_mission_names,
+            recipe_to_stellar_mission_links: self.recipe_to_stellar_mission_links_to_json(),
+        }
+    }
+
+    fn recipe_to_stellar_mission_links_to_json(&self) -> JsonValue {
+        map_to_json(
+            self.tables
+                .stellar_missions
+                .iter()
+                .flat_map(|stellar_mission| {
+                    stellar_mission
+                        .recipe_ids
+                        .iter()
+                        .map(|recipe_id| (*recipe_id, stellar_mission.id.into()))
+                }),
+        )
+    }
+
+    fn item_recipes_to_json(&self) -> JsonValue {
+        let mut item_recipes: BTreeMap<u32, Vec<JsonValue>> = BTreeMap::new();
+        for recipe in self.tables.recipes {
+            item_recipes
+                .entry(recipe.item_id)
+                .or_default()
+                .push(object! {
+                    recipe_id: recipe.id,
+                    result_amount: recipe.result_amount,
+                });
+        }
+        map_to_json(
+            item_recipes
+                .into_iter()
+                .map(|(item_id, recipes)| (item_id, recipes.into())),
+        )
+    }
+
+    fn rlvls_to_json(&self) -> JsonValue {
+        // Same layout as the generated `rlvls.rs`, i.e. the rlvl is the index into the array.
+        let mut rlvls = vec![rlvl_to_json(&RecipeLevel::default())];
+        rlvls.extend(self.tables.rlvls.iter().map(rlvl_to_json));
+        rlvls.into()
+    }
+
+    fn level_adjust_table_to_json(&self) -> JsonValue {
+        // Same layout as the generated `level_adjust_table.rs`, i.e. the job level is the index into the array.
+        let mut level_adjust_table = vec![0];
+        level_adjust_table.extend(
+            self.tables
+                .level_adjust_table
+                .iter()
+                .map(|entry| entry.rlvl),
+        );
+        level_adjust_table.into()
+    }
+}
+
+fn names_to_json<T>(
+    names: &[(Lang, &[T])],
+    id_and_name: impl Fn(&T) -> (u32, &String),
+) -> JsonValue {
+    let mut json = JsonValue::new_object();
+    for (lang, names) in names {
+        let names = map_to_json(names.iter().map(|name| {
+            let (id, name) = id_and_name(name);
+            (id, name.as_str().into())
+        }));
+        json.insert(&lang.shortcode().to_uppercase(), names)
+            .unwrap();
+    }
+    json
+}
+
+fn map_to_json(entries: impl Iterator<Item = (u32, JsonValue)>) -> JsonValue {
+    let mut json = JsonValue::new_object();
+    for (id, value) in entries {
+        json.insert(&id.to_string(), value).unwrap();
+    }
+    json
+}
+
+fn version_to_json(version: &XivapiVersionInfo) -> JsonValue {
+    object! {
+        key: version.key.as_str(),
+        name: version.name.as_deref(),
+    }
+}
+
+fn recipe_to_json(recipe: &Recipe) -> JsonValue {
+    let ingredients: Vec<JsonValue> = recipe
+        .ingredients
+        .iter()
+        .copied()
+        .chain(repeat(Ingredient::default()))
+        .take(6)
+        .map(|ingredient| {
+            object! {
+                item_id: ingredient.item_id,
+                amount: ingredient.amount,
+            }
+        })
+        .collect();
+    object! {
+        job_id: recipe.job_id,
+        item_id: recipe.item_id,
+        max_level_scaling: recipe.max_level_scaling,
+        recipe_level: recipe.recipe_level,
+        progress_factor: recipe.progress_factor,
+        quality_factor: recipe.quality_factor,
+        durability_factor: recipe.durability_factor,
+        material_factor: recipe.material_factor,
+        ingredients: ingredients,
+        is_expert: recipe.is_expert,
+        req_craftsmanship: recipe.req_craftsmanship,
+        req_control: recipe.req_control,
+    }
+}
+
+fn item_to_json(item: &Item) -> JsonValue {
+    object! {
+        item_level: item.item_level,
+        always_collectable: item.always_collectable,
+    }
+}
+
+fn rlvl_to_json(rlvl: &RecipeLevel) -> JsonValue {
+    object! {
+        job_level: rlvl.job_level,
+        max_progress: rlvl.max_progress,
+        max_quality: rlvl.max_quality,
+        max_durability: rlvl.max_durability,
+        progress_div: rlvl.progress_div,
+        quality_div: rlvl.quality_div,
+        progress_mod: rlvl.progress_mod,
+        quality_mod: rlvl.quality_mod,
+    }
+}
+
+fn consumable_to_json(consumable: &Consumable) -> JsonValue {
+    object! {
+        item_id: consumable.item_id,
+        item_level: consumable.item_level,
+        hq: consumable.hq,
+        craft_rel: consumable.craft_rel,
+        craft_max: consumable.craft_max,
+        control_rel: consumable.control_rel,
+        control_max: consumable.control_max,
+        cp_rel: consumable.cp_rel,
+        cp_max: consumable.cp_max,
+    }
+}
+
+fn gear_stats_to_json(stats: &GearStats) -> JsonValue {
+    object! {
+        craftsmanship: stats.craftsmanship,
+        control: stats.control,
+        cp: stats.cp,
+    }
+}
+
+fn gear_to_json(gear: &Gear) -> JsonValue {
+    object! {
+        item_id: gear.item_id,
+        equip_slot: gear.equip_slot,
+        equip_level: gear.equip_level,
+        item_level: gear.item_level,
+        materia_slots: gear.materia_slots,
+        advanced_melding: gear.advanced_melding,
+        stats: gear_stats_to_json(&gear.stats),
+        hq_stats: gear_stats_to_json(&gear.hq_stats),
+        meld_cap: gear_stats_to_json(&gear.meld_cap),
+    }
+}
+
+fn materia_to_json(materia: &Materia) -> JsonValue {
+    object! {
+        item_id: materia.item_id,
+        stat: materia.stat,
+        grade: materia.grade,
+        value: materia.value,
+    }
+}
+
+fn stellar_mission_to_json(stellar_mission: &StellarMission) -> JsonValue {
+    object! {
+        job_id: stellar_mission.job_id,
+        stellar_steady_hand_charges: stellar_mission.stellar_steady_hand_charges(),
+        recipe_ids: stellar_mission.recipe_ids.clone(),
+    }
+}
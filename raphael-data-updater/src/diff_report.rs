@@ -0,0 +1,424 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+use json::{JsonValue, object};
+
+/// A single entry of a table, e.g. a recipe.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffEntry {
+    pub id: String,
+    /// English name of the entry, if it has one.
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldChange {
+    pub field: String,
+    /// Old value, formatted as JSON.
+    pub old: String,
+    /// New value, formatted as JSON.
+    pub new: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModifiedEntry {
+    pub entry: DiffEntry,
+    pub changes: Vec<FieldChange>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TableDiff {
+    pub added: Vec<DiffEntry>,
+    pub removed: Vec<DiffEntry>,
+    pub modified: Vec<ModifiedEntry>,
+}
+
+impl TableDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenamedEntry {
+    /// Locale shortcode, e.g. "EN".
+    pub locale: String,
+    pub id: String,
+    pub old_name: String,
+    pub new_name: String,
+}
+
+/// Summary of the changes between two data packs generated by the updater.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DiffReport {
+    pub old_data_version: String,
+    pub new_data_version: String,
+    pub recipes: TableDiff,
+    pub rlvls: TableDiff,
+    pub level_adjust_table: TableDiff,
+    pub meals: TableDiff,
+    pub potions: TableDiff,
+    pub stellar_missions: TableDiff,
+    pub renamed_items: Vec<RenamedEntry>,
+    pub renamed_stellar_missions: Vec<RenamedEntry>,
+}
+
+impl DiffReport {
+    /// Compares two data packs in the format written by [`crate::DataPack::to_json`].
+    pub fn new(old: &JsonValue, new: &JsonValue) -> Self {
+        let item_name = |item_id: &JsonValue| {
+            let item_id = item_id.as_u32()?.to_string();
+            english_name(new, "item_names", &item_id)
+                .or_else(|| english_name(old, "item_names", &item_id))
+        };
+        let recipe_name = |recipe: &JsonValue| item_name(&recipe["item_id"]);
+        let consumable_name = |consumable: &JsonValue| {
+            let name = item_name(&consumable["item_id"])?;
+            match consumable["hq"].as_bool() {
+                Some(true) => Some(format!("{name} (HQ)")),
+                _ => Some(name),
+            }
+        };
+        let mission_name = |id: &str| {
+            english_name(new, "stellar_mission_names", id)
+                .or_else(|| english_name(old, "stellar_mission_names", id))
+        };
+        Self {
+            old_data_version: data_version(old),
+            new_data_version: data_version(new),
+            recipes: diff_tables(
+                &object_entries(&old["recipes"]),
+                &object_entries(&new["recipes"]),
+                |_, recipe| recipe_name(recipe),
+            ),
+            rlvls: diff_tables(
+                &array_entries(&old["rlvls"]),
+                &array_entries(&new["rlvls"]),
+                |_, _| None,
+            ),
+            level_adjust_table: diff_tables(
+                &array_entries(&old["level_adjust_table"]),
+                &array_entries(&new["level_adjust_table"]),
+                |_, _| None,
+            ),
+            meals: diff_tables(
+                &consumable_entries(&old["meals"]),
+                &consumable_entries(&new["meals"]),
+                |_, consumable| consumable_name(consumable),
+            ),
+            potions: diff_tables(
+                &consumable_entries(&old["potions"]),
+                &consumable_entries(&new["potions"]),
+                |_, consumable| consumable_name(consumable),
+            ),
+            stellar_missions: diff_tables(
+                &object_entries(&old["stellar_missions"]),
+                &object_entries(&new["stellar_missions"]),
+                |id, _| mission_name(id),
+            ),
+            renamed_items: renamed_entries(&old["item_names"], &new["item_names"]),
+            renamed_stellar_missions: renamed_entries(
+                &old["stellar_mission_names"],
+                &new["stellar_mission_names"],
+            ),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.recipes.is_empty()
+            && self.rlvls.is_empty()
+            && self.level_adjust_table.is_empty()
+            && self.meals.is_empty()
+            && self.potions.is_empty()
+            && self.stellar_missions.is_empty()
+            && self.renamed_items.is_empty()
+            && self.renamed_stellar_missions.is_empty()
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = String::new();
+        writeln!(markdown, "# Game data changes").unwrap();
+        writeln!(markdown).unwrap();
+        writeln!(
+            markdown,
+            "Data version: {} → {}",
+            self.old_data_version, self.new_data_version
+        )
+        .unwrap();
+        if self.is_empty() {
+            writeln!(markdown).unwrap();
+            writeln!(markdown, "No changes.").unwrap();
+            return markdown;
+        }
+        for (title, table) in self.tables() {
+            write_table_markdown(&mut markdown, title, table);
+        }
+        for (title, renamed) in [
+            ("Renamed items", &self.renamed_items),
+            ("Renamed stellar missions", &self.renamed_stellar_missions),
+        ] {
+            if renamed.is_empty() {
+                continue;
+            }
+            writeln!(markdown).unwrap();
+            writeln!(markdown, "## {title}").unwrap();
+            writeln!(markdown).unwrap();
+            writeln!(markdown, "| Locale | ID | Old name | New name |").unwrap();
+            writeln!(markdown, "| --- | --- | --- | --- |").unwrap();
+            for entry in renamed {
+                writeln!(
+                    markdown,
+                    "| {} | {} | {} | {} |",
+                    entry.locale,
+                    entry.id,
+                    escape_markdown(&entry.old_name),
+                    escape_markdown(&entry.new_name)
+                )
+                .unwrap();
+            }
+        }
+        markdown
+    }
+
+    pub fn to_json(&self) -> JsonValue {
+        let mut tables = JsonValue::new_object();
+        for (title, table) in self.tables() {
+            let key = title.to_lowercase().replace(' ', "_");
+            tables.insert(&key, table_to_json(table)).unwrap();
+        }
+        let renamed_items: Vec<JsonValue> =
+            self.renamed_items.iter().map(renamed_to_json).collect();
+        let renamed_stellar_missions: Vec<JsonValue> = self
+            .renamed_stellar_missions
+            .iter()
+            .map(renamed_to_json)
+            .collect();
+        object! {
+            old_data_version: self.old_data_version.as_str(),
+            new_data_version: self.new_data_version.as_str(),
+            tables: tables,
+            renamed_items: renamed_items,
+            renamed_stellar_missions: renamed_stellar_missions,
+        }
+    }
+
+    fn tables(&self) -> [(&'static str, &TableDiff); 6] {
+        [
+            ("Recipes", &self.recipes),
+            ("Recipe levels", &self.rlvls),
+            ("Level adjust table", &self.level_adjust_table),
+            ("Meals", &self.meals),
+            ("Potions", &self.potions),
+            ("Stellar missions", &self.stellar_missions),
+        ]
+    }
+}
+
+fn data_version(data_pack: &JsonValue) -> String {
+    data_pack["data_version"]
+        .as_str()
+        .unwrap_or("unknown")
+        .to_owned()
+}
+
+fn english_name(data_pack: &JsonValue, names: &str, id: &str) -> Option<String> {
+    data_pack[names]["EN"][id].as_str().map(str::to_owned)
+}
+
+fn object_entries(table: &JsonValue) -> BTreeMap<u32, (String, &JsonValue)> {
+    table
+        .entries()
+        .filter_map(|(id, value)| Some((id.parse().ok()?, (id.to_owned(), value))))
+        .collect()
+}
+
+fn array_entries(table: &JsonValue) -> BTreeMap<u32, (String, &JsonValue)> {
+    table
+        .members()
+        .enumerate()
+        .map(|(index, value)| (index as u32, (index.to_string(), value)))
+        .collect()
+}
+
+/// Consumables are identified by their item ID and whether they are HQ.
+fn consumable_entries(table: &JsonValue) -> BTreeMap<(u32, bool), (String, &JsonValue)> {
+    table
+        .members()
+        .filter_map(|consumable| {
+            let item_id = consumable["item_id"].as_u32()?;
+            let hq = consumable["hq"].as_bool()?;
+            let id = format!("{item_id}{}", if hq { " HQ" } else { "" });
+            Some(((item_id, hq), (id, consumable)))
+        })
+        .collect()
+}
+
+fn diff_tables<K: Ord>(
+    old: &BTreeMap<K, (String, &JsonValue)>,
+    new: &BTreeMap<K, (String, &JsonValue)>,
+    name: impl Fn(&str, &JsonValue) -> Option<String>,
+) -> TableDiff {
+    let entry = |id: &String, value: &JsonValue| DiffEntry {
+        id: id.clone(),
+        name: name(id, value),
+    };
+    let mut diff = TableDiff::default();
+    for (key, (id, old_value)) in old {
+        match new.get(key) {
+            None => diff.removed.push(entry(id, old_value)),
+            Some((_, new_value)) if old_value != new_value => {
+                diff.modified.push(ModifiedEntry {
+                    entry: entry(id, new_value),
+                    changes: field_changes(old_value, new_value),
+                });
+            }
+            Some(_) => (),
+        }
+    }
+    for (key, (id, new_value)) in new {
+        if !old.contains_key(key) {
+            diff.added.push(entry(id, new_value));
+        }
+    }
+    diff
+}
+
+fn field_changes(old: &JsonValue, new: &JsonValue) -> Vec<FieldChange> {
+    if !old.is_object() || !new.is_object() {
+        return vec![FieldChange {
+            field: String::new(),
+            old: old.dump(),
+            new: new.dump(),
+        }];
+    }
+    let fields: BTreeSet<&str> = old
+        .entries()
+        .chain(new.entries())
+        .map(|(field, _)| field)
+        .collect();
+    fields
+        .into_iter()
+        .filter(|field| old[*field] != new[*field])
+        .map(|field| FieldChange {
+            field: field.to_owned(),
+            old: old[field].dump(),
+            new: new[field].dump(),
+        })
+        .collect()
+}
+
+fn renamed_entries(old_names: &JsonValue, new_names: &JsonValue) -> Vec<RenamedEntry> {
+    let mut renamed = Vec::new();
+    for (locale, new_locale_names) in new_names.entries() {
+        let old_locale_names = object_entries(&old_names[locale]);
+        for (key, (id, new_name)) in object_entries(new_locale_names) {
+            if let Some((_, old_name)) = old_locale_names.get(&key)
+                && old_name != &new_name
+            {
+                renamed.push(RenamedEntry {
+                    locale: locale.to_owned(),
+                    id,
+                    old_name: old_name.as_str().unwrap_or_default().to_owned(),
+                    new_name: new_name.as_str().unwrap_or_default().to_owned(),
+                });
+            }
+        }
+    }
+    renamed
+}
+
+fn escape_markdown(text: &str) -> String {
+    text.replace('|', "\\|")
+}
+
+fn entry_markdown(entry: &DiffEntry) -> String {
+    match &entry.name {
+        Some(name) => format!("{} ({})", entry.id, escape_markdown(name)),
+        None => entry.id.clone(),
+    }
+}
+
+fn write_table_markdown(markdown: &mut String, title: &str, table: &TableDiff) {
+    if table.is_empty() {
+        return;
+    }
+    writeln!(markdown).unwrap();
+    writeln!(markdown, "## {title}").unwrap();
+    for (label, entries) in [("Added", &table.added), ("Removed", &table.removed)] {
+        if entries.is_empty() {
+            continue;
+        }
+        writeln!(markdown).unwrap();
+        writeln!(markdown, "{label} ({}):", entries.len()).unwrap();
+        writeln!(markdown).unwrap();
+        for entry in entries {
+            writeln!(markdown, "- {}", entry_markdown(entry)).unwrap();
+        }
+    }
+    if !table.modified.is_empty() {
+        writeln!(markdown).unwrap();
+        writeln!(markdown, "Modified ({}):", table.modified.len()).unwrap();
+        writeln!(markdown).unwrap();
+        for modified in &table.modified {
+            let changes: Vec<String> = modified
+                .changes
+                .iter()
+                .map(|change| match change.field.is_empty() {
+                    true => format!("`{}` → `{}`", change.old, change.new),
+                    false => format!("{}: `{}` → `{}`", change.field, change.old, change.new),
+                })
+                .collect();
+            writeln!(
+                markdown,
+                "- {}: {}",
+                entry_markdown(&modified.entry),
+                changes.join(", ")
+            )
+            .unwrap();
+        }
+    }
+}
+
+fn entry_to_json(entry: &DiffEntry) -> JsonValue {
+    object! {
+        id: entry.id.as_str(),
+        name: entry.name.as_deref(),
+    }
+}
+
+fn table_to_json(table: &TableDiff) -> JsonValue {
+    let modified: Vec<JsonValue> = table
+        .modified
+        .iter()
+        .map(|modified| {
+            let changes: Vec<JsonValue> = modified
+                .changes
+                .iter()
+                .map(|change| {
+                    object! {
+                        field: change.field.as_str(),
+                        old: json::parse(&change.old).unwrap(),
+                        new: json::parse(&change.new).unwrap(),
+                    }
+                })
+                .collect();
+            let mut json = entry_to_json(&modified.entry);
+            json.insert("changes", changes).unwrap();
+            json
+        })
+        .collect();
+    object! {
+        added: table.added.iter().map(entry_to_json).collect::<Vec<_>>(),
+        removed: table.removed.iter().map(entry_to_json).collect::<Vec<_>>(),
+        modified: modified,
+    }
+}
+
+fn renamed_to_json(renamed: &RenamedEntry) -> JsonValue {
+    object! {
+        locale: renamed.locale.as_str(),
+        id: renamed.id.as_str(),
+        old_name: renamed.old_name.as_str(),
+        new_name: renamed.new_name.as_str(),
+    }
+}
@@ -0,0 +1,379 @@
+use std::collections::HashMap;
+
+use crate::{
+    SheetData,
+    consumable::{CONTROL_PARAM, CP_PARAM, CRAFTSMANSHIP_PARAM},
+};
+
+/// Row of the `Item` sheet with the fields that are relevant for gear.
+#[derive(Debug, Clone)]
+pub struct GearItem {
+    pub id: u32,
+    pub item_level: u32,
+    pub equip_level: u32,
+    pub equip_slot_category: u32,
+    pub materia_slots: u32,
+    pub advanced_melding: bool,
+    pub can_be_hq: bool,
+    pub param: Vec<u32>,
+    pub value: Vec<i32>,
+    pub param_special: Vec<u32>,
+    pub value_special: Vec<i32>,
+}
+
+impl SheetData for GearItem {
+    const SHEET: &'static str = "Item";
+    const REQUIRED_FIELDS: &[&str] = &[
+        "LevelItem@as(raw)",
+        "LevelEquip",
+        "EquipSlotCategory@as(raw)",
+        "MateriaSlotCount",
+        "IsAdvancedMeldingPermitted",
+        "CanBeHq",
+        "BaseParam@as(raw)",
+        "BaseParamValue",
+        "BaseParamSpecial@as(raw)",
+        "BaseParamValueSpecial",
+    ];
+
+    fn row_id(&self) -> u32 {
+        self.id
+    }
+
+    fn from_json(value: &json::JsonValue) -> Option<Self> {
+        let fields = &value["fields"];
+        Some(Self {
+            id: value["row_id"].as_u32().unwrap(),
+            item_level: fields["LevelItem@as(raw)"].as_u32().unwrap(),
+            equip_level: fields["LevelEquip"].as_u32().unwrap(),
+            equip_slot_category: fields["EquipSlotCategory@as(raw)"].as_u32().unwrap(),
+            materia_slots: fields["MateriaSlotCount"].as_u32().unwrap(),
+            advanced_melding: fields["IsAdvancedMeldingPermitted"].as_bool().unwrap(),
+            can_be_hq: fields["CanBeHq"].as_bool().unwrap(),
+            param: fields["BaseParam@as(raw)"]
+                .members()
+                .map(|value| value.as_u32().unwrap())
+                .collect(),
+            value: fields["BaseParamValue"]
+                .members()
+                .map(|value| value.as_i32().unwrap())
+                .collect(),
+            param_special: fields["BaseParamSpecial@as(raw)"]
+                .members()
+                .map(|value| value.as_u32().unwrap())
+                .collect(),
+            value_special: fields["BaseParamValueSpecial"]
+                .members()
+                .map(|value| value.as_i32().unwrap())
+                .collect(),
+        })
+    }
+}
+
+/// Row of the `ItemLevel` sheet, i.e. the maximum crafting stats of each item level.
+#[derive(Debug, Clone, Copy)]
+pub struct ItemLevelStats {
+    pub item_level: u32,
+    pub craftsmanship: u32,
+    pub control: u32,
+    pub cp: u32,
+}
+
+impl SheetData for ItemLevelStats {
+    const SHEET: &'static str = "ItemLevel";
+    const REQUIRED_FIELDS: &[&str] = &["Craftsmanship", "Control", "CP"];
+
+    fn row_id(&self) -> u32 {
+        self.item_level
+    }
+
+    fn from_json(value: &json::JsonValue) -> Option<Self> {
+        let fields = &value["fields"];
+        Some(Self {
+            item_level: value["row_id"].as_u32().unwrap(),
+            craftsmanship: fields["Craftsmanship"].as_u32().unwrap(),
+            control: fields["Control"].as_u32().unwrap(),
+            cp: fields["CP"].as_u32().unwrap(),
+        })
+    }
+}
+
+/// Row of the `BaseParam` sheet with the share (in per mille) of the item level stats that each equip slot can have.
+#[derive(Debug, Clone)]
+pub struct BaseParamSlots {
+    pub id: u32,
+    /// Indexed by `EquipSlotCategory - 1`.
+    pub slot_percent: Vec<u32>,
+}
+
+impl SheetData for BaseParamSlots {
+    const SHEET: &'static str = "BaseParam";
+    const REQUIRED_FIELDS: &[&str] = EQUIP_SLOT_PERCENT_FIELDS;
+
+    fn row_id(&self) -> u32 {
+        self.id
+    }
+
+    fn from_json(value: &json::JsonValue) -> Option<Self> {
+        let fields = &value["fields"];
+        Some(Self {
+            id: value["row_id"].as_u32().unwrap(),
+            slot_percent: EQUIP_SLOT_PERCENT_FIELDS
+                .iter()
+                .map(|field| fields[*field].as_u32().unwrap())
+                .collect(),
+        })
+    }
+}
+
+/// Row of the `Materia` sheet. Each row contains all grades of one kind of materia.
+#[derive(Debug, Clone)]
+pub struct MateriaRow {
+    pub id: u32,
+    pub item_ids: Vec<u32>,
+    pub param: u32,
+    pub values: Vec<i32>,
+}
+
+impl SheetData for MateriaRow {
+    const SHEET: &'static str = "Materia";
+    const REQUIRED_FIELDS: &[&str] = &["Item@as(raw)", "BaseParam@as(raw)", "Value"];
+
+    fn row_id(&self) -> u32 {
+        self.id
+    }
+
+    fn from_json(value: &json::JsonValue) -> Option<Self> {
+        let fields = &value["fields"];
+        Some(Self {
+            id: value["row_id"].as_u32().unwrap(),
+            item_ids: fields["Item@as(raw)"]
+                .members()
+                .map(|value| value.as_u32().unwrap())
+                .collect(),
+            param: fields["BaseParam@as(raw)"].as_u32().unwrap(),
+            values: fields["Value"]
+                .members()
+                .map(|value| value.as_i32().unwrap())
+                .collect(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GearStats {
+    pub craftsmanship: u32,
+    pub control: u32,
+    pub cp: u32,
+}
+
+impl GearStats {
+    fn add(&mut self, param: u32, value: i32) {
+        let value = value.max(0) as u32;
+        match param {
+            CRAFTSMANSHIP_PARAM => self.craftsmanship += value,
+            CONTROL_PARAM => self.control += value,
+            CP_PARAM => self.cp += value,
+            _ => (),
+        }
+    }
+}
+
+impl std::fmt::Display for GearStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "GearStats {{ ")?;
+        write!(f, "craftsmanship: {}, ", self.craftsmanship)?;
+        write!(f, "control: {}, ", self.control)?;
+        write!(f, "cp: {}, ", self.cp)?;
+        write!(f, "}}")?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Gear {
+    pub item_id: u32,
+    pub equip_slot: &'static str,
+    pub equip_level: u32,
+    pub item_level: u32,
+    pub materia_slots: u32,
+    pub advanced_melding: bool,
+    pub stats: GearStats,
+    pub hq_stats: GearStats,
+    pub meld_cap: GearStats,
+}
+
+impl std::fmt::Display for Gear {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Gear {{ ")?;
+        write!(f, "item_id: {}, ", self.item_id)?;
+        write!(f, "equip_slot: EquipSlot::{}, ", self.equip_slot)?;
+        write!(f, "equip_level: {}, ", self.equip_level)?;
+        write!(f, "item_level: {}, ", self.item_level)?;
+        write!(f, "materia_slots: {}, ", self.materia_slots)?;
+        write!(f, "advanced_melding: {}, ", self.advanced_melding)?;
+        write!(f, "stats: {}, ", self.stats)?;
+        write!(f, "hq_stats: {}, ", self.hq_stats)?;
+        write!(f, "meld_cap: {}, ", self.meld_cap)?;
+        write!(f, "}}")?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Materia {
+    pub item_id: u32,
+    pub stat: &'static str,
+    pub grade: u32,
+    pub value: u32,
+}
+
+impl std::fmt::Display for Materia {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Materia {{ ")?;
+        write!(f, "item_id: {}, ", self.item_id)?;
+        write!(f, "stat: CraftingStat::{}, ", self.stat)?;
+        write!(f, "grade: {}, ", self.grade)?;
+        write!(f, "value: {}, ", self.value)?;
+        write!(f, "}}")?;
+        Ok(())
+    }
+}
+
+/// Returns all items that have crafting stats, including the meld cap of each crafting stat.
+pub fn instantiate_gear(
+    gear_items: &[GearItem],
+    item_levels: &[ItemLevelStats],
+    base_params: &[BaseParamSlots],
+) -> Vec<Gear> {
+    let item_levels: HashMap<u32, &ItemLevelStats> = item_levels
+        .iter()
+        .map(|item_level| (item_level.item_level, item_level))
+        .collect();
+    let base_params: HashMap<u32, &BaseParamSlots> = base_params
+        .iter()
+        .map(|base_param| (base_param.id, base_param))
+        .collect();
+    let meld_cap = |item_level: &ItemLevelStats, param: u32, slot_index: usize| {
+        let max_value = match param {
+            CRAFTSMANSHIP_PARAM => item_level.craftsmanship,
+            CONTROL_PARAM => item_level.control,
+            _ => item_level.cp,
+        };
+        let slot_percent = base_params
+            .get(&param)
+            .and_then(|base_param| base_param.slot_percent.get(slot_index))
+            .copied()
+            .unwrap_or_default();
+        (max_value * slot_percent + 500) / 1000
+    };
+
+    let mut gear = Vec::new();
+    for item in gear_items {
+        let Some(equip_slot) = equip_slot_name(item.equip_slot_category) else {
+            continue;
+        };
+        if !item.param.iter().any(|param| is_crafting_param(*param)) {
+            continue;
+        }
+        let Some(item_level) = item_levels.get(&item.item_level) else {
+            log::warn!(
+                "Item {} has unknown item level {}",
+                item.id,
+                item.item_level
+            );
+            continue;
+        };
+        let mut stats = GearStats::default();
+        for (param, value) in item.param.iter().zip(item.value.iter()) {
+            stats.add(*param, *value);
+        }
+        let mut hq_stats = stats;
+        if item.can_be_hq {
+            for (param, value) in item.param_special.iter().zip(item.value_special.iter()) {
+                hq_stats.add(*param, *value);
+            }
+        }
+        let slot_index = item.equip_slot_category as usize - 1;
+        gear.push(Gear {
+            item_id: item.id,
+            equip_slot,
+            equip_level: item.equip_level,
+            item_level: item.item_level,
+            materia_slots: item.materia_slots,
+            advanced_melding: item.advanced_melding,
+            stats,
+            hq_stats,
+            meld_cap: GearStats {
+                craftsmanship: meld_cap(item_level, CRAFTSMANSHIP_PARAM, slot_index),
+                control: meld_cap(item_level, CONTROL_PARAM, slot_index),
+                cp: meld_cap(item_level, CP_PARAM, slot_index),
+            },
+        });
+    }
+    gear
+}
+
+/// Returns every grade of all materia that give crafting stats.
+pub fn instantiate_materia(materia_rows: &[MateriaRow]) -> Vec<Materia> {
+    let mut materia = Vec::new();
+    for row in materia_rows {
+        let stat = match row.param {
+            CRAFTSMANSHIP_PARAM => "Craftsmanship",
+            CONTROL_PARAM => "Control",
+            CP_PARAM => "Cp",
+            _ => continue,
+        };
+        for (index, (item_id, value)) in row.item_ids.iter().zip(row.values.iter()).enumerate() {
+            if *item_id == 0 || *value <= 0 {
+                continue;
+            }
+            materia.push(Materia {
+                item_id: *item_id,
+                stat,
+                grade: index as u32 + 1,
+                value: *value as u32,
+            });
+        }
+    }
+    materia
+}
+
+fn is_crafting_param(param: u32) -> bool {
+    matches!(param, CRAFTSMANSHIP_PARAM | CONTROL_PARAM | CP_PARAM)
+}
+
+/// Name of the `EquipSlot` variant in `raphael-data` for the given `EquipSlotCategory`.
+fn equip_slot_name(equip_slot_category: u32) -> Option<&'static str> {
+    // https://github.com/xivapi/ffxiv-datamining/blob/35e435494317723be856f18fb3b48f526316656e/csv/EquipSlotCategory.csv
+    match equip_slot_category {
+        1 => Some("MainHand"),
+        2 => Some("OffHand"),
+        3 => Some("Head"),
+        4 => Some("Body"),
+        5 => Some("Hands"),
+        7 => Some("Legs"),
+        8 => Some("Feet"),
+        9 => Some("Ears"),
+        10 => Some("Neck"),
+        11 => Some("Wrists"),
+        12 => Some("Ring"),
+        _ => None,
+    }
+}
+
+/// Fields of the `BaseParam` sheet, in the order of the `EquipSlotCategory` rows they belong to.
+const EQUIP_SLOT_PERCENT_FIELDS: &[&str] = &[
+    "OneHandWeaponPercent",
+    "OffHandPercent",
+    "HeadPercent",
+    "ChestPercent",
+    "HandsPercent",
+    "WaistPercent",
+    "LegsPercent",
+    "FeetPercent",
+    "EarringPercent",
+    "NecklacePercent",
+    "BraceletPercent",
+    "RingPercent",
+];
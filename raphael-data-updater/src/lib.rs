@@ -2,10 +2,10 @@ mod recipe;
 use std::{
     fs::{self, File},
     io::{Read, Seek, Write},
-    path::Path,
+    path::{Path, PathBuf},
 };
 
-pub use recipe::Recipe;
+pub use recipe::{Ingredient, Recipe};
 
 mod rlvl;
 pub use rlvl::RecipeLevel;
@@ -19,8 +19,23 @@ pub use item::{Item, ItemName};
 mod consumable;
 pub use consumable::{Consumable, ItemAction, ItemFood, instantiate_consumables};
 
+mod gear;
+pub use gear::{
+    BaseParamSlots, Gear, GearItem, GearStats, ItemLevelStats, Materia, MateriaRow,
+    instantiate_gear, instantiate_materia,
+};
+
 mod stellar_mission;
-pub use stellar_mission::{StellarMission, StellarMissionName};
+pub use stellar_mission::{StellarMission, StellarMissionName, TemporaryAction};
+
+mod data_pack;
+pub use data_pack::{DATA_PACK_FORMAT_VERSION, DataPack, GameTables};
+
+mod validation;
+pub use validation::{Severity, ValidationIssue, ValidationReport, validate};
+
+mod diff_report;
+pub use diff_report::{DiffEntry, DiffReport, FieldChange, ModifiedEntry, RenamedEntry, TableDiff};
 use toml_edit::DocumentMut;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -168,8 +183,12 @@ pub async fn fetch_latest_version_info(lang: Lang) -> Result<XivapiVersionInfo,
         .await?
         .text()
         .await?;
-    let json = json::parse(&response_text).unwrap();
-    Ok(json["versions"]
+    Ok(parse_latest_version_info(&response_text))
+}
+
+fn parse_latest_version_info(response_text: &str) -> XivapiVersionInfo {
+    let json = json::parse(response_text).unwrap();
+    json["versions"]
         .members()
         .find_map(|version| {
             if version["names"].contains("latest") {
@@ -188,7 +207,7 @@ pub async fn fetch_latest_version_info(lang: Lang) -> Result<XivapiVersionInfo,
                 None
             }
         })
-        .unwrap())
+        .unwrap()
 }
 
 pub trait SheetData: Sized {
@@ -269,3 +288,75 @@ pub async fn fetch_and_parse<T: SheetData>(lang: Lang) -> Vec<T> {
         log::debug!("{} {lang:?}: Fetching ({} rows)", T::SHEET, rows.len());
     }
 }
+
+/// Where the sheets are read from.
+#[derive(Debug, Clone)]
+pub enum SheetSource {
+    /// Query the xivapi (or boilmaster) endpoint of each language.
+    Xivapi,
+    /// Read local sheet exports from a directory, e.g. for reproducible builds without network access.
+    ///
+    /// The directory contains one subdirectory per language, named after [`Lang::xivapi_langcode`].
+    /// Each subdirectory contains `version.json` with the response of the `/version` endpoint
+    /// and a `<SHEET>.json` file per sheet, e.g. `Recipe.json`.
+    /// A sheet file contains either the rows as a JSON array or a response of the `/sheet/<SHEET>` endpoint,
+    /// with each row in the same format as returned by xivapi (`{"row_id": ..., "fields": {...}}`).
+    /// Sheets that are used by more than one [`SheetData`] type (e.g. `Item`) must contain the fields of all of them.
+    Local(PathBuf),
+}
+
+impl SheetSource {
+    /// Reads the sheets from the directory in `RAPHAEL_DATA_UPDATER_SHEET_DIR` if it is set, otherwise from xivapi.
+    pub fn from_env() -> Self {
+        match std::env::var_os("RAPHAEL_DATA_UPDATER_SHEET_DIR") {
+            Some(path) => Self::Local(path.into()),
+            None => Self::Xivapi,
+        }
+    }
+
+    pub async fn latest_version_info(self, lang: Lang) -> Result<XivapiVersionInfo, String> {
+        match self {
+            Self::Xivapi => fetch_latest_version_info(lang)
+                .await
+                .map_err(|error| error.to_string()),
+            Self::Local(path) => {
+                let path = path.join(lang.xivapi_langcode()).join("version.json");
+                let text = fs::read_to_string(&path)
+                    .map_err(|error| format!("Unable to read {}: {error}", path.display()))?;
+                Ok(parse_latest_version_info(&text))
+            }
+        }
+    }
+
+    pub async fn load<T: SheetData>(self, lang: Lang) -> Vec<T> {
+        match self {
+            Self::Xivapi => fetch_and_parse(lang).await,
+            Self::Local(path) => read_and_parse(&path, lang),
+        }
+    }
+}
+
+/// Reads and parses a sheet from a local export. See [`SheetSource::Local`] for the expected layout.
+pub fn read_and_parse<T: SheetData>(dir: &Path, lang: Lang) -> Vec<T> {
+    let path = dir
+        .join(lang.xivapi_langcode())
+        .join(format!("{}.json", T::SHEET));
+    let text = fs::read_to_string(&path)
+        .unwrap_or_else(|error| panic!("Unable to read {}: {error}", path.display()));
+    let json = json::parse(&text)
+        .unwrap_or_else(|error| panic!("Unable to parse {}: {error}", path.display()));
+    let rows = if json.is_array() {
+        &json
+    } else {
+        &json["rows"]
+    };
+    let mut rows: Vec<T> = rows.members().filter_map(T::from_json).collect();
+    rows.sort_by_key(T::row_id);
+    log::info!(
+        "{} {lang:?}: Done reading \"{}\" ({} rows)",
+        T::SHEET,
+        path.display(),
+        rows.len()
+    );
+    rows
+}
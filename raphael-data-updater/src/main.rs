@@ -1,6 +1,7 @@
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::fs::File;
 use std::io::{BufWriter, Write};
+use std::path::Path;
 
 use raphael_data_updater::*;
 
@@ -39,6 +40,30 @@ fn export_recipes(recipes: &[Recipe]) {
     log::info!("Generated \"{}\"", path.display());
 }
 
+fn export_item_recipes(recipes: &[Recipe]) {
+    let path = std::path::absolute("./raphael-data/data/item_recipes.rs").unwrap();
+    let mut item_recipes: BTreeMap<u32, Vec<&Recipe>> = BTreeMap::new();
+    for recipe in recipes {
+        item_recipes.entry(recipe.item_id).or_default().push(recipe);
+    }
+    let mut writer = BufWriter::new(File::create(&path).unwrap());
+    writeln!(writer, "nci_array! {{").unwrap();
+    for (item_id, recipes) in item_recipes {
+        write!(writer, "{item_id} => &[").unwrap();
+        for recipe in recipes {
+            write!(
+                writer,
+                "ItemRecipe {{ recipe_id: {}, result_amount: {}, }}, ",
+                recipe.id, recipe.result_amount
+            )
+            .unwrap();
+        }
+        writeln!(writer, "],").unwrap();
+    }
+    writeln!(writer, "}}").unwrap();
+    log::info!("Generated \"{}\"", path.display());
+}
+
 fn export_items(items: &[Item]) {
     let path = std::path::absolute("./raphael-data/data/items.rs").unwrap();
     let mut writer = BufWriter::new(File::create(&path).unwrap());
@@ -72,6 +97,28 @@ fn export_potions(consumables: &[Consumable]) {
     log::info!("Generated \"{}\"", path.display());
 }
 
+fn export_gear(gear: &[Gear]) {
+    let path = std::path::absolute("./raphael-data/data/gear.rs").unwrap();
+    let mut writer = BufWriter::new(File::create(&path).unwrap());
+    writeln!(&mut writer, "&[").unwrap();
+    for item in gear {
+        writeln!(&mut writer, "{item},").unwrap();
+    }
+    writeln!(&mut writer, "]").unwrap();
+    log::info!("Generated \"{}\"", path.display());
+}
+
+fn export_materia(materia: &[Materia]) {
+    let path = std::path::absolute("./raphael-data/data/materia.rs").unwrap();
+    let mut writer = BufWriter::new(File::create(&path).unwrap());
+    writeln!(&mut writer, "&[").unwrap();
+    for item in materia {
+        writeln!(&mut writer, "{item},").unwrap();
+    }
+    writeln!(&mut writer, "]").unwrap();
+    log::info!("Generated \"{}\"", path.display());
+}
+
 fn export_stellar_missions(stellar_missions: &[StellarMission]) {
     let path = std::path::absolute("./raphael-data/data/stellar_missions.rs").unwrap();
     let mut writer = BufWriter::new(File::create(&path).unwrap());
@@ -132,6 +179,74 @@ fn export_stellar_mission_names(stellar_mission_names: &[StellarMissionName], la
     log::info!("Generated \"{}\"", path.display());
 }
 
+const DATA_PACK_PATH: &str = "./raphael-data/data-pack.json";
+
+fn export_data_pack(data_pack: &json::JsonValue) {
+    let path = std::path::absolute(DATA_PACK_PATH).unwrap();
+    let mut writer = BufWriter::new(File::create(&path).unwrap());
+    data_pack.write(&mut writer).unwrap();
+    log::info!("Generated \"{}\"", path.display());
+}
+
+fn read_data_pack(path: &Path) -> json::JsonValue {
+    let text = std::fs::read_to_string(path)
+        .unwrap_or_else(|error| panic!("Unable to read {}: {error}", path.display()));
+    json::parse(&text).unwrap_or_else(|error| panic!("Unable to parse {}: {error}", path.display()))
+}
+
+/// The baseline of the diff report. This is the game data the updater was built with, i.e. the committed generated files.
+/// Can be overridden with `RAPHAEL_DATA_UPDATER_PREVIOUS_DATA_PACK`, e.g. to compare against a released data pack.
+fn read_previous_data_pack() -> json::JsonValue {
+    match std::env::var_os("RAPHAEL_DATA_UPDATER_PREVIOUS_DATA_PACK") {
+        Some(path) => read_data_pack(Path::new(&path)),
+        None => {
+            let data_pack = raphael_data::DataPack::from_embedded("committed game data");
+            json::parse(&data_pack.to_json()).unwrap()
+        }
+    }
+}
+
+/// Logs the validation warnings and errors. Exits if there are errors, unless `RAPHAEL_DATA_UPDATER_SKIP_VALIDATION` is set.
+fn validate_tables(tables: &GameTables) {
+    let report = validate(tables);
+    for warning in report.warnings() {
+        log::warn!("{}", warning.message);
+    }
+    if report.has_errors() {
+        for error in report.errors() {
+            log::error!("{}", error.message);
+        }
+        if std::env::var_os("RAPHAEL_DATA_UPDATER_SKIP_VALIDATION").is_none() {
+            log::error!(
+                "Data validation failed, no files were generated. Set the environment variable `RAPHAEL_DATA_UPDATER_SKIP_VALIDATION` to export the data anyway."
+            );
+            std::process::exit(1);
+        }
+        log::warn!("Data validation failed, exporting anyway");
+    }
+}
+
+/// Formats the report as JSON if `RAPHAEL_DATA_UPDATER_REPORT_FORMAT` is set to `json`, otherwise as Markdown.
+fn format_diff_report(report: &DiffReport) -> (String, &'static str) {
+    match std::env::var("RAPHAEL_DATA_UPDATER_REPORT_FORMAT").as_deref() {
+        Ok("json") => (report.to_json().pretty(2), "json"),
+        _ => (report.to_markdown(), "md"),
+    }
+}
+
+fn export_diff_report(report: &DiffReport) {
+    let (report, extension) = format_diff_report(report);
+    let path = std::path::absolute(format!("./raphael-data/data-diff.{extension}")).unwrap();
+    std::fs::write(&path, report).unwrap();
+    log::info!("Generated \"{}\"", path.display());
+}
+
+/// `raphael-data-updater diff <OLD_DATA_PACK> <NEW_DATA_PACK>` prints the diff report of two existing data packs.
+fn print_diff_report(old_path: &Path, new_path: &Path) {
+    let report = DiffReport::new(&read_data_pack(old_path), &read_data_pack(new_path));
+    println!("{}", format_diff_report(&report).0);
+}
+
 #[tokio::main]
 async fn main() {
     // Set log level to `Info` if not specified via `env_logger`'s default environment variable
@@ -144,10 +259,23 @@ async fn main() {
         env_logger::builder().format_timestamp(None).init();
     }
 
-    let global_version = tokio::spawn(fetch_latest_version_info(Lang::EN));
-    let cn_version = tokio::spawn(fetch_latest_version_info(Lang::CN));
-    let kr_version = tokio::spawn(fetch_latest_version_info(Lang::KR));
-    let tw_version = tokio::spawn(fetch_latest_version_info(Lang::TW));
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if let [command, old_path, new_path] = args.as_slice()
+        && command == "diff"
+    {
+        print_diff_report(Path::new(old_path), Path::new(new_path));
+        return;
+    }
+
+    let source = SheetSource::from_env();
+    if let SheetSource::Local(path) = &source {
+        log::info!("Reading sheets from \"{}\"", path.display());
+    }
+
+    let global_version = tokio::spawn(source.clone().latest_version_info(Lang::EN));
+    let cn_version = tokio::spawn(source.clone().latest_version_info(Lang::CN));
+    let kr_version = tokio::spawn(source.clone().latest_version_info(Lang::KR));
+    let tw_version = tokio::spawn(source.clone().latest_version_info(Lang::TW));
 
     let global_version = global_version.await.unwrap().unwrap();
     let cn_version = cn_version.await.unwrap().unwrap();
@@ -165,29 +293,40 @@ async fn main() {
         std::process::exit(0);
     }
 
-    let rlvls = tokio::spawn(fetch_and_parse::<RecipeLevel>(Lang::EN));
-    let level_adjust_table = tokio::spawn(fetch_and_parse::<LevelAdjustTableEntry>(Lang::EN));
-    let recipes = tokio::spawn(fetch_and_parse::<Recipe>(Lang::EN));
-    let items = tokio::spawn(fetch_and_parse::<Item>(Lang::EN));
-    let item_actions = tokio::spawn(fetch_and_parse::<ItemAction>(Lang::EN));
-    let item_foods = tokio::spawn(fetch_and_parse::<ItemFood>(Lang::EN));
-    let stellar_missions = tokio::spawn(fetch_and_parse::<StellarMission>(Lang::EN));
-
-    let item_names_en = tokio::spawn(fetch_and_parse::<ItemName>(Lang::EN));
-    let item_names_de = tokio::spawn(fetch_and_parse::<ItemName>(Lang::DE));
-    let item_names_fr = tokio::spawn(fetch_and_parse::<ItemName>(Lang::FR));
-    let item_names_jp = tokio::spawn(fetch_and_parse::<ItemName>(Lang::JP));
-    let item_names_cn = tokio::spawn(fetch_and_parse::<ItemName>(Lang::CN));
-    let item_names_kr = tokio::spawn(fetch_and_parse::<ItemName>(Lang::KR));
-    let item_names_tw = tokio::spawn(fetch_and_parse::<ItemName>(Lang::TW));
-
-    let stellar_mission_names_en = tokio::spawn(fetch_and_parse::<StellarMissionName>(Lang::EN));
-    let stellar_mission_names_de = tokio::spawn(fetch_and_parse::<StellarMissionName>(Lang::DE));
-    let stellar_mission_names_fr = tokio::spawn(fetch_and_parse::<StellarMissionName>(Lang::FR));
-    let stellar_mission_names_jp = tokio::spawn(fetch_and_parse::<StellarMissionName>(Lang::JP));
-    let stellar_mission_names_cn = tokio::spawn(fetch_and_parse::<StellarMissionName>(Lang::CN));
-    let stellar_mission_names_kr = tokio::spawn(fetch_and_parse::<StellarMissionName>(Lang::KR));
-    let stellar_mission_names_tw = tokio::spawn(fetch_and_parse::<StellarMissionName>(Lang::TW));
+    let rlvls = tokio::spawn(source.clone().load::<RecipeLevel>(Lang::EN));
+    let level_adjust_table = tokio::spawn(source.clone().load::<LevelAdjustTableEntry>(Lang::EN));
+    let recipes = tokio::spawn(source.clone().load::<Recipe>(Lang::EN));
+    let items = tokio::spawn(source.clone().load::<Item>(Lang::EN));
+    let item_actions = tokio::spawn(source.clone().load::<ItemAction>(Lang::EN));
+    let item_foods = tokio::spawn(source.clone().load::<ItemFood>(Lang::EN));
+    let gear_items = tokio::spawn(source.clone().load::<GearItem>(Lang::EN));
+    let item_levels = tokio::spawn(source.clone().load::<ItemLevelStats>(Lang::EN));
+    let base_params = tokio::spawn(source.clone().load::<BaseParamSlots>(Lang::EN));
+    let materia_rows = tokio::spawn(source.clone().load::<MateriaRow>(Lang::EN));
+    let stellar_missions = tokio::spawn(source.clone().load::<StellarMission>(Lang::EN));
+
+    let item_names_en = tokio::spawn(source.clone().load::<ItemName>(Lang::EN));
+    let item_names_de = tokio::spawn(source.clone().load::<ItemName>(Lang::DE));
+    let item_names_fr = tokio::spawn(source.clone().load::<ItemName>(Lang::FR));
+    let item_names_jp = tokio::spawn(source.clone().load::<ItemName>(Lang::JP));
+    let item_names_cn = tokio::spawn(source.clone().load::<ItemName>(Lang::CN));
+    let item_names_kr = tokio::spawn(source.clone().load::<ItemName>(Lang::KR));
+    let item_names_tw = tokio::spawn(source.clone().load::<ItemName>(Lang::TW));
+
+    let stellar_mission_names_en =
+        tokio::spawn(source.clone().load::<StellarMissionName>(Lang::EN));
+    let stellar_mission_names_de =
+        tokio::spawn(source.clone().load::<StellarMissionName>(Lang::DE));
+    let stellar_mission_names_fr =
+        tokio::spawn(source.clone().load::<StellarMissionName>(Lang::FR));
+    let stellar_mission_names_jp =
+        tokio::spawn(source.clone().load::<StellarMissionName>(Lang::JP));
+    let stellar_mission_names_cn =
+        tokio::spawn(source.clone().load::<StellarMissionName>(Lang::CN));
+    let stellar_mission_names_kr =
+        tokio::spawn(source.clone().load::<StellarMissionName>(Lang::KR));
+    let stellar_mission_names_tw =
+        tokio::spawn(source.clone().load::<StellarMissionName>(Lang::TW));
 
     let rlvls = rlvls.await.unwrap();
     let level_adjust_table = level_adjust_table.await.unwrap();
@@ -198,6 +337,12 @@ async fn main() {
     let item_foods = item_foods.await.unwrap();
     let (meals, potions) = instantiate_consumables(&items, item_actions, item_foods);
 
+    let gear_items = gear_items.await.unwrap();
+    let item_levels = item_levels.await.unwrap();
+    let base_params = base_params.await.unwrap();
+    let gear = instantiate_gear(&gear_items, &item_levels, &base_params);
+    let materia = instantiate_materia(&materia_rows.await.unwrap());
+
     let stellar_missions = stellar_missions.await.unwrap();
 
     let mut item_names_en = item_names_en.await.unwrap();
@@ -247,6 +392,8 @@ async fn main() {
             .chain(potions.iter())
             .map(|consumable| consumable.item_id),
     );
+    necessary_items.extend(gear.iter().map(|gear| gear.item_id));
+    necessary_items.extend(materia.iter().map(|materia| materia.item_id));
     items.retain(|item| necessary_items.contains(&item.id));
     item_names_en.retain(|item_name| necessary_items.contains(&item_name.id));
     item_names_de.retain(|item_name| necessary_items.contains(&item_name.id));
@@ -285,12 +432,46 @@ async fn main() {
             && !stellar_mission_name.name.is_empty()
     });
 
+    let tables = GameTables {
+        rlvls: &rlvls,
+        level_adjust_table: &level_adjust_table,
+        recipes: &recipes,
+        items: &items,
+        meals: &meals,
+        potions: &potions,
+        gear: &gear,
+        materia: &materia,
+        stellar_missions: &stellar_missions,
+        item_names: vec![
+            (Lang::EN, item_names_en.as_slice()),
+            (Lang::DE, item_names_de.as_slice()),
+            (Lang::FR, item_names_fr.as_slice()),
+            (Lang::JP, item_names_jp.as_slice()),
+            (Lang::CN, item_names_cn.as_slice()),
+            (Lang::KR, item_names_kr.as_slice()),
+            (Lang::TW, item_names_tw.as_slice()),
+        ],
+        stellar_mission_names: vec![
+            (Lang::EN, stellar_mission_names_en.as_slice()),
+            (Lang::DE, stellar_mission_names_de.as_slice()),
+            (Lang::FR, stellar_mission_names_fr.as_slice()),
+            (Lang::JP, stellar_mission_names_jp.as_slice()),
+            (Lang::CN, stellar_mission_names_cn.as_slice()),
+            (Lang::KR, stellar_mission_names_kr.as_slice()),
+            (Lang::TW, stellar_mission_names_tw.as_slice()),
+        ],
+    };
+    validate_tables(&tables);
+
     export_rlvls(&rlvls);
     export_level_adjust_table(&level_adjust_table);
     export_recipes(&recipes);
+    export_item_recipes(&recipes);
     export_meals(&meals);
     export_potions(&potions);
     export_items(&items);
+    export_gear(&gear);
+    export_materia(&materia);
     export_stellar_missions(&stellar_missions);
     export_recipe_to_stellar_mission_links(&stellar_missions);
 
@@ -310,5 +491,14 @@ async fn main() {
     export_stellar_mission_names(&stellar_mission_names_kr, Lang::KR.shortcode());
     export_stellar_mission_names(&stellar_mission_names_tw, Lang::TW.shortcode());
 
+    let previous_data_pack = read_previous_data_pack();
+    let data_pack = DataPack {
+        latest_versions: &latest_versions,
+        tables: &tables,
+    }
+    .to_json();
+    export_diff_report(&DiffReport::new(&previous_data_pack, &data_pack));
+    export_data_pack(&data_pack);
+
     latest_versions.write_versions_to_file();
 }
@@ -13,6 +13,8 @@ pub struct Recipe {
     pub id: u32,
     pub job_id: u32,
     pub item_id: u32,
+    /// Number of items produced by a single craft.
+    pub result_amount: u32,
     pub max_level_scaling: u32,
     pub recipe_level: u32,
     pub progress_factor: u32,
@@ -30,6 +32,7 @@ impl SheetData for Recipe {
     const REQUIRED_FIELDS: &[&str] = &[
         "CraftType@as(raw)",
         "ItemResult@as(raw)",
+        "AmountResult",
         "MaxAdjustableJobLevel@as(raw)",
         "RecipeLevelTable@as(raw)",
         "DifficultyFactor",
@@ -63,6 +66,7 @@ impl SheetData for Recipe {
             id: value["row_id"].as_u32().unwrap(),
             job_id: fields["CraftType@as(raw)"].as_u32().unwrap(),
             item_id: fields["ItemResult@as(raw)"].as_u32().unwrap(),
+            result_amount: fields["AmountResult"].as_u32().unwrap(),
             max_level_scaling: fields["MaxAdjustableJobLevel@as(raw)"].as_u32().unwrap(),
             recipe_level: fields["RecipeLevelTable@as(raw)"].as_u32().unwrap(),
             progress_factor: fields["DifficultyFactor"].as_u32().unwrap(),
@@ -27,7 +27,7 @@ pub struct StellarMission {
 }
 
 impl StellarMission {
-    fn stellar_steady_hand_charges(&self) -> u8 {
+    pub fn stellar_steady_hand_charges(&self) -> u8 {
         match self.temporary_action {
             TemporaryAction::StellarSteadyHand => self.temporary_action_charges,
             _ => 0,
@@ -102,11 +102,11 @@ impl std::fmt::Display for StellarMission {
             "stellar_steady_hand_charges: {}, ",
             self.stellar_steady_hand_charges()
         )?;
-        write!(f, "recipe_ids: &[")?;
+        write!(f, "recipe_ids: Cow::Borrowed(&[")?;
         for recipe_id in &self.recipe_ids {
             write!(f, "{}, ", recipe_id)?;
         }
-        write!(f, "], ")?;
+        write!(f, "]), ")?;
         write!(f, "}}")?;
         Ok(())
     }
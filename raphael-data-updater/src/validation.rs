@@ -0,0 +1,243 @@
+use std::collections::HashSet;
+
+use crate::{Consumable, GameTables, Lang};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// The data can be exported, but should be looked at.
+    Warning,
+    /// The data would break assumptions made by `raphael-data` and must not be exported.
+    Error,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationIssue {
+    pub severity: Severity,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationReport {
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    pub fn has_errors(&self) -> bool {
+        self.issues
+            .iter()
+            .any(|issue| issue.severity == Severity::Error)
+    }
+
+    pub fn errors(&self) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues
+            .iter()
+            .filter(|issue| issue.severity == Severity::Error)
+    }
+
+    pub fn warnings(&self) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues
+            .iter()
+            .filter(|issue| issue.severity == Severity::Warning)
+    }
+
+    fn error(&mut self, message: String) {
+        self.issues.push(ValidationIssue {
+            severity: Severity::Error,
+            message,
+        });
+    }
+
+    fn warning(&mut self, message: String) {
+        self.issues.push(ValidationIssue {
+            severity: Severity::Warning,
+            message,
+        });
+    }
+}
+
+impl std::fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for issue in &self.issues {
+            let severity = match issue.severity {
+                Severity::Warning => "warning",
+                Severity::Error => "error",
+            };
+            writeln!(f, "{severity}: {}", issue.message)?;
+        }
+        Ok(())
+    }
+}
+
+/// Languages whose servers run an older game version than the global servers.
+/// Items that were added in newer patches don't have a name in these languages yet.
+fn is_delayed_lang(lang: Lang) -> bool {
+    matches!(lang, Lang::CN | Lang::KR | Lang::TW)
+}
+
+/// Checks the invariants that `raphael-data` relies on when looking up game data.
+pub fn validate(tables: &GameTables) -> ValidationReport {
+    let mut report = ValidationReport::default();
+    validate_rlvls(tables, &mut report);
+    validate_recipes(tables, &mut report);
+    validate_level_adjust_table(tables, &mut report);
+    validate_stellar_missions(tables, &mut report);
+    for consumable in tables.meals.iter().chain(tables.potions) {
+        validate_consumable(consumable, &mut report);
+    }
+    validate_item_names(tables, &mut report);
+    report
+}
+
+fn validate_rlvls(tables: &GameTables, report: &mut ValidationReport) {
+    // The recipe level is used as index into the exported table.
+    for (index, rlvl) in tables.rlvls.iter().enumerate() {
+        let expected_rlvl = index as u32 + 1;
+        if rlvl.rlvl != expected_rlvl {
+            report.error(format!(
+                "Recipe level table entry {index} is for recipe level {}, expected recipe level {expected_rlvl}",
+                rlvl.rlvl
+            ));
+        }
+    }
+}
+
+fn validate_recipes(tables: &GameTables, report: &mut ValidationReport) {
+    let rlvls: HashSet<u32> = tables.rlvls.iter().map(|rlvl| rlvl.rlvl).collect();
+    let items: HashSet<u32> = tables.items.iter().map(|item| item.id).collect();
+    for recipe in tables.recipes {
+        if !rlvls.contains(&recipe.recipe_level) {
+            report.error(format!(
+                "Recipe {} uses recipe level {}, which does not exist",
+                recipe.id, recipe.recipe_level
+            ));
+        }
+        if recipe.ingredients.len() > 6 {
+            report.error(format!(
+                "Recipe {} has {} HQ-able ingredients, but at most 6 are supported",
+                recipe.id,
+                recipe.ingredients.len()
+            ));
+        }
+        for ingredient in &recipe.ingredients {
+            if !items.contains(&ingredient.item_id) {
+                report.error(format!(
+                    "Recipe {} uses ingredient {}, which is not an exported item",
+                    recipe.id, ingredient.item_id
+                ));
+            }
+        }
+    }
+}
+
+fn validate_level_adjust_table(tables: &GameTables, report: &mut ValidationReport) {
+    let rlvls: HashSet<u32> = tables.rlvls.iter().map(|rlvl| rlvl.rlvl).collect();
+    // Recipes with level scaling look up the level adjust table with the crafter's level,
+    // so the table must have an entry for every level from 1 up to the max level.
+    let max_level = tables
+        .rlvls
+        .iter()
+        .map(|rlvl| rlvl.job_level)
+        .chain(tables.recipes.iter().map(|recipe| recipe.max_level_scaling))
+        .max()
+        .unwrap_or_default();
+    for (index, entry) in tables.level_adjust_table.iter().enumerate() {
+        let expected_level = index as u32 + 1;
+        if entry.level != expected_level {
+            report.error(format!(
+                "Level adjust table entry {index} is for level {}, expected level {expected_level}",
+                entry.level
+            ));
+        }
+        if !rlvls.contains(&entry.rlvl) {
+            report.error(format!(
+                "Level adjust table maps level {} to recipe level {}, which does not exist",
+                entry.level, entry.rlvl
+            ));
+        }
+    }
+    if (tables.level_adjust_table.len() as u32) < max_level {
+        report.error(format!(
+            "Level adjust table only covers levels 1 to {}, but the max level is {max_level}",
+            tables.level_adjust_table.len()
+        ));
+    }
+}
+
+fn validate_stellar_missions(tables: &GameTables, report: &mut ValidationReport) {
+    let recipes: HashSet<u32> = tables.recipes.iter().map(|recipe| recipe.id).collect();
+    for stellar_mission in tables.stellar_missions {
+        for recipe_id in &stellar_mission.recipe_ids {
+            if !recipes.contains(recipe_id) {
+                report.error(format!(
+                    "Stellar mission {} uses recipe {recipe_id}, which does not exist",
+                    stellar_mission.id
+                ));
+            }
+        }
+    }
+}
+
+fn validate_consumable(consumable: &Consumable, report: &mut ValidationReport) {
+    let quality = if consumable.hq { "HQ" } else { "NQ" };
+    let bonuses = [
+        ("craftsmanship", consumable.craft_rel, consumable.craft_max),
+        ("control", consumable.control_rel, consumable.control_max),
+        ("CP", consumable.cp_rel, consumable.cp_max),
+    ];
+    for (stat, rel, max) in bonuses {
+        if !(0..=100).contains(&rel) || !(0..=i32::from(u16::MAX)).contains(&max) {
+            report.error(format!(
+                "Consumable {} ({quality}) has an invalid {stat} bonus of {rel}% (max {max})",
+                consumable.item_id
+            ));
+        }
+    }
+    if bonuses.iter().all(|(_, rel, max)| *rel == 0 || *max == 0) {
+        report.warning(format!(
+            "Consumable {} ({quality}) does not give any crafting stats",
+            consumable.item_id
+        ));
+    }
+}
+
+fn validate_item_names(tables: &GameTables, report: &mut ValidationReport) {
+    let mut referenced_items: Vec<u32> = tables
+        .recipes
+        .iter()
+        .flat_map(|recipe| {
+            std::iter::once(recipe.item_id).chain(
+                recipe
+                    .ingredients
+                    .iter()
+                    .map(|ingredient| ingredient.item_id),
+            )
+        })
+        .collect();
+    referenced_items.sort_unstable();
+    referenced_items.dedup();
+    for (lang, item_names) in &tables.item_names {
+        let named_items: HashSet<u32> = item_names
+            .iter()
+            .filter(|item_name| !item_name.name.is_empty())
+            .map(|item_name| item_name.id)
+            .collect();
+        let missing: Vec<u32> = referenced_items
+            .iter()
+            .copied()
+            .filter(|item_id| !named_items.contains(item_id))
+            .collect();
+        if missing.is_empty() {
+            continue;
+        }
+        let message = format!(
+            "{lang:?}: {} items used by recipes have no name, e.g. {:?}",
+            missing.len(),
+            &missing[..missing.len().min(10)]
+        );
+        if is_delayed_lang(*lang) {
+            report.warning(message);
+        } else {
+            report.error(message);
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use json::{JsonValue, array, object};
+use raphael_data_updater::*;
+
+fn recipe(item_id: u32, recipe_level: u32) -> JsonValue {
+    object! {
+        job_id: 0,
+        item_id: item_id,
+        recipe_level: recipe_level,
+        durability_factor: 100,
+    }
+}
+
+fn consumable(item_id: u32, hq: bool, craft_max: u32) -> JsonValue {
+    object! {
+        item_id: item_id,
+        hq: hq,
+        craft_max: craft_max,
+    }
+}
+
+fn old_data_pack() -> JsonValue {
+    object! {
+        data_version: "7.25",
+        recipes: object! {
+            "1": recipe(100, 690),
+            "2": recipe(200, 690),
+        },
+        rlvls: array![object! { job_level: 0 }, object! { job_level: 100, max_progress: 6600 }],
+        level_adjust_table: array![0, 690],
+        meals: array![consumable(300, false, 80)],
+        potions: array![],
+        stellar_missions: object! {},
+        item_names: object! {
+            EN: object! { "100": "Old Board", "200": "Ingot", "300": "Stew" },
+            DE: object! { "100": "Brett" },
+        },
+        stellar_mission_names: object! {},
+    }
+}
+
+fn new_data_pack() -> JsonValue {
+    object! {
+        data_version: "7.3",
+        recipes: object! {
+            "1": recipe(100, 710),
+            "3": recipe(400, 710),
+        },
+        rlvls: array![object! { job_level: 0 }, object! { job_level: 100, max_progress: 6800 }],
+        level_adjust_table: array![0, 690],
+        meals: array![consumable(300, false, 80), consumable(300, true, 100)],
+        potions: array![],
+        stellar_missions: object! { "5": object! { job_id: 1, recipe_ids: array![3] } },
+        item_names: object! {
+            EN: object! { "100": "New Board", "200": "Ingot", "300": "Stew", "400": "Cloth" },
+            DE: object! { "100": "Brett" },
+        },
+        stellar_mission_names: object! { EN: object! { "5": "Mission" } },
+    }
+}
+
+fn ids(entries: &[DiffEntry]) -> Vec<&str> {
+    entries.iter().map(|entry| entry.id.as_str()).collect()
+}
+
+#[test]
+fn test_diff_report() {
+    let report = DiffReport::new(&old_data_pack(), &new_data_pack());
+    assert_eq!(report.old_data_version, "7.25");
+    assert_eq!(report.new_data_version, "7.3");
+
+    assert_eq!(ids(&report.recipes.added), ["3"]);
+    assert_eq!(report.recipes.added[0].name.as_deref(), Some("Cloth"));
+    assert_eq!(ids(&report.recipes.removed), ["2"]);
+    assert_eq!(report.recipes.removed[0].name.as_deref(), Some("Ingot"));
+    assert_eq!(report.recipes.modified.len(), 1);
+    assert_eq!(
+        report.recipes.modified[0].changes,
+        [FieldChange {
+            field: "recipe_level".to_owned(),
+            old: "690".to_owned(),
+            new: "710".to_owned(),
+        }]
+    );
+
+    assert_eq!(report.rlvls.modified.len(), 1);
+    assert_eq!(report.rlvls.modified[0].entry.id, "1");
+    assert!(report.level_adjust_table.is_empty());
+
+    assert_eq!(ids(&report.meals.added), ["300 HQ"]);
+    assert_eq!(report.meals.added[0].name.as_deref(), Some("Stew (HQ)"));
+    assert!(report.potions.is_empty());
+
+    assert_eq!(ids(&report.stellar_missions.added), ["5"]);
+    assert_eq!(
+        report.stellar_missions.added[0].name.as_deref(),
+        Some("Mission")
+    );
+
+    assert_eq!(
+        report.renamed_items,
+        [RenamedEntry {
+            locale: "EN".to_owned(),
+            id: "100".to_owned(),
+            old_name: "Old Board".to_owned(),
+            new_name: "New Board".to_owned(),
+        }]
+    );
+    assert!(report.renamed_stellar_missions.is_empty());
+
+    let markdown = report.to_markdown();
+    assert!(markdown.contains("Data version: 7.25 → 7.3"));
+    assert!(markdown.contains("- 3 (Cloth)"));
+    assert!(markdown.contains("| EN | 100 | Old Board | New Board |"));
+
+    let json = report.to_json();
+    assert_eq!(json["tables"]["recipes"]["added"][0]["id"], "3");
+    assert_eq!(
+        json["tables"]["recipes"]["modified"][0]["changes"][0]["new"],
+        710
+    );
+}
+
+#[test]
+fn test_identical_data_packs() {
+    let report = DiffReport::new(&new_data_pack(), &new_data_pack());
+    assert!(report.is_empty());
+    assert!(report.to_markdown().contains("No changes."));
+}
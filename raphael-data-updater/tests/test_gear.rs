@@ -0,0 +1,111 @@
+use raphael_data_updater::*;
+
+const CP_PARAM: u32 = 11;
+const CRAFTSMANSHIP_PARAM: u32 = 70;
+const CONTROL_PARAM: u32 = 71;
+const STRENGTH_PARAM: u32 = 1;
+
+fn gear_item(id: u32, equip_slot_category: u32, param: Vec<u32>) -> GearItem {
+    GearItem {
+        id,
+        item_level: 710,
+        equip_level: 100,
+        equip_slot_category,
+        materia_slots: 2,
+        advanced_melding: true,
+        can_be_hq: true,
+        value: vec![100; param.len()],
+        param,
+        param_special: vec![CRAFTSMANSHIP_PARAM],
+        value_special: vec![10],
+    }
+}
+
+fn base_param(id: u32, percent: u32) -> BaseParamSlots {
+    BaseParamSlots {
+        id,
+        slot_percent: vec![percent; 12],
+    }
+}
+
+#[test]
+fn test_instantiate_gear() {
+    let gear_items = [
+        gear_item(1, 3, vec![CRAFTSMANSHIP_PARAM, CP_PARAM]),
+        // Combat gear is skipped.
+        gear_item(2, 3, vec![STRENGTH_PARAM]),
+        // Items that cannot be equipped are skipped.
+        gear_item(3, 0, vec![CONTROL_PARAM]),
+    ];
+    let item_levels = [ItemLevelStats {
+        item_level: 710,
+        craftsmanship: 1000,
+        control: 1001,
+        cp: 100,
+    }];
+    let base_params = [
+        base_param(CRAFTSMANSHIP_PARAM, 100),
+        base_param(CONTROL_PARAM, 100),
+        base_param(CP_PARAM, 50),
+    ];
+    let gear = instantiate_gear(&gear_items, &item_levels, &base_params);
+    assert_eq!(gear.len(), 1);
+    assert_eq!(gear[0].item_id, 1);
+    assert_eq!(gear[0].equip_slot, "Head");
+    assert_eq!(
+        gear[0].stats,
+        GearStats {
+            craftsmanship: 100,
+            control: 0,
+            cp: 100,
+        }
+    );
+    assert_eq!(
+        gear[0].hq_stats,
+        GearStats {
+            craftsmanship: 110,
+            control: 0,
+            cp: 100,
+        }
+    );
+    assert_eq!(
+        gear[0].meld_cap,
+        GearStats {
+            craftsmanship: 100,
+            control: 100,
+            cp: 5,
+        }
+    );
+    assert!(
+        gear[0]
+            .to_string()
+            .contains("equip_slot: EquipSlot::Head, ")
+    );
+}
+
+#[test]
+fn test_instantiate_materia() {
+    let materia_rows = [
+        MateriaRow {
+            id: 1,
+            item_ids: vec![5001, 5002, 0],
+            param: CONTROL_PARAM,
+            values: vec![3, 6, 0],
+        },
+        MateriaRow {
+            id: 2,
+            item_ids: vec![6001],
+            param: STRENGTH_PARAM,
+            values: vec![3],
+        },
+    ];
+    let materia = instantiate_materia(&materia_rows);
+    let materia: Vec<String> = materia.iter().map(ToString::to_string).collect();
+    assert_eq!(
+        materia,
+        [
+            "Materia { item_id: 5001, stat: CraftingStat::Control, grade: 1, value: 3, }",
+            "Materia { item_id: 5002, stat: CraftingStat::Control, grade: 2, value: 6, }",
+        ]
+    );
+}
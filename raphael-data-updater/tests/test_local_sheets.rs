@@ -0,0 +1,54 @@
+use std::path::PathBuf;
+
+use raphael_data_updater::*;
+
+fn fixture_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/sheets")
+}
+
+#[test]
+fn test_read_recipes_from_sheet_response() {
+    let recipes: Vec<Recipe> = read_and_parse(&fixture_dir(), Lang::EN);
+    let recipe_ids: Vec<u32> = recipes.iter().map(|recipe| recipe.id).collect();
+    assert_eq!(recipe_ids, [35498, 35499]);
+    let recipe = &recipes[0];
+    assert_eq!(recipe.job_id, 7);
+    assert_eq!(recipe.item_id, 44091);
+    assert_eq!(recipe.result_amount, 3);
+    assert_eq!(recipe.recipe_level, 710);
+    assert_eq!(recipe.durability_factor, 50);
+    assert_eq!(recipe.req_craftsmanship, 4200);
+    assert_eq!(recipe.req_control, 3900);
+    assert_eq!(recipe.ingredients[0].item_id, 44064);
+    assert_eq!(recipe.ingredients[0].amount, 2);
+}
+
+#[test]
+fn test_read_rlvls_from_row_array() {
+    let rlvls: Vec<RecipeLevel> = read_and_parse(&fixture_dir(), Lang::EN);
+    assert_eq!(rlvls.len(), 1);
+    assert_eq!(rlvls[0].rlvl, 690);
+    assert_eq!(rlvls[0].job_level, 100);
+    assert_eq!(rlvls[0].max_progress, 6600);
+    assert_eq!(rlvls[0].quality_mod, 75);
+}
+
+#[tokio::test]
+async fn test_read_latest_version_info() {
+    let version_info = SheetSource::Local(fixture_dir())
+        .latest_version_info(Lang::EN)
+        .await
+        .unwrap();
+    assert_eq!(version_info.key, "2a5e8ad2b3f4c1d7");
+    assert_eq!(version_info.name.as_deref(), Some("7.3"));
+}
+
+#[tokio::test]
+async fn test_missing_version_info() {
+    assert!(
+        SheetSource::Local(fixture_dir())
+            .latest_version_info(Lang::DE)
+            .await
+            .is_err()
+    );
+}
@@ -0,0 +1,153 @@
+use raphael_data_updater::*;
+
+fn rlvl(rlvl: u32, job_level: u32) -> RecipeLevel {
+    RecipeLevel {
+        rlvl,
+        job_level,
+        ..Default::default()
+    }
+}
+
+fn recipe(id: u32, item_id: u32, recipe_level: u32, ingredients: &[u32]) -> Recipe {
+    Recipe {
+        id,
+        job_id: 0,
+        item_id,
+        result_amount: 1,
+        max_level_scaling: 0,
+        recipe_level,
+        progress_factor: 100,
+        quality_factor: 100,
+        durability_factor: 100,
+        material_factor: 0,
+        ingredients: ingredients
+            .iter()
+            .map(|&item_id| Ingredient { item_id, amount: 1 })
+            .collect(),
+        is_expert: false,
+        req_craftsmanship: 0,
+        req_control: 0,
+    }
+}
+
+fn item(id: u32) -> Item {
+    Item {
+        id,
+        item_level: 1,
+        item_action: 0,
+        can_be_hq: true,
+        always_collectable: false,
+    }
+}
+
+fn item_name(id: u32, name: &str) -> ItemName {
+    ItemName {
+        id,
+        name: name.to_owned(),
+    }
+}
+
+fn messages(issues: impl Iterator<Item = ValidationIssue>) -> Vec<String> {
+    issues.map(|issue| issue.message).collect()
+}
+
+#[test]
+fn test_valid_tables() {
+    let rlvls = [rlvl(1, 1), rlvl(2, 2)];
+    let level_adjust_table = [
+        LevelAdjustTableEntry { level: 1, rlvl: 1 },
+        LevelAdjustTableEntry { level: 2, rlvl: 2 },
+    ];
+    let recipes = [recipe(1, 100, 2, &[200])];
+    let items = [item(100), item(200)];
+    let meals = [Consumable {
+        item_id: 300,
+        craft_rel: 5,
+        craft_max: 50,
+        ..Default::default()
+    }];
+    let item_names_en = [item_name(100, "Board"), item_name(200, "Log")];
+    let item_names_cn = [item_name(200, "木材")];
+    let tables = GameTables {
+        rlvls: &rlvls,
+        level_adjust_table: &level_adjust_table,
+        recipes: &recipes,
+        items: &items,
+        meals: &meals,
+        potions: &[],
+        gear: &[],
+        materia: &[],
+        stellar_missions: &[],
+        item_names: vec![
+            (Lang::EN, item_names_en.as_slice()),
+            (Lang::CN, item_names_cn.as_slice()),
+        ],
+        stellar_mission_names: Vec::new(),
+    };
+    let report = validate(&tables);
+    assert!(!report.has_errors());
+    // Missing names for delayed languages are only reported as warnings.
+    assert_eq!(
+        messages(report.warnings().cloned()),
+        ["CN: 1 items used by recipes have no name, e.g. [100]"]
+    );
+}
+
+#[test]
+fn test_invalid_tables() {
+    let rlvls = [rlvl(1, 1), rlvl(3, 2)];
+    let level_adjust_table = [LevelAdjustTableEntry { level: 1, rlvl: 1 }];
+    let recipes = [recipe(1, 100, 2, &[200, 201, 202, 203, 204, 205, 206])];
+    let items = [item(100)];
+    let potions = [Consumable {
+        item_id: 300,
+        cp_rel: 120,
+        cp_max: 10,
+        ..Default::default()
+    }];
+    let stellar_missions = [StellarMission {
+        id: 5,
+        job_id: 0,
+        temporary_action: TemporaryAction::None,
+        temporary_action_charges: 0,
+        recipe_ids: vec![2],
+    }];
+    let item_names_en = [item_name(100, "Board")];
+    let tables = GameTables {
+        rlvls: &rlvls,
+        level_adjust_table: &level_adjust_table,
+        recipes: &recipes,
+        items: &items,
+        meals: &[],
+        potions: &potions,
+        gear: &[],
+        materia: &[],
+        stellar_missions: &stellar_missions,
+        item_names: vec![(Lang::EN, item_names_en.as_slice())],
+        stellar_mission_names: Vec::new(),
+    };
+    let report = validate(&tables);
+    assert!(report.has_errors());
+    let errors = messages(report.errors().cloned());
+    let expected_errors = [
+        "Recipe level table entry 1 is for recipe level 3, expected recipe level 2",
+        "Recipe 1 uses recipe level 2, which does not exist",
+        "Recipe 1 has 7 HQ-able ingredients, but at most 6 are supported",
+        "Recipe 1 uses ingredient 200, which is not an exported item",
+        "Level adjust table only covers levels 1 to 1, but the max level is 2",
+        "Stellar mission 5 uses recipe 2, which does not exist",
+        "Consumable 300 (NQ) has an invalid CP bonus of 120% (max 10)",
+        "EN: 7 items used by recipes have no name, e.g. [200, 201, 202, 203, 204, 205, 206]",
+    ];
+    for expected_error in expected_errors {
+        assert!(
+            errors.iter().any(|error| error == expected_error),
+            "missing error: {expected_error}"
+        );
+    }
+    assert!(
+        report
+            .to_string()
+            .contains("error: Recipe 1 uses recipe level 2")
+    );
+}
@@ -1,1002 +1,1002 @@
 nci_array! {
-1 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36165, ], },
-2 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36166, ], },
-3 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36167, ], },
-4 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36168, ], },
-5 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36169, ], },
-6 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36170, ], },
-7 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36171, ], },
-8 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36172, ], },
-9 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36173, ], },
-10 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36174, 36175, ], },
-11 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36176, 36177, ], },
-12 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36178, 36179, ], },
-13 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36180, 36181, ], },
-14 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36182, ], },
-15 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36183, ], },
-16 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36184, ], },
-17 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36185, ], },
-18 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36186, ], },
-19 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36187, ], },
-20 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36188, 36189, ], },
-21 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36190, 36191, ], },
-22 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36192, ], },
-23 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36193, ], },
-24 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36194, ], },
-25 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36195, ], },
-26 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36196, 36197, 36198, ], },
-27 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36199, ], },
-28 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36200, ], },
-29 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36201, ], },
-30 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36202, 36203, 36204, ], },
-31 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36205, 36206, ], },
-32 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36207, 36208, ], },
-33 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36209, ], },
-34 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36210, ], },
-35 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36211, ], },
-36 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36212, ], },
-37 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36213, ], },
-38 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36214, ], },
-39 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36215, ], },
-40 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36216, ], },
-41 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36217, ], },
-42 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36218, ], },
-43 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36219, 36220, ], },
-44 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36222, 36223, ], },
-45 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36225, 36227, ], },
-46 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36228, ], },
-47 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36229, ], },
-48 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36230, ], },
-49 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36231, ], },
-50 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36232, ], },
-51 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36233, ], },
-52 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36234, ], },
-53 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36235, ], },
-54 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36236, ], },
-55 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36237, 36238, ], },
-56 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36239, 36240, ], },
-57 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36241, 36242, ], },
-58 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36243, 36244, ], },
-59 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36245, ], },
-60 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36246, ], },
-61 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36247, ], },
-62 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36248, ], },
-63 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36249, ], },
-64 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36250, ], },
-65 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36251, 36252, ], },
-66 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36253, 36254, ], },
-67 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36255, ], },
-68 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36256, ], },
-69 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36257, ], },
-70 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36258, ], },
-71 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36259, 36260, 36261, ], },
-72 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36262, ], },
-73 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36263, ], },
-74 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36264, ], },
-75 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36265, 36266, 36267, ], },
-76 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36268, 36269, ], },
-77 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36270, 36271, ], },
-78 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36272, ], },
-79 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36273, ], },
-80 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36274, ], },
-81 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36275, ], },
-82 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36276, ], },
-83 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36277, ], },
-84 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36278, ], },
-85 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36279, ], },
-86 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36280, ], },
-87 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36281, ], },
-88 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36282, 36283, ], },
-89 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36285, 36286, ], },
-90 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36288, 36290, ], },
-91 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36291, ], },
-92 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36292, ], },
-93 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36293, ], },
-94 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36294, ], },
-95 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36295, ], },
-96 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36296, ], },
-97 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36297, ], },
-98 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36298, ], },
-99 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36299, ], },
-100 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36300, 36301, ], },
-101 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36302, 36303, ], },
-102 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36304, 36305, ], },
-103 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36306, 36307, ], },
-104 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36308, ], },
-105 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36309, ], },
-106 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36310, ], },
-107 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36311, ], },
-108 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36312, ], },
-109 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36313, ], },
-110 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36314, 36315, ], },
-111 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36316, 36317, ], },
-112 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36318, ], },
-113 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36319, ], },
-114 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36320, ], },
-115 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36321, ], },
-116 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36322, 36323, 36324, ], },
-117 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36325, ], },
-118 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36326, ], },
-119 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36327, ], },
-120 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36328, 36329, 36330, ], },
-121 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36331, 36332, ], },
-122 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36333, 36334, ], },
-123 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36335, ], },
-124 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36336, ], },
-125 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36337, ], },
-126 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36338, ], },
-127 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36339, ], },
-128 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36340, ], },
-129 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36341, ], },
-130 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36342, ], },
-131 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36343, ], },
-132 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36344, ], },
-133 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36345, 36346, ], },
-134 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36348, 36349, ], },
-135 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36351, 36353, ], },
-136 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36354, ], },
-137 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36355, ], },
-138 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36356, ], },
-139 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36357, ], },
-140 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36358, ], },
-141 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36359, ], },
-142 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36360, ], },
-143 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36361, ], },
-144 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36362, ], },
-145 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36363, 36364, ], },
-146 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36365, 36366, ], },
-147 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36367, 36368, ], },
-148 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36369, 36370, ], },
-149 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36371, ], },
-150 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36372, ], },
-151 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36373, ], },
-152 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36374, ], },
-153 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36375, ], },
-154 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36376, ], },
-155 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36377, 36378, ], },
-156 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36379, 36380, ], },
-157 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36381, ], },
-158 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36382, ], },
-159 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36383, ], },
-160 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36384, ], },
-161 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36385, 36386, 36387, ], },
-162 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36388, ], },
-163 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36389, ], },
-164 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36390, ], },
-165 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36391, 36392, 36393, ], },
-166 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36394, 36395, ], },
-167 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36396, 36397, ], },
-168 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36398, ], },
-169 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36399, ], },
-170 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36400, ], },
-171 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36401, ], },
-172 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36402, ], },
-173 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36403, ], },
-174 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36404, ], },
-175 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36405, ], },
-176 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36406, ], },
-177 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36407, ], },
-178 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36408, 36409, ], },
-179 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36411, 36412, ], },
-180 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36414, 36416, ], },
-181 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36417, ], },
-182 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36418, ], },
-183 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36419, ], },
-184 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36420, ], },
-185 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36421, ], },
-186 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36422, ], },
-187 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36423, ], },
-188 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36424, ], },
-189 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36425, ], },
-190 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36426, 36427, ], },
-191 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36428, 36429, ], },
-192 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36430, 36431, ], },
-193 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36432, 36433, ], },
-194 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36434, ], },
-195 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36435, ], },
-196 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36436, ], },
-197 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36437, ], },
-198 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36438, ], },
-199 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36439, ], },
-200 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36440, 36441, ], },
-201 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36442, 36443, ], },
-202 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36444, ], },
-203 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36445, ], },
-204 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36446, ], },
-205 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36447, ], },
-206 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36448, 36449, 36450, ], },
-207 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36451, ], },
-208 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36452, ], },
-209 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36453, ], },
-210 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36454, 36455, 36456, ], },
-211 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36457, 36458, ], },
-212 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36459, 36460, ], },
-213 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36461, ], },
-214 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36462, ], },
-215 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36463, ], },
-216 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36464, ], },
-217 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36465, ], },
-218 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36466, ], },
-219 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36467, ], },
-220 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36468, ], },
-221 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36469, ], },
-222 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36470, ], },
-223 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36471, 36472, ], },
-224 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36474, 36475, ], },
-225 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36477, 36479, ], },
-226 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36480, ], },
-227 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36481, ], },
-228 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36482, ], },
-229 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36483, ], },
-230 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36484, ], },
-231 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36485, ], },
-232 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36486, ], },
-233 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36487, ], },
-234 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36488, ], },
-235 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36489, 36490, ], },
-236 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36491, 36492, ], },
-237 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36493, 36494, ], },
-238 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36495, 36496, ], },
-239 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36497, ], },
-240 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36498, ], },
-241 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36499, ], },
-242 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36500, ], },
-243 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36501, ], },
-244 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36502, ], },
-245 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36503, 36504, ], },
-246 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36505, 36506, ], },
-247 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36507, ], },
-248 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36508, ], },
-249 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36509, ], },
-250 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36510, ], },
-251 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36511, 36512, 36513, ], },
-252 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36514, ], },
-253 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36515, ], },
-254 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36516, ], },
-255 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36517, 36518, 36519, ], },
-256 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36520, 36521, ], },
-257 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36522, 36523, ], },
-258 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36524, ], },
-259 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36525, ], },
-260 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36526, ], },
-261 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36527, ], },
-262 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36528, ], },
-263 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36529, ], },
-264 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36530, ], },
-265 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36531, ], },
-266 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36532, ], },
-267 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36533, ], },
-268 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36534, 36535, ], },
-269 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36537, 36538, ], },
-270 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36540, 36542, ], },
-271 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36543, ], },
-272 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36544, ], },
-273 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36545, ], },
-274 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36546, ], },
-275 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36547, ], },
-276 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36548, ], },
-277 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36549, ], },
-278 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36550, ], },
-279 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36551, ], },
-280 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36552, 36553, ], },
-281 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36554, 36555, ], },
-282 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36556, 36557, ], },
-283 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36558, 36559, ], },
-284 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36560, ], },
-285 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36561, ], },
-286 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36562, ], },
-287 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36563, ], },
-288 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36564, ], },
-289 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36565, ], },
-290 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36566, 36567, ], },
-291 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36568, 36569, ], },
-292 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36570, ], },
-293 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36571, ], },
-294 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36572, ], },
-295 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36573, ], },
-296 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36574, 36575, 36576, ], },
-297 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36577, ], },
-298 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36578, ], },
-299 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36579, ], },
-300 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36580, 36581, 36582, ], },
-301 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36583, 36584, ], },
-302 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36585, 36586, ], },
-303 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36587, ], },
-304 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36588, ], },
-305 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36589, ], },
-306 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36590, ], },
-307 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36591, ], },
-308 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36592, ], },
-309 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36593, ], },
-310 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36594, ], },
-311 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36595, ], },
-312 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36596, ], },
-313 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36597, 36598, ], },
-314 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36600, 36601, ], },
-315 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36603, 36605, ], },
-316 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36606, ], },
-317 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36607, ], },
-318 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36608, ], },
-319 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36609, ], },
-320 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36610, ], },
-321 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36611, ], },
-322 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36612, ], },
-323 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36613, ], },
-324 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36614, ], },
-325 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36615, 36616, ], },
-326 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36617, 36618, ], },
-327 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36619, 36620, ], },
-328 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36621, 36622, ], },
-329 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36623, ], },
-330 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36624, ], },
-331 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36625, ], },
-332 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36626, ], },
-333 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36627, ], },
-334 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36628, ], },
-335 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36629, 36630, ], },
-336 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36631, 36632, ], },
-337 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36633, ], },
-338 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36634, ], },
-339 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36635, ], },
-340 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36636, ], },
-341 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36637, 36638, 36639, ], },
-342 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36640, ], },
-343 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36641, ], },
-344 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36642, ], },
-345 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36643, 36644, 36645, ], },
-346 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36646, 36647, ], },
-347 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36648, 36649, ], },
-348 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36650, ], },
-349 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36651, ], },
-350 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36652, ], },
-351 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36653, ], },
-352 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36654, ], },
-353 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36655, ], },
-354 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36656, ], },
-355 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36657, ], },
-356 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36658, ], },
-357 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36659, ], },
-358 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36660, 36661, ], },
-359 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36663, 36664, ], },
-360 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36666, 36668, ], },
-496 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36669, ], },
-497 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36673, ], },
-498 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36675, ], },
-499 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36671, ], },
-500 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36677, ], },
-501 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36679, ], },
-502 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36678, ], },
-503 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36680, ], },
-504 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36683, ], },
-505 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36670, ], },
-506 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36674, ], },
-507 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36681, ], },
-508 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36672, ], },
-509 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36682, ], },
-510 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36676, ], },
-511 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36684, ], },
-512 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36685, ], },
-513 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36686, ], },
-514 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36687, ], },
-515 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36690, ], },
-516 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36694, ], },
-517 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[36691, ], },
-518 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36695, ], },
-519 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36697, ], },
-520 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[36699, ], },
-521 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36700, ], },
-522 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36703, ], },
-523 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[36701, ], },
-524 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36705, ], },
-525 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36707, ], },
-526 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[36708, ], },
-527 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36711, ], },
-528 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36712, ], },
-529 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[36710, ], },
-530 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36715, ], },
-531 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36719, ], },
-532 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[36718, ], },
-533 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36720, ], },
-534 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36721, ], },
-535 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[36722, ], },
-545 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36950, ], },
-546 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36951, ], },
-547 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36952, ], },
-548 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36953, ], },
-549 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36954, ], },
-550 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36955, ], },
-551 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36956, ], },
-552 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36957, ], },
-553 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36958, ], },
-554 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36959, 36960, ], },
-555 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36961, 36962, ], },
-556 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36963, 36964, ], },
-557 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36965, 36966, ], },
-558 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36967, ], },
-559 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36968, ], },
-560 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36969, ], },
-561 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36970, ], },
-562 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36971, ], },
-563 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36972, ], },
-564 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36973, 36974, ], },
-565 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36975, 36976, ], },
-566 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36977, ], },
-567 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36978, ], },
-568 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36979, ], },
-569 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36980, ], },
-570 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36981, 36982, 36983, ], },
-571 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36984, ], },
-572 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36985, 36986, 36987, ], },
-573 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36988, 36989, ], },
-574 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36990, 36991, ], },
-575 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36992, ], },
-576 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36993, ], },
-577 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36994, ], },
-578 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36995, ], },
-579 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36996, ], },
-580 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36997, ], },
-581 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36998, ], },
-582 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[36999, ], },
-583 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[37000, ], },
-584 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[37001, 37002, ], },
-585 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[37003, 37004, ], },
-586 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[37005, 37006, ], },
-587 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37007, ], },
-588 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37008, ], },
-589 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37009, ], },
-590 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37010, ], },
-591 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37011, ], },
-592 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37012, ], },
-593 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37013, ], },
-594 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37014, ], },
-595 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37015, ], },
-596 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37016, 37017, ], },
-597 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37018, 37019, ], },
-598 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37020, 37021, ], },
-599 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37022, 37023, ], },
-600 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37024, ], },
-601 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37025, ], },
-602 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37026, ], },
-603 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37027, ], },
-604 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37028, ], },
-605 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37029, ], },
-606 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37030, 37031, ], },
-607 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37032, 37033, ], },
-608 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37034, ], },
-609 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37035, ], },
-610 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37036, ], },
-611 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37037, ], },
-612 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37038, 37039, 37040, ], },
-613 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37041, ], },
-614 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37042, 37043, 37044, ], },
-615 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37045, 37046, ], },
-616 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37047, 37048, ], },
-617 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37049, ], },
-618 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37050, ], },
-619 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37051, ], },
-620 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37052, ], },
-621 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37053, ], },
-622 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37054, ], },
-623 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37055, ], },
-624 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37056, ], },
-625 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37057, ], },
-626 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37058, 37059, ], },
-627 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37060, 37061, ], },
-628 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37062, 37063, ], },
-629 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37064, ], },
-630 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37065, ], },
-631 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37066, ], },
-632 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37067, ], },
-633 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37068, ], },
-634 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37069, ], },
-635 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37070, ], },
-636 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37071, ], },
-637 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37072, ], },
-638 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37073, 37074, ], },
-639 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37075, 37076, ], },
-640 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37077, 37078, ], },
-641 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37079, 37080, ], },
-642 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37081, ], },
-643 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37082, ], },
-644 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37083, ], },
-645 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37084, ], },
-646 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37085, ], },
-647 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37086, ], },
-648 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37087, 37088, ], },
-649 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37089, 37090, ], },
-650 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37091, ], },
-651 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37092, ], },
-652 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37093, ], },
-653 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37094, ], },
-654 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37095, 37096, 37097, ], },
-655 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37098, ], },
-656 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37099, 37100, 37101, ], },
-657 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37102, 37103, ], },
-658 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37104, 37105, ], },
-659 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37106, ], },
-660 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37107, ], },
-661 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37108, ], },
-662 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37109, ], },
-663 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37110, ], },
-664 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37111, ], },
-665 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37112, ], },
-666 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37113, ], },
-667 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37114, ], },
-668 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37115, 37116, ], },
-669 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37117, 37118, ], },
-670 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37119, 37120, ], },
-671 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37121, ], },
-672 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37122, ], },
-673 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37123, ], },
-674 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37124, ], },
-675 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37125, ], },
-676 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37126, ], },
-677 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37127, ], },
-678 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37128, ], },
-679 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37129, ], },
-680 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37130, 37131, ], },
-681 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37132, 37133, ], },
-682 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37134, 37135, ], },
-683 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37136, 37137, ], },
-684 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37138, ], },
-685 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37139, ], },
-686 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37140, ], },
-687 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37141, ], },
-688 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37142, ], },
-689 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37143, ], },
-690 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37144, 37145, ], },
-691 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37146, 37147, ], },
-692 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37148, ], },
-693 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37149, ], },
-694 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37150, ], },
-695 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37151, ], },
-696 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37152, 37153, 37154, ], },
-697 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37155, ], },
-698 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37156, 37157, 37158, ], },
-699 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37159, 37160, ], },
-700 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37161, 37162, ], },
-701 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37163, ], },
-702 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37164, ], },
-703 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37165, ], },
-704 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37166, ], },
-705 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37167, ], },
-706 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37168, ], },
-707 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37169, ], },
-708 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37170, ], },
-709 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37171, ], },
-710 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37172, 37173, ], },
-711 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37174, 37175, ], },
-712 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37176, 37177, ], },
-713 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37178, ], },
-714 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37179, ], },
-715 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37180, ], },
-716 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37181, ], },
-717 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37182, ], },
-718 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37183, ], },
-719 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37184, ], },
-720 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37185, ], },
-721 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37186, ], },
-722 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37187, 37188, ], },
-723 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37189, 37190, ], },
-724 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37191, 37192, ], },
-725 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37193, 37194, ], },
-726 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37195, ], },
-727 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37196, ], },
-728 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37197, ], },
-729 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37198, ], },
-730 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37199, ], },
-731 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37200, ], },
-732 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37201, 37202, ], },
-733 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37203, 37204, ], },
-734 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37205, ], },
-735 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37206, ], },
-736 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37207, ], },
-737 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37208, ], },
-738 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37209, 37210, 37211, ], },
-739 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37212, ], },
-740 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37213, 37214, 37215, ], },
-741 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37216, 37217, ], },
-742 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37218, 37219, ], },
-743 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37220, ], },
-744 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37221, ], },
-745 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37222, ], },
-746 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37223, ], },
-747 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37224, ], },
-748 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37225, ], },
-749 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37226, ], },
-750 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37227, ], },
-751 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37228, ], },
-752 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37229, 37230, ], },
-753 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37231, 37232, ], },
-754 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37233, 37234, ], },
-755 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37235, ], },
-756 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37236, ], },
-757 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37237, ], },
-758 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37238, ], },
-759 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37239, ], },
-760 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37240, ], },
-761 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37241, ], },
-762 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37242, ], },
-763 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37243, ], },
-764 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37244, 37245, ], },
-765 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37246, 37247, ], },
-766 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37248, 37249, ], },
-767 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37250, 37251, ], },
-768 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37252, ], },
-769 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37253, ], },
-770 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37254, ], },
-771 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37255, ], },
-772 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37256, ], },
-773 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37257, ], },
-774 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37258, 37259, ], },
-775 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37260, 37261, ], },
-776 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37262, ], },
-777 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37263, ], },
-778 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37264, ], },
-779 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37265, ], },
-780 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37266, 37267, 37268, ], },
-781 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37269, ], },
-782 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37270, 37271, 37272, ], },
-783 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37273, 37274, ], },
-784 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37275, 37276, ], },
-785 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37277, ], },
-786 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37278, ], },
-787 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37279, ], },
-788 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37280, ], },
-789 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37281, ], },
-790 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37282, ], },
-791 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37283, ], },
-792 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37284, ], },
-793 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37285, ], },
-794 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37286, 37287, ], },
-795 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37288, 37289, ], },
-796 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37290, 37291, ], },
-797 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37292, ], },
-798 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37293, ], },
-799 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37294, ], },
-800 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37295, ], },
-801 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37296, ], },
-802 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37297, ], },
-803 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37298, ], },
-804 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37299, ], },
-805 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37300, ], },
-806 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37301, 37302, ], },
-807 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37303, 37304, ], },
-808 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37305, 37306, ], },
-809 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37307, 37308, ], },
-810 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37309, ], },
-811 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37310, ], },
-812 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37311, ], },
-813 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37312, ], },
-814 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37313, ], },
-815 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37314, ], },
-816 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37315, 37316, ], },
-817 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37317, 37318, ], },
-818 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37319, ], },
-819 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37320, ], },
-820 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37321, ], },
-821 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37322, ], },
-822 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37323, 37324, 37325, ], },
-823 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37326, ], },
-824 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37327, 37328, 37329, ], },
-825 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37330, 37331, ], },
-826 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37332, 37333, ], },
-827 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37334, ], },
-828 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37335, ], },
-829 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37336, ], },
-830 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37337, ], },
-831 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37338, ], },
-832 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37339, ], },
-833 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37340, ], },
-834 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37341, ], },
-835 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37342, ], },
-836 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37343, 37344, ], },
-837 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37345, 37346, ], },
-838 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37347, 37348, ], },
-839 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37349, ], },
-840 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37350, ], },
-841 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37351, ], },
-842 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37352, ], },
-843 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37353, ], },
-844 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37354, ], },
-845 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37355, ], },
-846 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37356, ], },
-847 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37357, ], },
-848 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37358, 37359, ], },
-849 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37360, 37361, ], },
-850 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37362, 37363, ], },
-851 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37364, 37365, ], },
-852 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37366, ], },
-853 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37367, ], },
-854 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37368, ], },
-855 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37369, ], },
-856 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37370, ], },
-857 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37371, ], },
-858 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37372, 37373, ], },
-859 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37374, 37375, ], },
-860 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37376, ], },
-861 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37377, ], },
-862 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37378, ], },
-863 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37379, ], },
-864 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37380, 37381, 37382, ], },
-865 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37383, ], },
-866 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37384, 37385, 37386, ], },
-867 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37387, 37388, ], },
-868 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37389, 37390, ], },
-869 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37391, ], },
-870 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37392, ], },
-871 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37393, ], },
-872 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37394, ], },
-873 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37395, ], },
-874 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37396, ], },
-875 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37397, ], },
-876 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37398, ], },
-877 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37399, ], },
-878 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37400, 37401, ], },
-879 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37402, 37403, ], },
-880 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37404, 37405, ], },
-1007 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[37406, ], },
-1008 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[37407, ], },
-1009 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[37408, ], },
-1010 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37409, ], },
-1011 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37410, ], },
-1012 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37411, ], },
-1013 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37412, ], },
-1014 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37413, ], },
-1015 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37414, ], },
-1016 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37415, ], },
-1017 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37416, ], },
-1018 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37417, ], },
-1019 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37418, ], },
-1020 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37419, ], },
-1021 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37420, ], },
-1022 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37421, ], },
-1023 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37422, ], },
-1024 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37423, ], },
-1025 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37424, ], },
-1026 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37425, ], },
-1027 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37426, ], },
-1028 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37427, ], },
-1029 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37428, ], },
-1030 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37429, ], },
-1040 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[37496, ], },
-1041 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[37497, ], },
-1042 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[37498, ], },
-1043 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[37499, ], },
-1044 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[37500, ], },
-1045 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[37501, ], },
-1046 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[37502, ], },
-1047 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[37503, 37504, ], },
-1048 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[37505, ], },
-1049 => StellarMission { job_id: 0, stellar_steady_hand_charges: 3, recipe_ids: &[37506, ], },
-1050 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[37507, ], },
-1051 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[37508, 37509, ], },
-1052 => StellarMission { job_id: 0, stellar_steady_hand_charges: 2, recipe_ids: &[37510, 37511, ], },
-1053 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[37512, ], },
-1054 => StellarMission { job_id: 0, stellar_steady_hand_charges: 2, recipe_ids: &[37513, ], },
-1055 => StellarMission { job_id: 0, stellar_steady_hand_charges: 2, recipe_ids: &[37514, ], },
-1056 => StellarMission { job_id: 0, stellar_steady_hand_charges: 2, recipe_ids: &[37515, ], },
-1057 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[37516, ], },
-1058 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[37517, 37518, ], },
-1059 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[37519, ], },
-1060 => StellarMission { job_id: 0, stellar_steady_hand_charges: 1, recipe_ids: &[37520, ], },
-1061 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[37521, ], },
-1062 => StellarMission { job_id: 0, stellar_steady_hand_charges: 2, recipe_ids: &[37522, ], },
-1063 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[37523, 37524, ], },
-1064 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[37525, ], },
-1065 => StellarMission { job_id: 0, stellar_steady_hand_charges: 1, recipe_ids: &[37526, 37527, ], },
-1066 => StellarMission { job_id: 0, stellar_steady_hand_charges: 1, recipe_ids: &[37528, 37529, ], },
-1067 => StellarMission { job_id: 0, stellar_steady_hand_charges: 1, recipe_ids: &[37530, 37531, ], },
-1068 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37532, ], },
-1069 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37533, ], },
-1070 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37534, ], },
-1071 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37535, ], },
-1072 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37536, ], },
-1073 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37537, ], },
-1074 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37538, ], },
-1075 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37539, 37540, ], },
-1076 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37541, ], },
-1077 => StellarMission { job_id: 1, stellar_steady_hand_charges: 3, recipe_ids: &[37542, ], },
-1078 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37543, ], },
-1079 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37544, 37545, ], },
-1080 => StellarMission { job_id: 1, stellar_steady_hand_charges: 2, recipe_ids: &[37546, 37547, ], },
-1081 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37548, ], },
-1082 => StellarMission { job_id: 1, stellar_steady_hand_charges: 2, recipe_ids: &[37549, ], },
-1083 => StellarMission { job_id: 1, stellar_steady_hand_charges: 2, recipe_ids: &[37550, ], },
-1084 => StellarMission { job_id: 1, stellar_steady_hand_charges: 2, recipe_ids: &[37551, ], },
-1085 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37552, ], },
-1086 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37553, 37554, ], },
-1087 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37555, ], },
-1088 => StellarMission { job_id: 1, stellar_steady_hand_charges: 1, recipe_ids: &[37556, ], },
-1089 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37557, ], },
-1090 => StellarMission { job_id: 1, stellar_steady_hand_charges: 2, recipe_ids: &[37558, ], },
-1091 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37559, 37560, ], },
-1092 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37561, ], },
-1093 => StellarMission { job_id: 1, stellar_steady_hand_charges: 1, recipe_ids: &[37562, 37563, ], },
-1094 => StellarMission { job_id: 1, stellar_steady_hand_charges: 1, recipe_ids: &[37564, 37565, ], },
-1095 => StellarMission { job_id: 1, stellar_steady_hand_charges: 1, recipe_ids: &[37566, 37567, ], },
-1096 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37568, ], },
-1097 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37569, ], },
-1098 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37570, ], },
-1099 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37571, ], },
-1100 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37572, ], },
-1101 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37573, ], },
-1102 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37574, ], },
-1103 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37575, 37576, ], },
-1104 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37577, ], },
-1105 => StellarMission { job_id: 2, stellar_steady_hand_charges: 3, recipe_ids: &[37578, ], },
-1106 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37579, ], },
-1107 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37580, 37581, ], },
-1108 => StellarMission { job_id: 2, stellar_steady_hand_charges: 2, recipe_ids: &[37582, 37583, ], },
-1109 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37584, ], },
-1110 => StellarMission { job_id: 2, stellar_steady_hand_charges: 2, recipe_ids: &[37585, ], },
-1111 => StellarMission { job_id: 2, stellar_steady_hand_charges: 2, recipe_ids: &[37586, ], },
-1112 => StellarMission { job_id: 2, stellar_steady_hand_charges: 2, recipe_ids: &[37587, ], },
-1113 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37588, ], },
-1114 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37589, 37590, ], },
-1115 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37591, ], },
-1116 => StellarMission { job_id: 2, stellar_steady_hand_charges: 1, recipe_ids: &[37592, ], },
-1117 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37593, ], },
-1118 => StellarMission { job_id: 2, stellar_steady_hand_charges: 2, recipe_ids: &[37594, ], },
-1119 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37595, 37596, ], },
-1120 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37597, ], },
-1121 => StellarMission { job_id: 2, stellar_steady_hand_charges: 1, recipe_ids: &[37598, 37599, ], },
-1122 => StellarMission { job_id: 2, stellar_steady_hand_charges: 1, recipe_ids: &[37600, 37601, ], },
-1123 => StellarMission { job_id: 2, stellar_steady_hand_charges: 1, recipe_ids: &[37602, 37603, ], },
-1124 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37604, ], },
-1125 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37605, ], },
-1126 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37606, ], },
-1127 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37607, ], },
-1128 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37608, ], },
-1129 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37609, ], },
-1130 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37610, ], },
-1131 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37611, 37612, ], },
-1132 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37613, ], },
-1133 => StellarMission { job_id: 3, stellar_steady_hand_charges: 3, recipe_ids: &[37614, ], },
-1134 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37615, ], },
-1135 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37616, 37617, ], },
-1136 => StellarMission { job_id: 3, stellar_steady_hand_charges: 2, recipe_ids: &[37618, 37619, ], },
-1137 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37620, ], },
-1138 => StellarMission { job_id: 3, stellar_steady_hand_charges: 2, recipe_ids: &[37621, ], },
-1139 => StellarMission { job_id: 3, stellar_steady_hand_charges: 2, recipe_ids: &[37622, ], },
-1140 => StellarMission { job_id: 3, stellar_steady_hand_charges: 2, recipe_ids: &[37623, ], },
-1141 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37624, ], },
-1142 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37625, 37626, ], },
-1143 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37627, ], },
-1144 => StellarMission { job_id: 3, stellar_steady_hand_charges: 1, recipe_ids: &[37628, ], },
-1145 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37629, ], },
-1146 => StellarMission { job_id: 3, stellar_steady_hand_charges: 2, recipe_ids: &[37630, ], },
-1147 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37631, 37632, ], },
-1148 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37633, ], },
-1149 => StellarMission { job_id: 3, stellar_steady_hand_charges: 1, recipe_ids: &[37634, 37635, ], },
-1150 => StellarMission { job_id: 3, stellar_steady_hand_charges: 1, recipe_ids: &[37636, 37637, ], },
-1151 => StellarMission { job_id: 3, stellar_steady_hand_charges: 1, recipe_ids: &[37638, 37639, ], },
-1152 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37640, ], },
-1153 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37641, ], },
-1154 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37642, ], },
-1155 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37643, ], },
-1156 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37644, ], },
-1157 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37645, ], },
-1158 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37646, ], },
-1159 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37647, 37648, ], },
-1160 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37649, ], },
-1161 => StellarMission { job_id: 4, stellar_steady_hand_charges: 3, recipe_ids: &[37650, ], },
-1162 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37651, ], },
-1163 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37652, 37653, ], },
-1164 => StellarMission { job_id: 4, stellar_steady_hand_charges: 2, recipe_ids: &[37654, 37655, ], },
-1165 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37656, ], },
-1166 => StellarMission { job_id: 4, stellar_steady_hand_charges: 2, recipe_ids: &[37657, ], },
-1167 => StellarMission { job_id: 4, stellar_steady_hand_charges: 2, recipe_ids: &[37658, ], },
-1168 => StellarMission { job_id: 4, stellar_steady_hand_charges: 2, recipe_ids: &[37659, ], },
-1169 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37660, ], },
-1170 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37661, 37662, ], },
-1171 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37663, ], },
-1172 => StellarMission { job_id: 4, stellar_steady_hand_charges: 1, recipe_ids: &[37664, ], },
-1173 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37665, ], },
-1174 => StellarMission { job_id: 4, stellar_steady_hand_charges: 2, recipe_ids: &[37666, ], },
-1175 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37667, 37668, ], },
-1176 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37669, ], },
-1177 => StellarMission { job_id: 4, stellar_steady_hand_charges: 1, recipe_ids: &[37670, 37671, ], },
-1178 => StellarMission { job_id: 4, stellar_steady_hand_charges: 1, recipe_ids: &[37672, 37673, ], },
-1179 => StellarMission { job_id: 4, stellar_steady_hand_charges: 1, recipe_ids: &[37674, 37675, ], },
-1180 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37676, ], },
-1181 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37677, ], },
-1182 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37678, ], },
-1183 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37679, ], },
-1184 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37680, ], },
-1185 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37681, ], },
-1186 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37682, ], },
-1187 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37683, 37684, ], },
-1188 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37685, ], },
-1189 => StellarMission { job_id: 5, stellar_steady_hand_charges: 3, recipe_ids: &[37686, ], },
-1190 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37687, ], },
-1191 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37688, 37689, ], },
-1192 => StellarMission { job_id: 5, stellar_steady_hand_charges: 2, recipe_ids: &[37690, 37691, ], },
-1193 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37692, ], },
-1194 => StellarMission { job_id: 5, stellar_steady_hand_charges: 2, recipe_ids: &[37693, ], },
-1195 => StellarMission { job_id: 5, stellar_steady_hand_charges: 2, recipe_ids: &[37694, ], },
-1196 => StellarMission { job_id: 5, stellar_steady_hand_charges: 2, recipe_ids: &[37695, ], },
-1197 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37696, ], },
-1198 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37697, 37698, ], },
-1199 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37699, ], },
-1200 => StellarMission { job_id: 5, stellar_steady_hand_charges: 1, recipe_ids: &[37700, ], },
-1201 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37701, ], },
-1202 => StellarMission { job_id: 5, stellar_steady_hand_charges: 2, recipe_ids: &[37702, ], },
-1203 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37703, 37704, ], },
-1204 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37705, ], },
-1205 => StellarMission { job_id: 5, stellar_steady_hand_charges: 1, recipe_ids: &[37706, 37707, ], },
-1206 => StellarMission { job_id: 5, stellar_steady_hand_charges: 1, recipe_ids: &[37708, 37709, ], },
-1207 => StellarMission { job_id: 5, stellar_steady_hand_charges: 1, recipe_ids: &[37710, 37711, ], },
-1208 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37712, ], },
-1209 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37713, ], },
-1210 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37714, ], },
-1211 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37715, ], },
-1212 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37716, ], },
-1213 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37717, ], },
-1214 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37718, ], },
-1215 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37719, 37720, ], },
-1216 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37721, ], },
-1217 => StellarMission { job_id: 6, stellar_steady_hand_charges: 3, recipe_ids: &[37722, ], },
-1218 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37723, ], },
-1219 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37724, 37725, ], },
-1220 => StellarMission { job_id: 6, stellar_steady_hand_charges: 2, recipe_ids: &[37726, 37727, ], },
-1221 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37728, ], },
-1222 => StellarMission { job_id: 6, stellar_steady_hand_charges: 2, recipe_ids: &[37729, ], },
-1223 => StellarMission { job_id: 6, stellar_steady_hand_charges: 2, recipe_ids: &[37730, ], },
-1224 => StellarMission { job_id: 6, stellar_steady_hand_charges: 2, recipe_ids: &[37731, ], },
-1225 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37732, ], },
-1226 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37733, 37734, ], },
-1227 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37735, ], },
-1228 => StellarMission { job_id: 6, stellar_steady_hand_charges: 1, recipe_ids: &[37736, ], },
-1229 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37737, ], },
-1230 => StellarMission { job_id: 6, stellar_steady_hand_charges: 2, recipe_ids: &[37738, ], },
-1231 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37739, 37740, ], },
-1232 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37741, ], },
-1233 => StellarMission { job_id: 6, stellar_steady_hand_charges: 1, recipe_ids: &[37742, 37743, ], },
-1234 => StellarMission { job_id: 6, stellar_steady_hand_charges: 1, recipe_ids: &[37744, 37745, ], },
-1235 => StellarMission { job_id: 6, stellar_steady_hand_charges: 1, recipe_ids: &[37746, 37747, ], },
-1236 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37748, ], },
-1237 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37749, ], },
-1238 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37750, ], },
-1239 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37751, ], },
-1240 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37752, ], },
-1241 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37753, ], },
-1242 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37754, ], },
-1243 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37755, 37756, ], },
-1244 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37757, ], },
-1245 => StellarMission { job_id: 7, stellar_steady_hand_charges: 3, recipe_ids: &[37758, ], },
-1246 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37759, ], },
-1247 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37760, 37761, ], },
-1248 => StellarMission { job_id: 7, stellar_steady_hand_charges: 2, recipe_ids: &[37762, 37763, ], },
-1249 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37764, ], },
-1250 => StellarMission { job_id: 7, stellar_steady_hand_charges: 2, recipe_ids: &[37765, ], },
-1251 => StellarMission { job_id: 7, stellar_steady_hand_charges: 2, recipe_ids: &[37766, ], },
-1252 => StellarMission { job_id: 7, stellar_steady_hand_charges: 2, recipe_ids: &[37767, ], },
-1253 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37768, ], },
-1254 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37769, 37770, ], },
-1255 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37771, ], },
-1256 => StellarMission { job_id: 7, stellar_steady_hand_charges: 1, recipe_ids: &[37772, ], },
-1257 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37773, ], },
-1258 => StellarMission { job_id: 7, stellar_steady_hand_charges: 2, recipe_ids: &[37774, ], },
-1259 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37775, 37776, ], },
-1260 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37777, ], },
-1261 => StellarMission { job_id: 7, stellar_steady_hand_charges: 1, recipe_ids: &[37778, 37779, ], },
-1262 => StellarMission { job_id: 7, stellar_steady_hand_charges: 1, recipe_ids: &[37780, 37781, ], },
-1263 => StellarMission { job_id: 7, stellar_steady_hand_charges: 1, recipe_ids: &[37782, 37783, ], },
-1348 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[37784, ], },
-1349 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: &[37785, ], },
-1350 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37786, ], },
-1351 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: &[37787, ], },
-1352 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37788, ], },
-1353 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: &[37789, ], },
-1354 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37790, ], },
-1355 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: &[37791, ], },
-1356 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37792, ], },
-1357 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: &[37793, ], },
-1358 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37794, ], },
-1359 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: &[37795, ], },
-1360 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37796, ], },
-1361 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: &[37797, ], },
-1362 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37798, ], },
-1363 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: &[37799, ], },
+1 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36165, ]), },
+2 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36166, ]), },
+3 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36167, ]), },
+4 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36168, ]), },
+5 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36169, ]), },
+6 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36170, ]), },
+7 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36171, ]), },
+8 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36172, ]), },
+9 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36173, ]), },
+10 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36174, 36175, ]), },
+11 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36176, 36177, ]), },
+12 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36178, 36179, ]), },
+13 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36180, 36181, ]), },
+14 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36182, ]), },
+15 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36183, ]), },
+16 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36184, ]), },
+17 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36185, ]), },
+18 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36186, ]), },
+19 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36187, ]), },
+20 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36188, 36189, ]), },
+21 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36190, 36191, ]), },
+22 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36192, ]), },
+23 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36193, ]), },
+24 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36194, ]), },
+25 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36195, ]), },
+26 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36196, 36197, 36198, ]), },
+27 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36199, ]), },
+28 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36200, ]), },
+29 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36201, ]), },
+30 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36202, 36203, 36204, ]), },
+31 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36205, 36206, ]), },
+32 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36207, 36208, ]), },
+33 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36209, ]), },
+34 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36210, ]), },
+35 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36211, ]), },
+36 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36212, ]), },
+37 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36213, ]), },
+38 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36214, ]), },
+39 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36215, ]), },
+40 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36216, ]), },
+41 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36217, ]), },
+42 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36218, ]), },
+43 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36219, 36220, ]), },
+44 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36222, 36223, ]), },
+45 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36225, 36227, ]), },
+46 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36228, ]), },
+47 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36229, ]), },
+48 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36230, ]), },
+49 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36231, ]), },
+50 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36232, ]), },
+51 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36233, ]), },
+52 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36234, ]), },
+53 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36235, ]), },
+54 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36236, ]), },
+55 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36237, 36238, ]), },
+56 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36239, 36240, ]), },
+57 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36241, 36242, ]), },
+58 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36243, 36244, ]), },
+59 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36245, ]), },
+60 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36246, ]), },
+61 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36247, ]), },
+62 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36248, ]), },
+63 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36249, ]), },
+64 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36250, ]), },
+65 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36251, 36252, ]), },
+66 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36253, 36254, ]), },
+67 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36255, ]), },
+68 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36256, ]), },
+69 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36257, ]), },
+70 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36258, ]), },
+71 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36259, 36260, 36261, ]), },
+72 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36262, ]), },
+73 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36263, ]), },
+74 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36264, ]), },
+75 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36265, 36266, 36267, ]), },
+76 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36268, 36269, ]), },
+77 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36270, 36271, ]), },
+78 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36272, ]), },
+79 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36273, ]), },
+80 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36274, ]), },
+81 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36275, ]), },
+82 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36276, ]), },
+83 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36277, ]), },
+84 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36278, ]), },
+85 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36279, ]), },
+86 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36280, ]), },
+87 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36281, ]), },
+88 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36282, 36283, ]), },
+89 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36285, 36286, ]), },
+90 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36288, 36290, ]), },
+91 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36291, ]), },
+92 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36292, ]), },
+93 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36293, ]), },
+94 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36294, ]), },
+95 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36295, ]), },
+96 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36296, ]), },
+97 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36297, ]), },
+98 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36298, ]), },
+99 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36299, ]), },
+100 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36300, 36301, ]), },
+101 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36302, 36303, ]), },
+102 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36304, 36305, ]), },
+103 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36306, 36307, ]), },
+104 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36308, ]), },
+105 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36309, ]), },
+106 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36310, ]), },
+107 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36311, ]), },
+108 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36312, ]), },
+109 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36313, ]), },
+110 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36314, 36315, ]), },
+111 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36316, 36317, ]), },
+112 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36318, ]), },
+113 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36319, ]), },
+114 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36320, ]), },
+115 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36321, ]), },
+116 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36322, 36323, 36324, ]), },
+117 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36325, ]), },
+118 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36326, ]), },
+119 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36327, ]), },
+120 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36328, 36329, 36330, ]), },
+121 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36331, 36332, ]), },
+122 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36333, 36334, ]), },
+123 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36335, ]), },
+124 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36336, ]), },
+125 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36337, ]), },
+126 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36338, ]), },
+127 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36339, ]), },
+128 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36340, ]), },
+129 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36341, ]), },
+130 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36342, ]), },
+131 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36343, ]), },
+132 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36344, ]), },
+133 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36345, 36346, ]), },
+134 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36348, 36349, ]), },
+135 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36351, 36353, ]), },
+136 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36354, ]), },
+137 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36355, ]), },
+138 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36356, ]), },
+139 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36357, ]), },
+140 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36358, ]), },
+141 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36359, ]), },
+142 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36360, ]), },
+143 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36361, ]), },
+144 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36362, ]), },
+145 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36363, 36364, ]), },
+146 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36365, 36366, ]), },
+147 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36367, 36368, ]), },
+148 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36369, 36370, ]), },
+149 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36371, ]), },
+150 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36372, ]), },
+151 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36373, ]), },
+152 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36374, ]), },
+153 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36375, ]), },
+154 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36376, ]), },
+155 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36377, 36378, ]), },
+156 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36379, 36380, ]), },
+157 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36381, ]), },
+158 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36382, ]), },
+159 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36383, ]), },
+160 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36384, ]), },
+161 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36385, 36386, 36387, ]), },
+162 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36388, ]), },
+163 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36389, ]), },
+164 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36390, ]), },
+165 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36391, 36392, 36393, ]), },
+166 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36394, 36395, ]), },
+167 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36396, 36397, ]), },
+168 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36398, ]), },
+169 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36399, ]), },
+170 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36400, ]), },
+171 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36401, ]), },
+172 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36402, ]), },
+173 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36403, ]), },
+174 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36404, ]), },
+175 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36405, ]), },
+176 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36406, ]), },
+177 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36407, ]), },
+178 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36408, 36409, ]), },
+179 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36411, 36412, ]), },
+180 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36414, 36416, ]), },
+181 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36417, ]), },
+182 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36418, ]), },
+183 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36419, ]), },
+184 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36420, ]), },
+185 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36421, ]), },
+186 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36422, ]), },
+187 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36423, ]), },
+188 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36424, ]), },
+189 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36425, ]), },
+190 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36426, 36427, ]), },
+191 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36428, 36429, ]), },
+192 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36430, 36431, ]), },
+193 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36432, 36433, ]), },
+194 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36434, ]), },
+195 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36435, ]), },
+196 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36436, ]), },
+197 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36437, ]), },
+198 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36438, ]), },
+199 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36439, ]), },
+200 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36440, 36441, ]), },
+201 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36442, 36443, ]), },
+202 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36444, ]), },
+203 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36445, ]), },
+204 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36446, ]), },
+205 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36447, ]), },
+206 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36448, 36449, 36450, ]), },
+207 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36451, ]), },
+208 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36452, ]), },
+209 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36453, ]), },
+210 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36454, 36455, 36456, ]), },
+211 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36457, 36458, ]), },
+212 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36459, 36460, ]), },
+213 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36461, ]), },
+214 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36462, ]), },
+215 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36463, ]), },
+216 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36464, ]), },
+217 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36465, ]), },
+218 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36466, ]), },
+219 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36467, ]), },
+220 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36468, ]), },
+221 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36469, ]), },
+222 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36470, ]), },
+223 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36471, 36472, ]), },
+224 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36474, 36475, ]), },
+225 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36477, 36479, ]), },
+226 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36480, ]), },
+227 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36481, ]), },
+228 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36482, ]), },
+229 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36483, ]), },
+230 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36484, ]), },
+231 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36485, ]), },
+232 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36486, ]), },
+233 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36487, ]), },
+234 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36488, ]), },
+235 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36489, 36490, ]), },
+236 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36491, 36492, ]), },
+237 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36493, 36494, ]), },
+238 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36495, 36496, ]), },
+239 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36497, ]), },
+240 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36498, ]), },
+241 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36499, ]), },
+242 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36500, ]), },
+243 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36501, ]), },
+244 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36502, ]), },
+245 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36503, 36504, ]), },
+246 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36505, 36506, ]), },
+247 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36507, ]), },
+248 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36508, ]), },
+249 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36509, ]), },
+250 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36510, ]), },
+251 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36511, 36512, 36513, ]), },
+252 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36514, ]), },
+253 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36515, ]), },
+254 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36516, ]), },
+255 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36517, 36518, 36519, ]), },
+256 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36520, 36521, ]), },
+257 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36522, 36523, ]), },
+258 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36524, ]), },
+259 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36525, ]), },
+260 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36526, ]), },
+261 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36527, ]), },
+262 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36528, ]), },
+263 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36529, ]), },
+264 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36530, ]), },
+265 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36531, ]), },
+266 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36532, ]), },
+267 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36533, ]), },
+268 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36534, 36535, ]), },
+269 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36537, 36538, ]), },
+270 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36540, 36542, ]), },
+271 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36543, ]), },
+272 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36544, ]), },
+273 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36545, ]), },
+274 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36546, ]), },
+275 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36547, ]), },
+276 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36548, ]), },
+277 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36549, ]), },
+278 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36550, ]), },
+279 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36551, ]), },
+280 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36552, 36553, ]), },
+281 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36554, 36555, ]), },
+282 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36556, 36557, ]), },
+283 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36558, 36559, ]), },
+284 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36560, ]), },
+285 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36561, ]), },
+286 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36562, ]), },
+287 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36563, ]), },
+288 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36564, ]), },
+289 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36565, ]), },
+290 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36566, 36567, ]), },
+291 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36568, 36569, ]), },
+292 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36570, ]), },
+293 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36571, ]), },
+294 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36572, ]), },
+295 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36573, ]), },
+296 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36574, 36575, 36576, ]), },
+297 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36577, ]), },
+298 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36578, ]), },
+299 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36579, ]), },
+300 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36580, 36581, 36582, ]), },
+301 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36583, 36584, ]), },
+302 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36585, 36586, ]), },
+303 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36587, ]), },
+304 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36588, ]), },
+305 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36589, ]), },
+306 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36590, ]), },
+307 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36591, ]), },
+308 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36592, ]), },
+309 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36593, ]), },
+310 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36594, ]), },
+311 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36595, ]), },
+312 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36596, ]), },
+313 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36597, 36598, ]), },
+314 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36600, 36601, ]), },
+315 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36603, 36605, ]), },
+316 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36606, ]), },
+317 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36607, ]), },
+318 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36608, ]), },
+319 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36609, ]), },
+320 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36610, ]), },
+321 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36611, ]), },
+322 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36612, ]), },
+323 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36613, ]), },
+324 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36614, ]), },
+325 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36615, 36616, ]), },
+326 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36617, 36618, ]), },
+327 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36619, 36620, ]), },
+328 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36621, 36622, ]), },
+329 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36623, ]), },
+330 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36624, ]), },
+331 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36625, ]), },
+332 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36626, ]), },
+333 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36627, ]), },
+334 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36628, ]), },
+335 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36629, 36630, ]), },
+336 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36631, 36632, ]), },
+337 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36633, ]), },
+338 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36634, ]), },
+339 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36635, ]), },
+340 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36636, ]), },
+341 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36637, 36638, 36639, ]), },
+342 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36640, ]), },
+343 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36641, ]), },
+344 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36642, ]), },
+345 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36643, 36644, 36645, ]), },
+346 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36646, 36647, ]), },
+347 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36648, 36649, ]), },
+348 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36650, ]), },
+349 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36651, ]), },
+350 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36652, ]), },
+351 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36653, ]), },
+352 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36654, ]), },
+353 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36655, ]), },
+354 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36656, ]), },
+355 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36657, ]), },
+356 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36658, ]), },
+357 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36659, ]), },
+358 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36660, 36661, ]), },
+359 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36663, 36664, ]), },
+360 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36666, 36668, ]), },
+496 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36669, ]), },
+497 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36673, ]), },
+498 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36675, ]), },
+499 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36671, ]), },
+500 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36677, ]), },
+501 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36679, ]), },
+502 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36678, ]), },
+503 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36680, ]), },
+504 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36683, ]), },
+505 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36670, ]), },
+506 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36674, ]), },
+507 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36681, ]), },
+508 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36672, ]), },
+509 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36682, ]), },
+510 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36676, ]), },
+511 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36684, ]), },
+512 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36685, ]), },
+513 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36686, ]), },
+514 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36687, ]), },
+515 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36690, ]), },
+516 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36694, ]), },
+517 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36691, ]), },
+518 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36695, ]), },
+519 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36697, ]), },
+520 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36699, ]), },
+521 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36700, ]), },
+522 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36703, ]), },
+523 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36701, ]), },
+524 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36705, ]), },
+525 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36707, ]), },
+526 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36708, ]), },
+527 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36711, ]), },
+528 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36712, ]), },
+529 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36710, ]), },
+530 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36715, ]), },
+531 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36719, ]), },
+532 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36718, ]), },
+533 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36720, ]), },
+534 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36721, ]), },
+535 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36722, ]), },
+545 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36950, ]), },
+546 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36951, ]), },
+547 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36952, ]), },
+548 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36953, ]), },
+549 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36954, ]), },
+550 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36955, ]), },
+551 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36956, ]), },
+552 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36957, ]), },
+553 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36958, ]), },
+554 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36959, 36960, ]), },
+555 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36961, 36962, ]), },
+556 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36963, 36964, ]), },
+557 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36965, 36966, ]), },
+558 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36967, ]), },
+559 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36968, ]), },
+560 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36969, ]), },
+561 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36970, ]), },
+562 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36971, ]), },
+563 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36972, ]), },
+564 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36973, 36974, ]), },
+565 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36975, 36976, ]), },
+566 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36977, ]), },
+567 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36978, ]), },
+568 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36979, ]), },
+569 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36980, ]), },
+570 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36981, 36982, 36983, ]), },
+571 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36984, ]), },
+572 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36985, 36986, 36987, ]), },
+573 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36988, 36989, ]), },
+574 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36990, 36991, ]), },
+575 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36992, ]), },
+576 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36993, ]), },
+577 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36994, ]), },
+578 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36995, ]), },
+579 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36996, ]), },
+580 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36997, ]), },
+581 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36998, ]), },
+582 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[36999, ]), },
+583 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37000, ]), },
+584 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37001, 37002, ]), },
+585 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37003, 37004, ]), },
+586 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37005, 37006, ]), },
+587 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37007, ]), },
+588 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37008, ]), },
+589 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37009, ]), },
+590 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37010, ]), },
+591 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37011, ]), },
+592 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37012, ]), },
+593 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37013, ]), },
+594 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37014, ]), },
+595 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37015, ]), },
+596 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37016, 37017, ]), },
+597 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37018, 37019, ]), },
+598 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37020, 37021, ]), },
+599 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37022, 37023, ]), },
+600 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37024, ]), },
+601 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37025, ]), },
+602 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37026, ]), },
+603 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37027, ]), },
+604 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37028, ]), },
+605 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37029, ]), },
+606 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37030, 37031, ]), },
+607 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37032, 37033, ]), },
+608 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37034, ]), },
+609 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37035, ]), },
+610 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37036, ]), },
+611 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37037, ]), },
+612 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37038, 37039, 37040, ]), },
+613 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37041, ]), },
+614 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37042, 37043, 37044, ]), },
+615 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37045, 37046, ]), },
+616 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37047, 37048, ]), },
+617 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37049, ]), },
+618 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37050, ]), },
+619 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37051, ]), },
+620 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37052, ]), },
+621 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37053, ]), },
+622 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37054, ]), },
+623 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37055, ]), },
+624 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37056, ]), },
+625 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37057, ]), },
+626 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37058, 37059, ]), },
+627 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37060, 37061, ]), },
+628 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37062, 37063, ]), },
+629 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37064, ]), },
+630 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37065, ]), },
+631 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37066, ]), },
+632 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37067, ]), },
+633 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37068, ]), },
+634 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37069, ]), },
+635 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37070, ]), },
+636 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37071, ]), },
+637 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37072, ]), },
+638 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37073, 37074, ]), },
+639 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37075, 37076, ]), },
+640 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37077, 37078, ]), },
+641 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37079, 37080, ]), },
+642 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37081, ]), },
+643 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37082, ]), },
+644 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37083, ]), },
+645 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37084, ]), },
+646 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37085, ]), },
+647 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37086, ]), },
+648 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37087, 37088, ]), },
+649 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37089, 37090, ]), },
+650 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37091, ]), },
+651 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37092, ]), },
+652 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37093, ]), },
+653 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37094, ]), },
+654 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37095, 37096, 37097, ]), },
+655 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37098, ]), },
+656 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37099, 37100, 37101, ]), },
+657 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37102, 37103, ]), },
+658 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37104, 37105, ]), },
+659 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37106, ]), },
+660 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37107, ]), },
+661 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37108, ]), },
+662 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37109, ]), },
+663 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37110, ]), },
+664 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37111, ]), },
+665 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37112, ]), },
+666 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37113, ]), },
+667 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37114, ]), },
+668 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37115, 37116, ]), },
+669 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37117, 37118, ]), },
+670 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37119, 37120, ]), },
+671 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37121, ]), },
+672 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37122, ]), },
+673 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37123, ]), },
+674 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37124, ]), },
+675 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37125, ]), },
+676 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37126, ]), },
+677 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37127, ]), },
+678 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37128, ]), },
+679 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37129, ]), },
+680 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37130, 37131, ]), },
+681 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37132, 37133, ]), },
+682 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37134, 37135, ]), },
+683 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37136, 37137, ]), },
+684 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37138, ]), },
+685 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37139, ]), },
+686 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37140, ]), },
+687 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37141, ]), },
+688 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37142, ]), },
+689 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37143, ]), },
+690 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37144, 37145, ]), },
+691 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37146, 37147, ]), },
+692 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37148, ]), },
+693 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37149, ]), },
+694 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37150, ]), },
+695 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37151, ]), },
+696 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37152, 37153, 37154, ]), },
+697 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37155, ]), },
+698 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37156, 37157, 37158, ]), },
+699 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37159, 37160, ]), },
+700 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37161, 37162, ]), },
+701 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37163, ]), },
+702 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37164, ]), },
+703 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37165, ]), },
+704 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37166, ]), },
+705 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37167, ]), },
+706 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37168, ]), },
+707 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37169, ]), },
+708 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37170, ]), },
+709 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37171, ]), },
+710 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37172, 37173, ]), },
+711 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37174, 37175, ]), },
+712 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37176, 37177, ]), },
+713 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37178, ]), },
+714 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37179, ]), },
+715 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37180, ]), },
+716 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37181, ]), },
+717 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37182, ]), },
+718 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37183, ]), },
+719 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37184, ]), },
+720 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37185, ]), },
+721 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37186, ]), },
+722 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37187, 37188, ]), },
+723 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37189, 37190, ]), },
+724 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37191, 37192, ]), },
+725 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37193, 37194, ]), },
+726 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37195, ]), },
+727 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37196, ]), },
+728 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37197, ]), },
+729 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37198, ]), },
+730 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37199, ]), },
+731 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37200, ]), },
+732 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37201, 37202, ]), },
+733 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37203, 37204, ]), },
+734 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37205, ]), },
+735 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37206, ]), },
+736 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37207, ]), },
+737 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37208, ]), },
+738 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37209, 37210, 37211, ]), },
+739 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37212, ]), },
+740 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37213, 37214, 37215, ]), },
+741 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37216, 37217, ]), },
+742 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37218, 37219, ]), },
+743 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37220, ]), },
+744 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37221, ]), },
+745 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37222, ]), },
+746 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37223, ]), },
+747 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37224, ]), },
+748 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37225, ]), },
+749 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37226, ]), },
+750 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37227, ]), },
+751 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37228, ]), },
+752 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37229, 37230, ]), },
+753 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37231, 37232, ]), },
+754 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37233, 37234, ]), },
+755 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37235, ]), },
+756 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37236, ]), },
+757 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37237, ]), },
+758 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37238, ]), },
+759 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37239, ]), },
+760 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37240, ]), },
+761 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37241, ]), },
+762 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37242, ]), },
+763 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37243, ]), },
+764 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37244, 37245, ]), },
+765 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37246, 37247, ]), },
+766 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37248, 37249, ]), },
+767 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37250, 37251, ]), },
+768 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37252, ]), },
+769 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37253, ]), },
+770 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37254, ]), },
+771 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37255, ]), },
+772 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37256, ]), },
+773 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37257, ]), },
+774 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37258, 37259, ]), },
+775 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37260, 37261, ]), },
+776 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37262, ]), },
+777 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37263, ]), },
+778 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37264, ]), },
+779 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37265, ]), },
+780 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37266, 37267, 37268, ]), },
+781 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37269, ]), },
+782 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37270, 37271, 37272, ]), },
+783 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37273, 37274, ]), },
+784 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37275, 37276, ]), },
+785 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37277, ]), },
+786 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37278, ]), },
+787 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37279, ]), },
+788 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37280, ]), },
+789 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37281, ]), },
+790 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37282, ]), },
+791 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37283, ]), },
+792 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37284, ]), },
+793 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37285, ]), },
+794 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37286, 37287, ]), },
+795 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37288, 37289, ]), },
+796 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37290, 37291, ]), },
+797 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37292, ]), },
+798 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37293, ]), },
+799 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37294, ]), },
+800 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37295, ]), },
+801 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37296, ]), },
+802 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37297, ]), },
+803 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37298, ]), },
+804 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37299, ]), },
+805 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37300, ]), },
+806 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37301, 37302, ]), },
+807 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37303, 37304, ]), },
+808 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37305, 37306, ]), },
+809 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37307, 37308, ]), },
+810 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37309, ]), },
+811 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37310, ]), },
+812 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37311, ]), },
+813 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37312, ]), },
+814 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37313, ]), },
+815 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37314, ]), },
+816 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37315, 37316, ]), },
+817 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37317, 37318, ]), },
+818 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37319, ]), },
+819 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37320, ]), },
+820 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37321, ]), },
+821 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37322, ]), },
+822 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37323, 37324, 37325, ]), },
+823 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37326, ]), },
+824 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37327, 37328, 37329, ]), },
+825 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37330, 37331, ]), },
+826 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37332, 37333, ]), },
+827 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37334, ]), },
+828 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37335, ]), },
+829 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37336, ]), },
+830 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37337, ]), },
+831 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37338, ]), },
+832 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37339, ]), },
+833 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37340, ]), },
+834 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37341, ]), },
+835 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37342, ]), },
+836 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37343, 37344, ]), },
+837 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37345, 37346, ]), },
+838 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37347, 37348, ]), },
+839 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37349, ]), },
+840 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37350, ]), },
+841 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37351, ]), },
+842 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37352, ]), },
+843 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37353, ]), },
+844 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37354, ]), },
+845 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37355, ]), },
+846 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37356, ]), },
+847 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37357, ]), },
+848 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37358, 37359, ]), },
+849 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37360, 37361, ]), },
+850 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37362, 37363, ]), },
+851 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37364, 37365, ]), },
+852 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37366, ]), },
+853 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37367, ]), },
+854 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37368, ]), },
+855 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37369, ]), },
+856 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37370, ]), },
+857 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37371, ]), },
+858 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37372, 37373, ]), },
+859 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37374, 37375, ]), },
+860 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37376, ]), },
+861 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37377, ]), },
+862 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37378, ]), },
+863 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37379, ]), },
+864 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37380, 37381, 37382, ]), },
+865 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37383, ]), },
+866 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37384, 37385, 37386, ]), },
+867 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37387, 37388, ]), },
+868 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37389, 37390, ]), },
+869 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37391, ]), },
+870 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37392, ]), },
+871 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37393, ]), },
+872 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37394, ]), },
+873 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37395, ]), },
+874 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37396, ]), },
+875 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37397, ]), },
+876 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37398, ]), },
+877 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37399, ]), },
+878 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37400, 37401, ]), },
+879 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37402, 37403, ]), },
+880 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37404, 37405, ]), },
+1007 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37406, ]), },
+1008 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37407, ]), },
+1009 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37408, ]), },
+1010 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37409, ]), },
+1011 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37410, ]), },
+1012 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37411, ]), },
+1013 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37412, ]), },
+1014 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37413, ]), },
+1015 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37414, ]), },
+1016 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37415, ]), },
+1017 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37416, ]), },
+1018 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37417, ]), },
+1019 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37418, ]), },
+1020 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37419, ]), },
+1021 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37420, ]), },
+1022 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37421, ]), },
+1023 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37422, ]), },
+1024 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37423, ]), },
+1025 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37424, ]), },
+1026 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37425, ]), },
+1027 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37426, ]), },
+1028 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37427, ]), },
+1029 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37428, ]), },
+1030 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37429, ]), },
+1040 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37496, ]), },
+1041 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37497, ]), },
+1042 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37498, ]), },
+1043 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37499, ]), },
+1044 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37500, ]), },
+1045 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37501, ]), },
+1046 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37502, ]), },
+1047 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37503, 37504, ]), },
+1048 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37505, ]), },
+1049 => StellarMission { job_id: 0, stellar_steady_hand_charges: 3, recipe_ids: Cow::Borrowed(&[37506, ]), },
+1050 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37507, ]), },
+1051 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37508, 37509, ]), },
+1052 => StellarMission { job_id: 0, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37510, 37511, ]), },
+1053 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37512, ]), },
+1054 => StellarMission { job_id: 0, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37513, ]), },
+1055 => StellarMission { job_id: 0, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37514, ]), },
+1056 => StellarMission { job_id: 0, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37515, ]), },
+1057 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37516, ]), },
+1058 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37517, 37518, ]), },
+1059 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37519, ]), },
+1060 => StellarMission { job_id: 0, stellar_steady_hand_charges: 1, recipe_ids: Cow::Borrowed(&[37520, ]), },
+1061 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37521, ]), },
+1062 => StellarMission { job_id: 0, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37522, ]), },
+1063 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37523, 37524, ]), },
+1064 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37525, ]), },
+1065 => StellarMission { job_id: 0, stellar_steady_hand_charges: 1, recipe_ids: Cow::Borrowed(&[37526, 37527, ]), },
+1066 => StellarMission { job_id: 0, stellar_steady_hand_charges: 1, recipe_ids: Cow::Borrowed(&[37528, 37529, ]), },
+1067 => StellarMission { job_id: 0, stellar_steady_hand_charges: 1, recipe_ids: Cow::Borrowed(&[37530, 37531, ]), },
+1068 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37532, ]), },
+1069 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37533, ]), },
+1070 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37534, ]), },
+1071 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37535, ]), },
+1072 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37536, ]), },
+1073 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37537, ]), },
+1074 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37538, ]), },
+1075 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37539, 37540, ]), },
+1076 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37541, ]), },
+1077 => StellarMission { job_id: 1, stellar_steady_hand_charges: 3, recipe_ids: Cow::Borrowed(&[37542, ]), },
+1078 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37543, ]), },
+1079 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37544, 37545, ]), },
+1080 => StellarMission { job_id: 1, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37546, 37547, ]), },
+1081 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37548, ]), },
+1082 => StellarMission { job_id: 1, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37549, ]), },
+1083 => StellarMission { job_id: 1, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37550, ]), },
+1084 => StellarMission { job_id: 1, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37551, ]), },
+1085 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37552, ]), },
+1086 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37553, 37554, ]), },
+1087 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37555, ]), },
+1088 => StellarMission { job_id: 1, stellar_steady_hand_charges: 1, recipe_ids: Cow::Borrowed(&[37556, ]), },
+1089 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37557, ]), },
+1090 => StellarMission { job_id: 1, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37558, ]), },
+1091 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37559, 37560, ]), },
+1092 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37561, ]), },
+1093 => StellarMission { job_id: 1, stellar_steady_hand_charges: 1, recipe_ids: Cow::Borrowed(&[37562, 37563, ]), },
+1094 => StellarMission { job_id: 1, stellar_steady_hand_charges: 1, recipe_ids: Cow::Borrowed(&[37564, 37565, ]), },
+1095 => StellarMission { job_id: 1, stellar_steady_hand_charges: 1, recipe_ids: Cow::Borrowed(&[37566, 37567, ]), },
+1096 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37568, ]), },
+1097 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37569, ]), },
+1098 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37570, ]), },
+1099 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37571, ]), },
+1100 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37572, ]), },
+1101 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37573, ]), },
+1102 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37574, ]), },
+1103 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37575, 37576, ]), },
+1104 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37577, ]), },
+1105 => StellarMission { job_id: 2, stellar_steady_hand_charges: 3, recipe_ids: Cow::Borrowed(&[37578, ]), },
+1106 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37579, ]), },
+1107 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37580, 37581, ]), },
+1108 => StellarMission { job_id: 2, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37582, 37583, ]), },
+1109 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37584, ]), },
+1110 => StellarMission { job_id: 2, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37585, ]), },
+1111 => StellarMission { job_id: 2, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37586, ]), },
+1112 => StellarMission { job_id: 2, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37587, ]), },
+1113 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37588, ]), },
+1114 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37589, 37590, ]), },
+1115 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37591, ]), },
+1116 => StellarMission { job_id: 2, stellar_steady_hand_charges: 1, recipe_ids: Cow::Borrowed(&[37592, ]), },
+1117 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37593, ]), },
+1118 => StellarMission { job_id: 2, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37594, ]), },
+1119 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37595, 37596, ]), },
+1120 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37597, ]), },
+1121 => StellarMission { job_id: 2, stellar_steady_hand_charges: 1, recipe_ids: Cow::Borrowed(&[37598, 37599, ]), },
+1122 => StellarMission { job_id: 2, stellar_steady_hand_charges: 1, recipe_ids: Cow::Borrowed(&[37600, 37601, ]), },
+1123 => StellarMission { job_id: 2, stellar_steady_hand_charges: 1, recipe_ids: Cow::Borrowed(&[37602, 37603, ]), },
+1124 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37604, ]), },
+1125 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37605, ]), },
+1126 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37606, ]), },
+1127 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37607, ]), },
+1128 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37608, ]), },
+1129 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37609, ]), },
+1130 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37610, ]), },
+1131 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37611, 37612, ]), },
+1132 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37613, ]), },
+1133 => StellarMission { job_id: 3, stellar_steady_hand_charges: 3, recipe_ids: Cow::Borrowed(&[37614, ]), },
+1134 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37615, ]), },
+1135 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37616, 37617, ]), },
+1136 => StellarMission { job_id: 3, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37618, 37619, ]), },
+1137 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37620, ]), },
+1138 => StellarMission { job_id: 3, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37621, ]), },
+1139 => StellarMission { job_id: 3, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37622, ]), },
+1140 => StellarMission { job_id: 3, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37623, ]), },
+1141 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37624, ]), },
+1142 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37625, 37626, ]), },
+1143 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37627, ]), },
+1144 => StellarMission { job_id: 3, stellar_steady_hand_charges: 1, recipe_ids: Cow::Borrowed(&[37628, ]), },
+1145 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37629, ]), },
+1146 => StellarMission { job_id: 3, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37630, ]), },
+1147 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37631, 37632, ]), },
+1148 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37633, ]), },
+1149 => StellarMission { job_id: 3, stellar_steady_hand_charges: 1, recipe_ids: Cow::Borrowed(&[37634, 37635, ]), },
+1150 => StellarMission { job_id: 3, stellar_steady_hand_charges: 1, recipe_ids: Cow::Borrowed(&[37636, 37637, ]), },
+1151 => StellarMission { job_id: 3, stellar_steady_hand_charges: 1, recipe_ids: Cow::Borrowed(&[37638, 37639, ]), },
+1152 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37640, ]), },
+1153 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37641, ]), },
+1154 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37642, ]), },
+1155 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37643, ]), },
+1156 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37644, ]), },
+1157 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37645, ]), },
+1158 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37646, ]), },
+1159 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37647, 37648, ]), },
+1160 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37649, ]), },
+1161 => StellarMission { job_id: 4, stellar_steady_hand_charges: 3, recipe_ids: Cow::Borrowed(&[37650, ]), },
+1162 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37651, ]), },
+1163 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37652, 37653, ]), },
+1164 => StellarMission { job_id: 4, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37654, 37655, ]), },
+1165 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37656, ]), },
+1166 => StellarMission { job_id: 4, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37657, ]), },
+1167 => StellarMission { job_id: 4, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37658, ]), },
+1168 => StellarMission { job_id: 4, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37659, ]), },
+1169 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37660, ]), },
+1170 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37661, 37662, ]), },
+1171 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37663, ]), },
+1172 => StellarMission { job_id: 4, stellar_steady_hand_charges: 1, recipe_ids: Cow::Borrowed(&[37664, ]), },
+1173 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37665, ]), },
+1174 => StellarMission { job_id: 4, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37666, ]), },
+1175 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37667, 37668, ]), },
+1176 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37669, ]), },
+1177 => StellarMission { job_id: 4, stellar_steady_hand_charges: 1, recipe_ids: Cow::Borrowed(&[37670, 37671, ]), },
+1178 => StellarMission { job_id: 4, stellar_steady_hand_charges: 1, recipe_ids: Cow::Borrowed(&[37672, 37673, ]), },
+1179 => StellarMission { job_id: 4, stellar_steady_hand_charges: 1, recipe_ids: Cow::Borrowed(&[37674, 37675, ]), },
+1180 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37676, ]), },
+1181 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37677, ]), },
+1182 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37678, ]), },
+1183 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37679, ]), },
+1184 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37680, ]), },
+1185 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37681, ]), },
+1186 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37682, ]), },
+1187 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37683, 37684, ]), },
+1188 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37685, ]), },
+1189 => StellarMission { job_id: 5, stellar_steady_hand_charges: 3, recipe_ids: Cow::Borrowed(&[37686, ]), },
+1190 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37687, ]), },
+1191 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37688, 37689, ]), },
+1192 => StellarMission { job_id: 5, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37690, 37691, ]), },
+1193 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37692, ]), },
+1194 => StellarMission { job_id: 5, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37693, ]), },
+1195 => StellarMission { job_id: 5, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37694, ]), },
+1196 => StellarMission { job_id: 5, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37695, ]), },
+1197 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37696, ]), },
+1198 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37697, 37698, ]), },
+1199 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37699, ]), },
+1200 => StellarMission { job_id: 5, stellar_steady_hand_charges: 1, recipe_ids: Cow::Borrowed(&[37700, ]), },
+1201 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37701, ]), },
+1202 => StellarMission { job_id: 5, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37702, ]), },
+1203 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37703, 37704, ]), },
+1204 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37705, ]), },
+1205 => StellarMission { job_id: 5, stellar_steady_hand_charges: 1, recipe_ids: Cow::Borrowed(&[37706, 37707, ]), },
+1206 => StellarMission { job_id: 5, stellar_steady_hand_charges: 1, recipe_ids: Cow::Borrowed(&[37708, 37709, ]), },
+1207 => StellarMission { job_id: 5, stellar_steady_hand_charges: 1, recipe_ids: Cow::Borrowed(&[37710, 37711, ]), },
+1208 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37712, ]), },
+1209 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37713, ]), },
+1210 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37714, ]), },
+1211 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37715, ]), },
+1212 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37716, ]), },
+1213 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37717, ]), },
+1214 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37718, ]), },
+1215 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37719, 37720, ]), },
+1216 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37721, ]), },
+1217 => StellarMission { job_id: 6, stellar_steady_hand_charges: 3, recipe_ids: Cow::Borrowed(&[37722, ]), },
+1218 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37723, ]), },
+1219 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37724, 37725, ]), },
+1220 => StellarMission { job_id: 6, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37726, 37727, ]), },
+1221 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37728, ]), },
+1222 => StellarMission { job_id: 6, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37729, ]), },
+1223 => StellarMission { job_id: 6, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37730, ]), },
+1224 => StellarMission { job_id: 6, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37731, ]), },
+1225 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37732, ]), },
+1226 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37733, 37734, ]), },
+1227 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37735, ]), },
+1228 => StellarMission { job_id: 6, stellar_steady_hand_charges: 1, recipe_ids: Cow::Borrowed(&[37736, ]), },
+1229 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37737, ]), },
+1230 => StellarMission { job_id: 6, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37738, ]), },
+1231 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37739, 37740, ]), },
+1232 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37741, ]), },
+1233 => StellarMission { job_id: 6, stellar_steady_hand_charges: 1, recipe_ids: Cow::Borrowed(&[37742, 37743, ]), },
+1234 => StellarMission { job_id: 6, stellar_steady_hand_charges: 1, recipe_ids: Cow::Borrowed(&[37744, 37745, ]), },
+1235 => StellarMission { job_id: 6, stellar_steady_hand_charges: 1, recipe_ids: Cow::Borrowed(&[37746, 37747, ]), },
+1236 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37748, ]), },
+1237 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37749, ]), },
+1238 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37750, ]), },
+1239 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37751, ]), },
+1240 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37752, ]), },
+1241 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37753, ]), },
+1242 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37754, ]), },
+1243 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37755, 37756, ]), },
+1244 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37757, ]), },
+1245 => StellarMission { job_id: 7, stellar_steady_hand_charges: 3, recipe_ids: Cow::Borrowed(&[37758, ]), },
+1246 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37759, ]), },
+1247 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37760, 37761, ]), },
+1248 => StellarMission { job_id: 7, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37762, 37763, ]), },
+1249 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37764, ]), },
+1250 => StellarMission { job_id: 7, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37765, ]), },
+1251 => StellarMission { job_id: 7, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37766, ]), },
+1252 => StellarMission { job_id: 7, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37767, ]), },
+1253 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37768, ]), },
+1254 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37769, 37770, ]), },
+1255 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37771, ]), },
+1256 => StellarMission { job_id: 7, stellar_steady_hand_charges: 1, recipe_ids: Cow::Borrowed(&[37772, ]), },
+1257 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37773, ]), },
+1258 => StellarMission { job_id: 7, stellar_steady_hand_charges: 2, recipe_ids: Cow::Borrowed(&[37774, ]), },
+1259 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37775, 37776, ]), },
+1260 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37777, ]), },
+1261 => StellarMission { job_id: 7, stellar_steady_hand_charges: 1, recipe_ids: Cow::Borrowed(&[37778, 37779, ]), },
+1262 => StellarMission { job_id: 7, stellar_steady_hand_charges: 1, recipe_ids: Cow::Borrowed(&[37780, 37781, ]), },
+1263 => StellarMission { job_id: 7, stellar_steady_hand_charges: 1, recipe_ids: Cow::Borrowed(&[37782, 37783, ]), },
+1348 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37784, ]), },
+1349 => StellarMission { job_id: 0, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37785, ]), },
+1350 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37786, ]), },
+1351 => StellarMission { job_id: 1, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37787, ]), },
+1352 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37788, ]), },
+1353 => StellarMission { job_id: 2, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37789, ]), },
+1354 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37790, ]), },
+1355 => StellarMission { job_id: 3, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37791, ]), },
+1356 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37792, ]), },
+1357 => StellarMission { job_id: 4, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37793, ]), },
+1358 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37794, ]), },
+1359 => StellarMission { job_id: 5, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37795, ]), },
+1360 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37796, ]), },
+1361 => StellarMission { job_id: 6, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37797, ]), },
+1362 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37798, ]), },
+1363 => StellarMission { job_id: 7, stellar_steady_hand_charges: 0, recipe_ids: Cow::Borrowed(&[37799, ]), },
 }
@@ -0,0 +1,113 @@
+use crate::{ItemRecipe, game_data, get_recipe};
+
+/// Returns all recipes that produce the given item, sorted by recipe ID.
+pub fn get_item_recipes(item_id: u32) -> &'static [ItemRecipe] {
+    game_data().item_recipes(item_id)
+}
+
+/// Returns the number of items produced by a single craft of the given recipe of the item.
+pub fn get_result_amount(item_id: u32, recipe_id: u32) -> u32 {
+    get_item_recipes(item_id)
+        .iter()
+        .find(|item_recipe| item_recipe.recipe_id == recipe_id)
+        .map_or(1, |item_recipe| item_recipe.result_amount.max(1))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CraftingTreeNode {
+    pub item_id: u32,
+    /// Total amount of the item that is needed.
+    pub amount: u32,
+    /// Recipe used to craft the item. `None` if the item cannot be crafted.
+    pub recipe_id: Option<u32>,
+    pub job_id: Option<u8>,
+    /// Number of times the recipe needs to be crafted to produce `amount` items.
+    pub crafts: u32,
+    pub ingredients: Vec<Self>,
+}
+
+impl CraftingTreeNode {
+    /// Returns all nodes with a recipe, ordered such that every node comes after all of its ingredients.
+    /// Crafting the nodes in this order ensures that all intermediate items are available when they are needed.
+    pub fn crafted_nodes(&self) -> Vec<&Self> {
+        let mut nodes = Vec::new();
+        self.collect_crafted_nodes(&mut nodes);
+        nodes
+    }
+
+    fn collect_crafted_nodes<'a>(&'a self, nodes: &mut Vec<&'a Self>) {
+        for ingredient in &self.ingredients {
+            ingredient.collect_crafted_nodes(nodes);
+        }
+        if self.recipe_id.is_some() {
+            nodes.push(self);
+        }
+    }
+}
+
+/// Expands a recipe into its full crafting tree.
+///
+/// Intermediate items are crafted using the recipe with the lowest ID that produces them.
+/// Only ingredients that are part of the recipe data are included, i.e. ingredients that cannot be HQ are missing.
+pub fn get_crafting_tree(recipe_id: u32, amount: u32) -> Option<CraftingTreeNode> {
+    let recipe = get_recipe(recipe_id)?;
+    let mut ancestors = Vec::new();
+    Some(build_node(
+        recipe.item_id,
+        amount,
+        Some(recipe_id),
+        &mut ancestors,
+    ))
+}
+
+fn build_node(
+    item_id: u32,
+    amount: u32,
+    recipe_id: Option<u32>,
+    ancestors: &mut Vec<u32>,
+) -> CraftingTreeNode {
+    // Guard against recipes that (indirectly) use their own result as an ingredient.
+    let recipe_id = recipe_id.filter(|_| !ancestors.contains(&item_id));
+    let Some((recipe_id, recipe)) =
+        recipe_id.and_then(|recipe_id| Some((recipe_id, get_recipe(recipe_id)?)))
+    else {
+        return CraftingTreeNode {
+            item_id,
+            amount,
+            recipe_id: None,
+            job_id: None,
+            crafts: 0,
+            ingredients: Vec::new(),
+        };
+    };
+
+    let crafts = amount.div_ceil(get_result_amount(item_id, recipe_id));
+
+    ancestors.push(item_id);
+    let ingredients = recipe
+        .ingredients
+        .iter()
+        .filter(|ingredient| ingredient.item_id != 0)
+        .map(|ingredient| {
+            let ingredient_recipe_id = get_item_recipes(ingredient.item_id)
+                .first()
+                .map(|item_recipe| item_recipe.recipe_id);
+            build_node(
+                ingredient.item_id,
+                ingredient.amount * crafts,
+                ingredient_recipe_id,
+                ancestors,
+            )
+        })
+        .collect();
+    ancestors.pop();
+
+    CraftingTreeNode {
+        item_id,
+        amount,
+        recipe_id: Some(recipe_id),
+        job_id: Some(recipe.job_id),
+        crafts,
+        ingredients,
+    }
+}
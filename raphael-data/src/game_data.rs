@@ -0,0 +1,385 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::OnceLock;
+
+use crate::{
+    Consumable, GEAR, Gear, ITEM_NAMES_CN, ITEM_NAMES_DE, ITEM_NAMES_EN, ITEM_NAMES_FR,
+    ITEM_NAMES_JP, ITEM_NAMES_KR, ITEM_NAMES_TW, ITEM_RECIPES, ITEMS, Item, ItemRecipe,
+    LEVEL_ADJUST_TABLE, Locale, MATERIA, MEALS, Materia, NciArray, POTIONS,
+    RECIPE_TO_STELLAR_MISSION_LINKS, RECIPES, RLVLS, Recipe, RecipeLevel, STELLAR_MISSION_NAMES_CN,
+    STELLAR_MISSION_NAMES_DE, STELLAR_MISSION_NAMES_EN, STELLAR_MISSION_NAMES_FR,
+    STELLAR_MISSION_NAMES_JP, STELLAR_MISSION_NAMES_KR, STELLAR_MISSION_NAMES_TW, STELLAR_MISSIONS,
+    StellarMission,
+};
+
+/// Version of the data pack format. Packs with a different format version are rejected when loading.
+pub const DATA_PACK_FORMAT_VERSION: u32 = 1;
+
+/// Read access to the game data tables.
+///
+/// The tables are either compiled into the binary ([`EmbeddedGameData`]) or loaded at runtime ([`DataPack`]).
+/// Use [`game_data`] to access the tables that are currently active.
+pub trait GameData: Send + Sync {
+    fn recipe(&self, recipe_id: u32) -> Option<&Recipe>;
+    /// All recipes, sorted by recipe ID.
+    fn recipes(&self) -> Box<dyn Iterator<Item = (u32, &Recipe)> + '_>;
+    fn item(&self, item_id: u32) -> Option<&Item>;
+    fn item_name(&self, item_id: u32, locale: Locale) -> Option<&str>;
+    /// All recipes that produce the given item, sorted by recipe ID.
+    fn item_recipes(&self, item_id: u32) -> &[ItemRecipe];
+    fn rlvls(&self) -> &[RecipeLevel];
+    fn level_adjust_table(&self) -> &[u16];
+    fn meals(&self) -> &[Consumable];
+    fn potions(&self) -> &[Consumable];
+    /// All items that have crafting stats.
+    fn gear(&self) -> &[Gear];
+    /// All materia that give crafting stats.
+    fn materia(&self) -> &[Materia];
+    fn stellar_mission(&self, mission_id: u32) -> Option<&StellarMission>;
+    /// All stellar missions, sorted by mission ID.
+    fn stellar_missions(&self) -> Box<dyn Iterator<Item = (u32, &StellarMission)> + '_>;
+    fn stellar_mission_name(&self, mission_id: u32, locale: Locale) -> Option<&str>;
+    /// ID of the stellar mission the recipe is part of.
+    fn recipe_stellar_mission(&self, recipe_id: u32) -> Option<u32>;
+}
+
+fn embedded_item_names(locale: Locale) -> &'static NciArray<u32, &'static str> {
+    match locale {
+        Locale::EN => &ITEM_NAMES_EN,
+        Locale::DE => &ITEM_NAMES_DE,
+        Locale::FR => &ITEM_NAMES_FR,
+        Locale::JP => &ITEM_NAMES_JP,
+        Locale::CN => &ITEM_NAMES_CN,
+        Locale::KR => &ITEM_NAMES_KR,
+        Locale::TW => &ITEM_NAMES_TW,
+    }
+}
+
+fn embedded_stellar_mission_names(locale: Locale) -> &'static NciArray<u32, &'static str> {
+    match locale {
+        Locale::EN => &STELLAR_MISSION_NAMES_EN,
+        Locale::DE => &STELLAR_MISSION_NAMES_DE,
+        Locale::FR => &STELLAR_MISSION_NAMES_FR,
+        Locale::JP => &STELLAR_MISSION_NAMES_JP,
+        Locale::CN => &STELLAR_MISSION_NAMES_CN,
+        Locale::KR => &STELLAR_MISSION_NAMES_KR,
+        Locale::TW => &STELLAR_MISSION_NAMES_TW,
+    }
+}
+
+/// Game data tables that are compiled into the binary.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EmbeddedGameData;
+
+impl GameData for EmbeddedGameData {
+    fn recipe(&self, recipe_id: u32) -> Option<&Recipe> {
+        RECIPES.get(recipe_id)
+    }
+
+    fn recipes(&self) -> Box<dyn Iterator<Item = (u32, &Recipe)> + '_> {
+        Box::new(RECIPES.entries())
+    }
+
+    fn item(&self, item_id: u32) -> Option<&Item> {
+        ITEMS.get(item_id)
+    }
+
+    fn item_name(&self, item_id: u32, locale: Locale) -> Option<&str> {
+        embedded_item_names(locale).get(item_id).copied()
+    }
+
+    fn item_recipes(&self, item_id: u32) -> &[ItemRecipe] {
+        ITEM_RECIPES.get(item_id).copied().unwrap_or_default()
+    }
+
+    fn rlvls(&self) -> &[RecipeLevel] {
+        RLVLS
+    }
+
+    fn level_adjust_table(&self) -> &[u16] {
+        LEVEL_ADJUST_TABLE
+    }
+
+    fn meals(&self) -> &[Consumable] {
+        MEALS
+    }
+
+    fn potions(&self) -> &[Consumable] {
+        POTIONS
+    }
+
+    fn gear(&self) -> &[Gear] {
+        GEAR
+    }
+
+    fn materia(&self) -> &[Materia] {
+        MATERIA
+    }
+
+    fn stellar_mission(&self, mission_id: u32) -> Option<&StellarMission> {
+        STELLAR_MISSIONS.get(mission_id)
+    }
+
+    fn stellar_missions(&self) -> Box<dyn Iterator<Item = (u32, &StellarMission)> + '_> {
+        Box::new(STELLAR_MISSIONS.entries())
+    }
+
+    fn stellar_mission_name(&self, mission_id: u32, locale: Locale) -> Option<&str> {
+        embedded_stellar_mission_names(locale)
+            .get(mission_id)
+            .copied()
+    }
+
+    fn recipe_stellar_mission(&self, recipe_id: u32) -> Option<u32> {
+        RECIPE_TO_STELLAR_MISSION_LINKS.get(recipe_id).copied()
+    }
+}
+
+/// Game data tables that are loaded at runtime, e.g. to support a new game patch without rebuilding.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DataPack {
+    /// See [`DATA_PACK_FORMAT_VERSION`].
+    pub format_version: u32,
+    /// Free-form description of the game version the data was extracted from, e.g. "7.3".
+    pub data_version: String,
+    pub recipes: BTreeMap<u32, Recipe>,
+    pub items: HashMap<u32, Item>,
+    pub item_names: HashMap<Locale, HashMap<u32, String>>,
+    pub item_recipes: HashMap<u32, Vec<ItemRecipe>>,
+    pub rlvls: Vec<RecipeLevel>,
+    pub level_adjust_table: Vec<u16>,
+    pub meals: Vec<Consumable>,
+    pub potions: Vec<Consumable>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub gear: Vec<Gear>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub materia: Vec<Materia>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub stellar_missions: BTreeMap<u32, StellarMission>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub stellar_mission_names: HashMap<Locale, HashMap<u32, String>>,
+    /// Maps the ID of each stellar mission recipe to the ID of its stellar mission.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub recipe_to_stellar_mission_links: HashMap<u32, u32>,
+}
+
+#[derive(Debug)]
+pub enum DataPackError {
+    #[cfg(feature = "data-pack")]
+    Parse(serde_json::Error),
+    UnsupportedFormatVersion(u32),
+    AlreadyLoaded,
+}
+
+impl std::fmt::Display for DataPackError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            #[cfg(feature = "data-pack")]
+            Self::Parse(error) => write!(f, "Failed to parse data pack: {error}"),
+            Self::UnsupportedFormatVersion(format_version) => write!(
+                f,
+                "Unsupported data pack format version {format_version} (expected {DATA_PACK_FORMAT_VERSION})"
+            ),
+            Self::AlreadyLoaded => write!(f, "A data pack has already been loaded"),
+        }
+    }
+}
+
+impl std::error::Error for DataPackError {}
+
+impl DataPack {
+    /// Copies the embedded game data tables into a data pack.
+    pub fn from_embedded(data_version: impl Into<String>) -> Self {
+        let data = EmbeddedGameData;
+        Self {
+            format_version: DATA_PACK_FORMAT_VERSION,
+            data_version: data_version.into(),
+            recipes: data
+                .recipes()
+                .map(|(recipe_id, recipe)| (recipe_id, *recipe))
+                .collect(),
+            items: ITEMS
+                .entries()
+                .map(|(item_id, item)| (item_id, *item))
+                .collect(),
+            item_names: Locale::ALL
+                .into_iter()
+                .map(|locale| {
+                    let names = embedded_item_names(locale)
+                        .entries()
+                        .map(|(item_id, name)| (item_id, (*name).to_owned()))
+                        .collect();
+                    (locale, names)
+                })
+                .collect(),
+            item_recipes: ITEM_RECIPES
+                .entries()
+                .map(|(item_id, item_recipes)| (item_id, item_recipes.to_vec()))
+                .collect(),
+            rlvls: RLVLS.to_vec(),
+            level_adjust_table: LEVEL_ADJUST_TABLE.to_vec(),
+            meals: MEALS.to_vec(),
+            potions: POTIONS.to_vec(),
+            gear: GEAR.to_vec(),
+            materia: MATERIA.to_vec(),
+            stellar_missions: data
+                .stellar_missions()
+                .map(|(mission_id, mission)| (mission_id, mission.clone()))
+                .collect(),
+            stellar_mission_names: Locale::ALL
+                .into_iter()
+                .map(|locale| {
+                    let names = embedded_stellar_mission_names(locale)
+                        .entries()
+                        .map(|(mission_id, name)| (mission_id, (*name).to_owned()))
+                        .collect();
+                    (locale, names)
+                })
+                .collect(),
+            recipe_to_stellar_mission_links: RECIPE_TO_STELLAR_MISSION_LINKS
+                .entries()
+                .map(|(recipe_id, mission_id)| (recipe_id, *mission_id))
+                .collect(),
+        }
+    }
+
+    fn check_format_version(self) -> Result<Self, DataPackError> {
+        match self.format_version {
+            DATA_PACK_FORMAT_VERSION => Ok(self),
+            format_version => Err(DataPackError::UnsupportedFormatVersion(format_version)),
+        }
+    }
+
+    #[cfg(feature = "data-pack")]
+    pub fn from_json(json: &str) -> Result<Self, DataPackError> {
+        serde_json::from_str::<Self>(json)
+            .map_err(DataPackError::Parse)?
+            .check_format_version()
+    }
+
+    #[cfg(feature = "data-pack")]
+    pub fn from_json_reader(reader: impl std::io::Read) -> Result<Self, DataPackError> {
+        serde_json::from_reader::<_, Self>(reader)
+            .map_err(DataPackError::Parse)?
+            .check_format_version()
+    }
+
+    #[cfg(feature = "data-pack")]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+impl GameData for DataPack {
+    fn recipe(&self, recipe_id: u32) -> Option<&Recipe> {
+        self.recipes.get(&recipe_id)
+    }
+
+    fn recipes(&self) -> Box<dyn Iterator<Item = (u32, &Recipe)> + '_> {
+        Box::new(
+            self.recipes
+                .iter()
+                .map(|(recipe_id, recipe)| (*recipe_id, recipe)),
+        )
+    }
+
+    fn item(&self, item_id: u32) -> Option<&Item> {
+        self.items.get(&item_id)
+    }
+
+    fn item_name(&self, item_id: u32, locale: Locale) -> Option<&str> {
+        self.item_names
+            .get(&locale)?
+            .get(&item_id)
+            .map(String::as_str)
+    }
+
+    fn item_recipes(&self, item_id: u32) -> &[ItemRecipe] {
+        self.item_recipes.get(&item_id).map_or(&[], Vec::as_slice)
+    }
+
+    fn rlvls(&self) -> &[RecipeLevel] {
+        &self.rlvls
+    }
+
+    fn level_adjust_table(&self) -> &[u16] {
+        &self.level_adjust_table
+    }
+
+    fn meals(&self) -> &[Consumable] {
+        &self.meals
+    }
+
+    fn potions(&self) -> &[Consumable] {
+        &self.potions
+    }
+
+    fn gear(&self) -> &[Gear] {
+        &self.gear
+    }
+
+    fn materia(&self) -> &[Materia] {
+        &self.materia
+    }
+
+    fn stellar_mission(&self, mission_id: u32) -> Option<&StellarMission> {
+        self.stellar_missions.get(&mission_id)
+    }
+
+    fn stellar_missions(&self) -> Box<dyn Iterator<Item = (u32, &StellarMission)> + '_> {
+        Box::new(
+            self.stellar_missions
+                .iter()
+                .map(|(mission_id, mission)| (*mission_id, mission)),
+        )
+    }
+
+    fn stellar_mission_name(&self, mission_id: u32, locale: Locale) -> Option<&str> {
+        self.stellar_mission_names
+            .get(&locale)?
+            .get(&mission_id)
+            .map(String::as_str)
+    }
+
+    fn recipe_stellar_mission(&self, recipe_id: u32) -> Option<u32> {
+        self.recipe_to_stellar_mission_links
+            .get(&recipe_id)
+            .copied()
+    }
+}
+
+static DATA_PACK: OnceLock<DataPack> = OnceLock::new();
+
+/// Replaces the embedded game data with the given data pack for the rest of the program.
+/// This should be done at startup, before any game data is accessed.
+/// Only a single data pack can be loaded.
+pub fn load_data_pack(data_pack: DataPack) -> Result<(), DataPackError> {
+    let data_pack = data_pack.check_format_version()?;
+    DATA_PACK
+        .set(data_pack)
+        .map_err(|_| DataPackError::AlreadyLoaded)
+}
+
+/// Returns the loaded data pack, or the embedded game data if no data pack was loaded.
+///
+/// The lookup functions of this crate (e.g. [`crate::get_game_settings`], [`crate::find_recipes`] and [`crate::get_raw_item_name`])
+/// use the game data returned by this function. Accessing the constants (e.g. [`crate::RECIPES`]) directly always uses the embedded data.
+pub fn game_data() -> &'static dyn GameData {
+    match DATA_PACK.get() {
+        Some(data_pack) => data_pack,
+        None => &EmbeddedGameData,
+    }
+}
+
+/// Returns whether the embedded game data has been replaced by a data pack.
+pub fn is_data_pack_loaded() -> bool {
+    DATA_PACK.get().is_some()
+}
+
+pub fn get_recipe(recipe_id: u32) -> Option<&'static Recipe> {
+    game_data().recipe(recipe_id)
+}
+
+pub fn get_stellar_mission(mission_id: u32) -> Option<&'static StellarMission> {
+    game_data().stellar_mission(mission_id)
+}
@@ -0,0 +1,314 @@
+use crate::{CrafterStats, game_data};
+
+pub const GEAR: &[Gear] = include!("../data/gear.rs");
+pub const MATERIA: &[Materia] = include!("../data/materia.rs");
+
+/// Maximum number of materia that can be melded into an item that allows advanced melding.
+pub const MAX_MATERIA_SLOTS: u8 = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EquipSlot {
+    MainHand,
+    OffHand,
+    Head,
+    Body,
+    Hands,
+    Legs,
+    Feet,
+    Ears,
+    Neck,
+    Wrists,
+    Ring,
+}
+
+impl EquipSlot {
+    pub const ALL: [Self; 11] = [
+        Self::MainHand,
+        Self::OffHand,
+        Self::Head,
+        Self::Body,
+        Self::Hands,
+        Self::Legs,
+        Self::Feet,
+        Self::Ears,
+        Self::Neck,
+        Self::Wrists,
+        Self::Ring,
+    ];
+
+    /// Number of items of this slot that can be equipped at the same time.
+    pub const fn capacity(self) -> usize {
+        match self {
+            Self::Ring => 2,
+            _ => 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CraftingStat {
+    Craftsmanship,
+    Control,
+    Cp,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GearStats {
+    pub craftsmanship: u16,
+    pub control: u16,
+    pub cp: u16,
+}
+
+impl GearStats {
+    pub fn get(self, stat: CraftingStat) -> u16 {
+        match stat {
+            CraftingStat::Craftsmanship => self.craftsmanship,
+            CraftingStat::Control => self.control,
+            CraftingStat::Cp => self.cp,
+        }
+    }
+
+    fn get_mut(&mut self, stat: CraftingStat) -> &mut u16 {
+        match stat {
+            CraftingStat::Craftsmanship => &mut self.craftsmanship,
+            CraftingStat::Control => &mut self.control,
+            CraftingStat::Cp => &mut self.cp,
+        }
+    }
+
+    fn saturating_add(self, other: Self) -> Self {
+        Self {
+            craftsmanship: self.craftsmanship.saturating_add(other.craftsmanship),
+            control: self.control.saturating_add(other.control),
+            cp: self.cp.saturating_add(other.cp),
+        }
+    }
+}
+
+/// An item that has crafting stats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Gear {
+    pub item_id: u32,
+    pub equip_slot: EquipSlot,
+    pub equip_level: u8,
+    pub item_level: u16,
+    pub materia_slots: u8,
+    pub advanced_melding: bool,
+    pub stats: GearStats,
+    /// Stats of the HQ version of the item. Same as `stats` if the item cannot be HQ.
+    pub hq_stats: GearStats,
+    /// Maximum value of each stat (base stats plus materia) that the item can have.
+    pub meld_cap: GearStats,
+}
+
+impl Gear {
+    /// Maximum number of materia that can be melded into the item, including overmelds.
+    pub fn max_materia(&self) -> u8 {
+        if self.advanced_melding {
+            MAX_MATERIA_SLOTS
+        } else {
+            self.materia_slots
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Materia {
+    pub item_id: u32,
+    pub stat: CraftingStat,
+    pub grade: u8,
+    pub value: u16,
+}
+
+pub fn find_gear(item_id: u32) -> Option<&'static Gear> {
+    game_data()
+        .gear()
+        .iter()
+        .find(|gear| gear.item_id == item_id)
+}
+
+pub fn find_materia(item_id: u32) -> Option<&'static Materia> {
+    game_data()
+        .materia()
+        .iter()
+        .find(|materia| materia.item_id == item_id)
+}
+
+/// Whether the loaded game data contains gear and materia. Gear sets cannot be evaluated without them.
+pub fn has_gear_data() -> bool {
+    !game_data().gear().is_empty() && !game_data().materia().is_empty()
+}
+
+fn lookup_gear(item_id: u32) -> Result<&'static Gear, GearsetError> {
+    if !has_gear_data() {
+        return Err(GearsetError::NoGearData);
+    }
+    find_gear(item_id).ok_or(GearsetError::UnknownGear(item_id))
+}
+
+fn lookup_materia(item_id: u32) -> Result<&'static Materia, GearsetError> {
+    if !has_gear_data() {
+        return Err(GearsetError::NoGearData);
+    }
+    find_materia(item_id).ok_or(GearsetError::UnknownMateria(item_id))
+}
+
+/// An equipped item and the materia melded into it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GearPiece {
+    pub item_id: u32,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub hq: bool,
+    /// Item IDs of the melded materia.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub materia: Vec<u32>,
+}
+
+impl GearPiece {
+    /// Stats of the item including materia. Each stat is capped at the meld cap of the item.
+    pub fn stats(&self) -> Result<GearStats, GearsetError> {
+        let gear = lookup_gear(self.item_id)?;
+        if self.materia.len() > gear.max_materia() as usize {
+            return Err(GearsetError::TooManyMateria {
+                item_id: self.item_id,
+                materia_count: self.materia.len(),
+                max_materia: gear.max_materia(),
+            });
+        }
+        let base_stats = if self.hq { gear.hq_stats } else { gear.stats };
+        let mut stats = base_stats;
+        for materia_id in &self.materia {
+            let materia = lookup_materia(*materia_id)?;
+            let stat = stats.get_mut(materia.stat);
+            *stat = stat.saturating_add(materia.value);
+        }
+        for stat in [
+            CraftingStat::Craftsmanship,
+            CraftingStat::Control,
+            CraftingStat::Cp,
+        ] {
+            // Materia cannot exceed the meld cap, but the base stats of an item are never reduced.
+            let cap = std::cmp::max(gear.meld_cap.get(stat), base_stats.get(stat));
+            let value = stats.get_mut(stat);
+            *value = std::cmp::min(*value, cap);
+        }
+        Ok(stats)
+    }
+}
+
+/// Equipped items of a single job.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Gearset {
+    /// Stats of the character without any gear equipped.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub base_stats: GearStats,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub pieces: Vec<GearPiece>,
+}
+
+impl Gearset {
+    /// Total stats of the base stats and all equipped items.
+    pub fn stats(&self) -> Result<GearStats, GearsetError> {
+        let mut equipped_slots = Vec::new();
+        let mut stats = self.base_stats;
+        for piece in &self.pieces {
+            let gear = lookup_gear(piece.item_id)?;
+            equipped_slots.push(gear.equip_slot);
+            let slot_count = equipped_slots
+                .iter()
+                .filter(|slot| **slot == gear.equip_slot)
+                .count();
+            if slot_count > gear.equip_slot.capacity() {
+                return Err(GearsetError::SlotOccupied {
+                    item_id: piece.item_id,
+                    equip_slot: gear.equip_slot,
+                });
+            }
+            stats = stats.saturating_add(piece.stats()?);
+        }
+        Ok(stats)
+    }
+
+    /// Replaces the craftsmanship, control and CP of `crafter_stats` with the stats of the gearset.
+    /// All items must be equippable at the level of `crafter_stats`.
+    pub fn crafter_stats(&self, crafter_stats: CrafterStats) -> Result<CrafterStats, GearsetError> {
+        for piece in &self.pieces {
+            let gear = lookup_gear(piece.item_id)?;
+            if gear.equip_level > crafter_stats.level {
+                return Err(GearsetError::LevelTooLow {
+                    item_id: piece.item_id,
+                    equip_level: gear.equip_level,
+                });
+            }
+        }
+        let stats = self.stats()?;
+        Ok(CrafterStats {
+            craftsmanship: stats.craftsmanship,
+            control: stats.control,
+            cp: stats.cp,
+            ..crafter_stats
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GearsetError {
+    NoGearData,
+    UnknownGear(u32),
+    UnknownMateria(u32),
+    TooManyMateria {
+        item_id: u32,
+        materia_count: usize,
+        max_materia: u8,
+    },
+    SlotOccupied {
+        item_id: u32,
+        equip_slot: EquipSlot,
+    },
+    LevelTooLow {
+        item_id: u32,
+        equip_level: u8,
+    },
+}
+
+impl std::fmt::Display for GearsetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoGearData => write!(f, "The game data does not contain gear or materia"),
+            Self::UnknownGear(item_id) => {
+                write!(f, "Item {item_id} is not gear with crafting stats")
+            }
+            Self::UnknownMateria(item_id) => {
+                write!(f, "Item {item_id} is not materia with crafting stats")
+            }
+            Self::TooManyMateria {
+                item_id,
+                materia_count,
+                max_materia,
+            } => write!(
+                f,
+                "Item {item_id} has {materia_count} materia, but at most {max_materia} can be melded"
+            ),
+            Self::SlotOccupied {
+                item_id,
+                equip_slot,
+            } => write!(
+                f,
+                "Item {item_id} cannot be equipped because the {equip_slot:?} slot is already occupied"
+            ),
+            Self::LevelTooLow {
+                item_id,
+                equip_level,
+            } => write!(f, "Item {item_id} requires level {equip_level}"),
+        }
+    }
+}
+
+impl std::error::Error for GearsetError {}
@@ -7,6 +7,20 @@ pub use config::*;
 mod locales;
 pub use locales::*;
 
+mod macro_parser;
+pub use macro_parser::*;
+
+mod crafting_tree;
+pub use crafting_tree::*;
+
+mod game_data;
+pub use game_data::*;
+
+mod gear;
+pub use gear::*;
+
+use std::borrow::Cow;
+
 use non_contiguously_indexed_array::{NciArray, nci_array};
 
 mod search;
@@ -18,6 +32,7 @@ pub const HQ_ICON_CHAR: char = '\u{e03c}';
 pub const CL_ICON_CHAR: char = '\u{e03d}';
 
 #[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Item {
     pub item_level: u16,
     pub always_collectable: bool,
@@ -31,6 +46,7 @@ pub struct Ingredient {
 }
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RecipeLevel {
     pub job_level: u8,
     pub max_progress: u32,
@@ -69,19 +85,32 @@ pub struct Recipe {
     pub req_control: u16,
 }
 
-#[derive(Debug)]
+/// A recipe that produces a specific item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ItemRecipe {
+    pub recipe_id: u32,
+    /// Number of items produced by a single craft.
+    pub result_amount: u32,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StellarMission {
     pub job_id: u8,
     pub stellar_steady_hand_charges: u8,
-    pub recipe_ids: &'static [u32],
+    pub recipe_ids: Cow<'static, [u32]>,
 }
 
 pub const RLVLS: &[RecipeLevel] = include!("../data/rlvls.rs");
 pub const LEVEL_ADJUST_TABLE: &[u16] = include!("../data/level_adjust_table.rs");
 pub const RECIPES: NciArray<u32, Recipe> = include!("../data/recipes.rs");
 pub const ITEMS: NciArray<u32, Item> = include!("../data/items.rs");
+/// Reverse index from an item ID to all recipes that produce the item, sorted by recipe ID.
+pub const ITEM_RECIPES: NciArray<u32, &[ItemRecipe]> = include!("../data/item_recipes.rs");
 
-pub const STELLAR_MISSIONS: NciArray<u32, StellarMission> = include!("../data/stellar_missions.rs");
+pub static STELLAR_MISSIONS: NciArray<u32, StellarMission> =
+    include!("../data/stellar_missions.rs");
 pub const RECIPE_TO_STELLAR_MISSION_LINKS: NciArray<u32, u32> =
     include!("../data/recipe_to_stellar_mission_link.rs");
 
@@ -92,14 +121,15 @@ pub fn get_game_settings(
     food: Option<Consumable>,
     potion: Option<Consumable>,
 ) -> Settings {
+    let data = game_data();
     let rlvl = if recipe.max_level_scaling != 0 {
         let job_level = std::cmp::min(recipe.max_level_scaling, crafter_stats.level);
-        LEVEL_ADJUST_TABLE[job_level as usize] as usize
+        data.level_adjust_table()[job_level as usize] as usize
     } else {
         recipe.recipe_level as usize
     };
 
-    let mut rlvl_record = RLVLS[rlvl];
+    let mut rlvl_record = data.rlvls()[rlvl];
     if recipe.max_level_scaling != 0 {
         // https://github.com/KonaeAkira/raphael-rs/pull/126#issuecomment-2832041490
         rlvl_record.max_durability = 80;
@@ -168,34 +198,37 @@ pub fn get_game_settings(
     }
 }
 
-pub fn get_initial_quality(
-    crafter_stats: CrafterStats,
-    recipe: Recipe,
-    hq_ingredients: [u8; 6],
-) -> u16 {
-    let ingredients: Vec<(Item, u32)> = recipe
+/// Item level and amount of each ingredient that can be HQ, in the order used for HQ ingredient amounts.
+fn hq_ingredient_item_levels(recipe: &Recipe) -> Vec<(u16, u32)> {
+    recipe
         .ingredients
         .iter()
-        .filter_map(|ingredient| Some((*ITEMS.get(ingredient.item_id)?, ingredient.amount)))
-        .collect();
-
-    let mut max_ilvl = 0;
-    let mut provided_ilvl = 0;
-    for (index, (item, max_amount)) in ingredients.into_iter().enumerate() {
-        max_ilvl += max_amount as u16 * item.item_level;
-        provided_ilvl += hq_ingredients[index] as u16 * item.item_level;
-    }
+        .filter_map(|ingredient| {
+            Some((
+                game_data().item(ingredient.item_id)?.item_level,
+                ingredient.amount,
+            ))
+        })
+        .collect()
+}
 
+fn initial_quality_from_item_levels(
+    crafter_stats: CrafterStats,
+    recipe: Recipe,
+    provided_ilvl: u16,
+    max_ilvl: u16,
+) -> u16 {
+    let rlvls = game_data().rlvls();
     let rlvl = if recipe.max_level_scaling != 0 {
         let job_level = std::cmp::min(recipe.max_level_scaling, crafter_stats.level);
-        RLVLS
+        rlvls
             .iter()
             .position(|rlvl_record| rlvl_record.job_level == job_level)
             .unwrap()
     } else {
         recipe.recipe_level as usize
     };
-    let rlvl_record = &RLVLS[rlvl];
+    let rlvl_record = &rlvls[rlvl];
     let max_quality = rlvl_record.max_quality * recipe.quality_factor / 100;
 
     if max_ilvl != 0 {
@@ -207,6 +240,88 @@ pub fn get_initial_quality(
     }
 }
 
+pub fn get_initial_quality(
+    crafter_stats: CrafterStats,
+    recipe: Recipe,
+    hq_ingredients: [u8; 6],
+) -> u16 {
+    let mut max_ilvl = 0;
+    let mut provided_ilvl = 0;
+    for (index, (item_level, max_amount)) in
+        hq_ingredient_item_levels(&recipe).into_iter().enumerate()
+    {
+        max_ilvl += max_amount as u16 * item_level;
+        provided_ilvl += hq_ingredients[index] as u16 * item_level;
+    }
+    initial_quality_from_item_levels(crafter_stats, recipe, provided_ilvl, max_ilvl)
+}
+
+/// Finds the cheapest HQ ingredient amounts that result in an initial quality of at least `min_initial_quality`.
+///
+/// `costs` contains the cost of a single HQ unit of each ingredient, in the same order as the HQ ingredient amounts.
+/// Use a cost of 1 for every ingredient to minimize the total number of HQ ingredients.
+/// Among the cheapest solutions, the one with the lowest initial quality is returned.
+/// Returns `None` if the initial quality cannot be reached even if all ingredients are HQ.
+pub fn optimize_hq_ingredients(
+    crafter_stats: CrafterStats,
+    recipe: Recipe,
+    min_initial_quality: u16,
+    costs: [u32; 6],
+) -> Option<[u8; 6]> {
+    let ingredients = hq_ingredient_item_levels(&recipe);
+    let max_ilvl: u16 = ingredients
+        .iter()
+        .map(|(item_level, max_amount)| *max_amount as u16 * item_level)
+        .sum();
+
+    // Initial quality is monotonic in the provided item level, so the smallest
+    // provided item level that reaches the target can be found with a binary search.
+    let reaches_target = |provided_ilvl| {
+        initial_quality_from_item_levels(crafter_stats, recipe, provided_ilvl, max_ilvl)
+            >= min_initial_quality
+    };
+    if !reaches_target(max_ilvl) {
+        return None;
+    }
+    let (mut min_provided_ilvl, mut upper_bound) = (0, max_ilvl);
+    while min_provided_ilvl < upper_bound {
+        let mid = min_provided_ilvl + (upper_bound - min_provided_ilvl) / 2;
+        if reaches_target(mid) {
+            upper_bound = mid;
+        } else {
+            min_provided_ilvl = mid + 1;
+        }
+    }
+
+    // Bounded knapsack over the provided item level.
+    // `cheapest[ilvl]` is the cheapest way to provide exactly `ilvl` item levels.
+    let mut cheapest: Vec<Option<(u64, [u8; 6])>> = vec![None; max_ilvl as usize + 1];
+    cheapest[0] = Some((0, [0; 6]));
+    for (index, (item_level, max_amount)) in ingredients.into_iter().enumerate() {
+        let previous = cheapest.clone();
+        for (provided_ilvl, entry) in previous.into_iter().enumerate() {
+            let Some((cost, hq_ingredients)) = entry else {
+                continue;
+            };
+            for amount in 1..=max_amount {
+                let provided_ilvl = provided_ilvl + (amount * u32::from(item_level)) as usize;
+                let cost = cost + u64::from(amount) * u64::from(costs[index]);
+                let mut hq_ingredients = hq_ingredients;
+                hq_ingredients[index] = amount as u8;
+                if cheapest[provided_ilvl].is_none_or(|(best_cost, _)| cost < best_cost) {
+                    cheapest[provided_ilvl] = Some((cost, hq_ingredients));
+                }
+            }
+        }
+    }
+
+    cheapest[min_provided_ilvl as usize..]
+        .iter()
+        .flatten()
+        .min_by_key(|(cost, _)| *cost)
+        .map(|(_, hq_ingredients)| *hq_ingredients)
+}
+
 const HQ_LOOKUP: [u8; 101] = [
     1, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 6, 6, 6, 6, 7, 7, 7, 7, 8, 8, 8,
     9, 9, 9, 10, 10, 10, 11, 11, 11, 12, 12, 12, 13, 13, 13, 14, 14, 14, 15, 15, 15, 16, 16, 17,
@@ -1,4 +1,4 @@
-use crate::{CL_ICON_CHAR, HQ_ICON_CHAR, ITEMS, NciArray, nci_array};
+use crate::{CL_ICON_CHAR, HQ_ICON_CHAR, NciArray, nci_array};
 use raphael_sim::Action;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -14,6 +14,16 @@ pub enum Locale {
 }
 
 impl Locale {
+    pub const ALL: [Self; 7] = [
+        Self::EN,
+        Self::DE,
+        Self::FR,
+        Self::JP,
+        Self::CN,
+        Self::KR,
+        Self::TW,
+    ];
+
     pub fn short_code(self) -> &'static str {
         match self {
             Self::EN => "EN",
@@ -71,20 +81,12 @@ pub const ITEM_NAMES_KR: NciArray<u32, &str> = include!("../data/item_names_kr.r
 pub const ITEM_NAMES_TW: NciArray<u32, &str> = include!("../data/item_names_tw.rs");
 
 pub fn get_raw_item_name(item_id: u32, locale: Locale) -> Option<&'static str> {
-    match locale {
-        Locale::EN => ITEM_NAMES_EN.get(item_id).copied(),
-        Locale::DE => ITEM_NAMES_DE.get(item_id).copied(),
-        Locale::FR => ITEM_NAMES_FR.get(item_id).copied(),
-        Locale::JP => ITEM_NAMES_JP.get(item_id).copied(),
-        Locale::CN => ITEM_NAMES_CN.get(item_id).copied(),
-        Locale::KR => ITEM_NAMES_KR.get(item_id).copied(),
-        Locale::TW => ITEM_NAMES_TW.get(item_id).copied(),
-    }
+    crate::game_data().item_name(item_id, locale)
 }
 
 pub fn get_item_name(item_id: u32, hq: bool, locale: Locale) -> Option<String> {
     let raw_item_name = get_raw_item_name(item_id, locale)?;
-    if ITEMS.get(item_id)?.always_collectable {
+    if crate::game_data().item(item_id)?.always_collectable {
         Some(format!("{} {}", raw_item_name, CL_ICON_CHAR))
     } else if hq {
         Some(format!("{} {}", raw_item_name, HQ_ICON_CHAR))
@@ -109,15 +111,7 @@ pub static STELLAR_MISSION_NAMES_TW: NciArray<u32, &str> =
     include!("../data/stellar_mission_names_tw.rs");
 
 pub fn get_stellar_mission_name(mission_id: u32, locale: Locale) -> Option<&'static str> {
-    match locale {
-        Locale::EN => STELLAR_MISSION_NAMES_EN.get(mission_id).copied(),
-        Locale::DE => STELLAR_MISSION_NAMES_DE.get(mission_id).copied(),
-        Locale::FR => STELLAR_MISSION_NAMES_FR.get(mission_id).copied(),
-        Locale::JP => STELLAR_MISSION_NAMES_JP.get(mission_id).copied(),
-        Locale::CN => STELLAR_MISSION_NAMES_CN.get(mission_id).copied(),
-        Locale::KR => STELLAR_MISSION_NAMES_KR.get(mission_id).copied(),
-        Locale::TW => STELLAR_MISSION_NAMES_TW.get(mission_id).copied(),
-    }
+    crate::game_data().stellar_mission_name(mission_id, locale)
 }
 
 pub const fn action_name(action: Action, locale: Locale) -> &'static str {
@@ -0,0 +1,98 @@
+use raphael_sim::Action;
+use strum::IntoEnumIterator;
+
+use crate::{Locale, action_name, macro_name};
+
+/// Text commands that use an action. All other text commands (e.g. `/echo`, `/macrolock`) are ignored.
+const ACTION_COMMANDS: [&str; 4] = ["ac", "action", "aktion", "アクション"];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MacroParseError {
+    /// One-based line number of the offending line.
+    pub line_number: usize,
+    pub line: String,
+}
+
+impl std::fmt::Display for MacroParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}: could not parse \"{}\"",
+            self.line_number, self.line
+        )
+    }
+}
+
+/// Parses in-game macro text (e.g. `/ac "Basic Synthesis" <wait.3>`) into a list of actions.
+/// Action names are accepted in every supported locale, with or without quotes.
+///
+/// Blank lines and text commands that don't use an action are skipped.
+/// All lines that could not be parsed are returned as errors.
+pub fn parse_macro(text: &str) -> Result<Vec<Action>, Vec<MacroParseError>> {
+    let mut actions: Vec<Action> = Vec::new();
+    let mut errors = Vec::new();
+    for (line_index, line) in text.lines().enumerate() {
+        match parse_macro_line(line) {
+            Ok(Some(action)) => {
+                // Hasty Touch turns into Daring Touch while under the effect of Expedience,
+                // which is granted by Hasty Touch. Both actions share the same macro name.
+                let action = match (actions.last(), action) {
+                    (Some(Action::HastyTouch), Action::HastyTouch) => Action::DaringTouch,
+                    _ => action,
+                };
+                actions.push(action);
+            }
+            Ok(None) => (),
+            Err(()) => errors.push(MacroParseError {
+                line_number: line_index + 1,
+                line: line.to_string(),
+            }),
+        }
+    }
+    match errors.is_empty() {
+        true => Ok(actions),
+        false => Err(errors),
+    }
+}
+
+/// Returns `Ok(None)` if the line doesn't contain an action.
+fn parse_macro_line(line: &str) -> Result<Option<Action>, ()> {
+    let line = line.trim();
+    if line.is_empty() {
+        return Ok(None);
+    }
+    let line = line.strip_prefix('/').ok_or(())?;
+    let (command, mut arguments) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+    if !ACTION_COMMANDS
+        .iter()
+        .any(|action_command| action_command.eq_ignore_ascii_case(command))
+    {
+        return Ok(None);
+    }
+    // Strip trailing placeholders such as `<wait.3>` or `<se.1>`.
+    arguments = arguments.trim();
+    while arguments.ends_with('>')
+        && let Some(placeholder_start) = arguments.rfind('<')
+    {
+        arguments = arguments[..placeholder_start].trim_end();
+    }
+    let name = match arguments.strip_prefix('"') {
+        Some(quoted) => quoted.split_once('"').ok_or(())?.0,
+        None => arguments,
+    };
+    find_action_by_name(name.trim()).map(Some).ok_or(())
+}
+
+/// Finds the action with the given display or macro name in any supported locale.
+/// The comparison ignores ASCII case.
+pub fn find_action_by_name(name: &str) -> Option<Action> {
+    if name.is_empty() {
+        return None;
+    }
+    Locale::ALL.into_iter().find_map(|locale| {
+        Action::iter().find(|&action| {
+            macro_name(action, locale).eq_ignore_ascii_case(name)
+                || action_name(action, locale).eq_ignore_ascii_case(name)
+        })
+    })
+}
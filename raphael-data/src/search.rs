@@ -6,7 +6,7 @@ use std::{
 use nucleo_matcher::pattern::{CaseMatching, Normalization, Pattern};
 
 use crate::{
-    CL_ICON_CHAR, HQ_ICON_CHAR, MEALS, POTIONS, RECIPES, STELLAR_MISSIONS, get_raw_item_name,
+    CL_ICON_CHAR, HQ_ICON_CHAR, Recipe, game_data, get_raw_item_name, get_recipe,
     get_stellar_mission_name,
 };
 
@@ -46,7 +46,7 @@ pub fn find_recipes(
         CaseMatching::Ignore,
         Normalization::Smart,
     );
-    let entries = RECIPES.entries().filter_map(|(recipe_id, recipe)| {
+    let entries = game_data().recipes().filter_map(|(recipe_id, recipe)| {
         let item_name = get_raw_item_name(recipe.item_id, locale)?;
         Some(MatcherCandidate {
             haystack: item_name,
@@ -59,6 +59,142 @@ pub fn find_recipes(
         .map(|(entry, _score)| entry.associated_data)
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RecipeSortOrder {
+    /// Best name matches first.
+    #[default]
+    Relevance,
+    RecipeLevelAscending,
+    RecipeLevelDescending,
+}
+
+/// Filters that recipe search results must satisfy. Filters that are `None` are ignored.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct RecipeFilter {
+    pub job_id: Option<u8>,
+    pub min_recipe_level: Option<u16>,
+    pub max_recipe_level: Option<u16>,
+    /// Job level of the recipe, see [`get_recipe_job_level`].
+    pub job_level: Option<u8>,
+    pub is_expert: Option<bool>,
+    /// Whether the recipe scales with the job level of the crafter (`max_level_scaling != 0`).
+    pub has_max_level_scaling: Option<bool>,
+    pub is_stellar_mission_recipe: Option<bool>,
+    /// Only include recipes whose craftsmanship requirement does not exceed this value.
+    pub craftsmanship: Option<u16>,
+    /// Only include recipes whose control requirement does not exceed this value.
+    pub control: Option<u16>,
+    pub sort_order: RecipeSortOrder,
+}
+
+/// Job level the recipe is listed at in-game.
+/// For recipes that scale with the job level, this is the highest level the recipe scales to.
+pub fn get_recipe_job_level(recipe: &Recipe) -> u8 {
+    match recipe.max_level_scaling {
+        0 => game_data().rlvls()[usize::from(recipe.recipe_level)].job_level,
+        max_level_scaling => max_level_scaling,
+    }
+}
+
+impl RecipeFilter {
+    pub fn matches(&self, recipe_id: u32, recipe: &Recipe) -> bool {
+        self.job_id.is_none_or(|job_id| recipe.job_id == job_id)
+            && self
+                .min_recipe_level
+                .is_none_or(|min_recipe_level| recipe.recipe_level >= min_recipe_level)
+            && self
+                .max_recipe_level
+                .is_none_or(|max_recipe_level| recipe.recipe_level <= max_recipe_level)
+            && self
+                .job_level
+                .is_none_or(|job_level| get_recipe_job_level(recipe) == job_level)
+            && self
+                .is_expert
+                .is_none_or(|is_expert| recipe.is_expert == is_expert)
+            && self
+                .has_max_level_scaling
+                .is_none_or(|has_max_level_scaling| {
+                    (recipe.max_level_scaling != 0) == has_max_level_scaling
+                })
+            && self.is_stellar_mission_recipe.is_none_or(|is_stellar| {
+                game_data().recipe_stellar_mission(recipe_id).is_some() == is_stellar
+            })
+            && self
+                .craftsmanship
+                .is_none_or(|craftsmanship| recipe.req_craftsmanship <= craftsmanship)
+            && self
+                .control
+                .is_none_or(|control| recipe.req_control <= control)
+    }
+
+    /// Removes all entries that do not match the filter and sorts the remaining entries.
+    pub fn apply(&self, entries: &mut Vec<RecipeSearchEntry>) {
+        entries.retain(|(recipe_id, recipe)| self.matches(*recipe_id, recipe));
+        match self.sort_order {
+            RecipeSortOrder::Relevance => (),
+            RecipeSortOrder::RecipeLevelAscending => {
+                entries.sort_by_key(|(_, recipe)| recipe.recipe_level);
+            }
+            RecipeSortOrder::RecipeLevelDescending => {
+                entries.sort_by_key(|(_, recipe)| std::cmp::Reverse(recipe.recipe_level));
+            }
+        }
+    }
+}
+
+/// Same as [`find_recipes`], but only returns recipes that match the filter, sorted by the filter's sort order.
+/// An empty search string matches all recipes.
+pub fn find_recipes_filtered(
+    search_string: &str,
+    locale: crate::Locale,
+    filter: &RecipeFilter,
+) -> Vec<RecipeSearchEntry> {
+    let mut entries: Vec<RecipeSearchEntry> = if search_string.trim().is_empty() {
+        game_data().recipes().collect()
+    } else {
+        find_recipes(search_string, locale).collect()
+    };
+    filter.apply(&mut entries);
+    entries
+}
+
+/// Recipe search entry together with the locale whose item name matched the search string.
+pub type MultiLocaleRecipeSearchEntry = (u32, &'static crate::Recipe, crate::Locale);
+
+/// Same as [`find_recipes`], but matches the search string against the item names of all locales at once.
+/// Each recipe is returned at most once, together with the locale of its best matching name.
+pub fn find_recipes_any_locale(
+    search_string: &str,
+) -> impl Iterator<Item = MultiLocaleRecipeSearchEntry> {
+    let pattern = Pattern::parse(
+        &preprocess_pattern(search_string),
+        CaseMatching::Ignore,
+        Normalization::Smart,
+    );
+    let entries = crate::Locale::ALL.into_iter().flat_map(|locale| {
+        game_data()
+            .recipes()
+            .filter_map(move |(recipe_id, recipe)| {
+                let item_name = get_raw_item_name(recipe.item_id, locale)?;
+                Some(MatcherCandidate {
+                    haystack: item_name,
+                    associated_data: (recipe_id, recipe, locale),
+                })
+            })
+    });
+    let matches = pattern.match_list(entries, MATCHER.lock().as_mut().unwrap());
+    let mut unique_matches: HashSet<u32> = HashSet::default();
+    matches.into_iter().filter_map(move |(entry, _score)| {
+        match unique_matches.insert(entry.associated_data.0) {
+            true => Some(entry.associated_data),
+            false => None,
+        }
+    })
+}
+
 pub type StellarMissionSearchEntry = (u32, &'static crate::StellarMission);
 pub fn find_stellar_missions(
     search_string: &str,
@@ -69,8 +205,8 @@ pub fn find_stellar_missions(
         CaseMatching::Ignore,
         Normalization::Smart,
     );
-    let mission_entries = STELLAR_MISSIONS
-        .entries()
+    let mission_entries = game_data()
+        .stellar_missions()
         .filter_map(|(mission_id, mission)| {
             let mission_name = get_stellar_mission_name(mission_id, locale)?;
             Some(MatcherCandidate {
@@ -78,11 +214,11 @@ pub fn find_stellar_missions(
                 associated_data: (mission_id, mission),
             })
         });
-    let recipe_entries = STELLAR_MISSIONS
-        .entries()
+    let recipe_entries = game_data()
+        .stellar_missions()
         .flat_map(|(mission_id, mission)| {
             mission.recipe_ids.iter().filter_map(move |&recipe_id| {
-                let recipe = RECIPES.get(recipe_id)?;
+                let recipe = get_recipe(recipe_id)?;
                 let item_name = get_raw_item_name(recipe.item_id, locale)?;
                 Some(MatcherCandidate {
                     haystack: item_name,
@@ -130,12 +266,64 @@ pub fn find_meals(
     search_string: &str,
     locale: crate::Locale,
 ) -> impl Iterator<Item = &'static crate::Consumable> {
-    find_consumables(search_string, locale, MEALS)
+    find_consumables(search_string, locale, game_data().meals())
 }
 
 pub fn find_potions(
     search_string: &str,
     locale: crate::Locale,
 ) -> impl Iterator<Item = &'static crate::Consumable> {
-    find_consumables(search_string, locale, POTIONS)
+    find_consumables(search_string, locale, game_data().potions())
+}
+
+/// Stellar mission search entry together with the locale whose name matched the search string.
+pub type MultiLocaleStellarMissionSearchEntry =
+    (u32, &'static crate::StellarMission, crate::Locale);
+
+/// Same as [`find_stellar_missions`], but matches the search string against the names of all locales at once.
+/// Each mission is returned at most once, together with the locale of its best matching name.
+pub fn find_stellar_missions_any_locale(
+    search_string: &str,
+) -> impl Iterator<Item = MultiLocaleStellarMissionSearchEntry> {
+    let pattern = Pattern::parse(
+        &preprocess_pattern(search_string),
+        CaseMatching::Ignore,
+        Normalization::Smart,
+    );
+    let mission_entries = crate::Locale::ALL.into_iter().flat_map(|locale| {
+        game_data()
+            .stellar_missions()
+            .filter_map(move |(mission_id, mission)| {
+                let mission_name = get_stellar_mission_name(mission_id, locale)?;
+                Some(MatcherCandidate {
+                    haystack: mission_name,
+                    associated_data: (mission_id, mission, locale),
+                })
+            })
+    });
+    let recipe_entries = crate::Locale::ALL.into_iter().flat_map(|locale| {
+        game_data()
+            .stellar_missions()
+            .flat_map(move |(mission_id, mission)| {
+                mission.recipe_ids.iter().filter_map(move |&recipe_id| {
+                    let recipe = get_recipe(recipe_id)?;
+                    let item_name = get_raw_item_name(recipe.item_id, locale)?;
+                    Some(MatcherCandidate {
+                        haystack: item_name,
+                        associated_data: (mission_id, mission, locale),
+                    })
+                })
+            })
+    });
+    let matches = pattern.match_list(
+        mission_entries.chain(recipe_entries),
+        MATCHER.lock().as_mut().unwrap(),
+    );
+    let mut unique_matches: HashSet<u32> = HashSet::default();
+    matches.into_iter().filter_map(move |(entry, _score)| {
+        match unique_matches.insert(entry.associated_data.0) {
+            true => Some(entry.associated_data),
+            false => None,
+        }
+    })
 }
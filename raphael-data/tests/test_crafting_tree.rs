@@ -0,0 +1,88 @@
+use raphael_data::*;
+
+#[test]
+fn test_item_recipes_reverse_index() {
+    for (recipe_id, recipe) in RECIPES.entries() {
+        assert!(
+            get_item_recipes(recipe.item_id)
+                .iter()
+                .any(|item_recipe| item_recipe.recipe_id == recipe_id),
+            "Recipe {recipe_id} is missing from the reverse index"
+        );
+    }
+    for (item_id, item_recipes) in ITEM_RECIPES.entries() {
+        assert!(item_recipes.is_sorted_by_key(|item_recipe| item_recipe.recipe_id));
+        for item_recipe in *item_recipes {
+            assert_eq!(RECIPES[item_recipe.recipe_id].item_id, item_id);
+            assert!(item_recipe.result_amount >= 1);
+        }
+    }
+}
+
+fn check_node(node: &CraftingTreeNode) {
+    let Some(recipe_id) = node.recipe_id else {
+        assert_eq!(node.crafts, 0);
+        assert!(node.ingredients.is_empty());
+        return;
+    };
+    let recipe = RECIPES[recipe_id];
+    assert_eq!(recipe.item_id, node.item_id);
+    assert_eq!(Some(recipe.job_id), node.job_id);
+    assert!(node.crafts >= 1);
+    let result_amount = get_item_recipes(node.item_id)
+        .iter()
+        .find(|item_recipe| item_recipe.recipe_id == recipe_id)
+        .unwrap()
+        .result_amount;
+    assert!(node.crafts * result_amount >= node.amount);
+    let ingredients: Vec<_> = recipe
+        .ingredients
+        .iter()
+        .filter(|ingredient| ingredient.item_id != 0)
+        .collect();
+    assert_eq!(ingredients.len(), node.ingredients.len());
+    for (ingredient, child) in ingredients.into_iter().zip(node.ingredients.iter()) {
+        assert_eq!(ingredient.item_id, child.item_id);
+        assert_eq!(ingredient.amount * node.crafts, child.amount);
+        check_node(child);
+    }
+}
+
+#[test]
+fn test_crafting_tree_consistency() {
+    for recipe_id in RECIPES
+        .entries()
+        .map(|(recipe_id, _)| recipe_id)
+        .step_by(50)
+    {
+        let tree = get_crafting_tree(recipe_id, 3).unwrap();
+        assert_eq!(tree.recipe_id, Some(recipe_id));
+        assert_eq!(tree.amount, 3);
+        check_node(&tree);
+    }
+}
+
+#[test]
+fn test_crafting_tree_crafted_nodes_order() {
+    for recipe_id in RECIPES
+        .entries()
+        .map(|(recipe_id, _)| recipe_id)
+        .step_by(50)
+    {
+        let tree = get_crafting_tree(recipe_id, 1).unwrap();
+        let crafted_nodes = tree.crafted_nodes();
+        // The root node is always crafted last.
+        assert_eq!(crafted_nodes.last().unwrap().recipe_id, Some(recipe_id));
+        assert!(
+            crafted_nodes
+                .iter()
+                .all(|node| node.recipe_id.is_some() && node.crafts >= 1)
+        );
+    }
+}
+
+#[test]
+fn test_crafting_tree_missing_recipe() {
+    assert_eq!(get_crafting_tree(u32::MAX, 1), None);
+    assert!(get_item_recipes(0).is_empty());
+}
@@ -0,0 +1,148 @@
+use raphael_data::*;
+
+#[test]
+fn test_embedded_data_pack_matches_embedded_data() {
+    let data_pack = DataPack::from_embedded("embedded");
+    let embedded = EmbeddedGameData;
+    assert_eq!(data_pack.recipes().count(), embedded.recipes().count());
+    for ((pack_recipe_id, pack_recipe), (recipe_id, recipe)) in
+        data_pack.recipes().zip(embedded.recipes())
+    {
+        assert_eq!(pack_recipe_id, recipe_id);
+        assert_eq!(pack_recipe, recipe);
+        for locale in Locale::ALL {
+            assert_eq!(
+                data_pack.item_name(recipe.item_id, locale),
+                embedded.item_name(recipe.item_id, locale)
+            );
+        }
+        assert_eq!(
+            data_pack.item_recipes(recipe.item_id),
+            embedded.item_recipes(recipe.item_id)
+        );
+    }
+    assert_eq!(data_pack.rlvls().len(), embedded.rlvls().len());
+    assert_eq!(
+        data_pack.level_adjust_table(),
+        embedded.level_adjust_table()
+    );
+    assert_eq!(data_pack.meals().len(), embedded.meals().len());
+    assert_eq!(data_pack.potions().len(), embedded.potions().len());
+    assert_eq!(
+        data_pack.stellar_missions().count(),
+        embedded.stellar_missions().count()
+    );
+    for ((pack_mission_id, pack_mission), (mission_id, mission)) in data_pack
+        .stellar_missions()
+        .zip(embedded.stellar_missions())
+    {
+        assert_eq!(pack_mission_id, mission_id);
+        assert_eq!(pack_mission.recipe_ids, mission.recipe_ids);
+        for locale in Locale::ALL {
+            assert_eq!(
+                data_pack.stellar_mission_name(mission_id, locale),
+                embedded.stellar_mission_name(mission_id, locale)
+            );
+        }
+        for &recipe_id in &*mission.recipe_ids {
+            assert_eq!(
+                data_pack.recipe_stellar_mission(recipe_id),
+                embedded.recipe_stellar_mission(recipe_id)
+            );
+        }
+    }
+}
+
+#[cfg(feature = "data-pack")]
+#[test]
+fn test_data_pack_json_round_trip() {
+    let data_pack = DataPack::from_embedded("embedded");
+    let loaded = DataPack::from_json(&data_pack.to_json()).unwrap();
+    assert_eq!(loaded.data_version, "embedded");
+    assert_eq!(loaded.recipes, data_pack.recipes);
+    assert_eq!(loaded.item_names, data_pack.item_names);
+    assert_eq!(loaded.item_recipes, data_pack.item_recipes);
+    assert_eq!(loaded.level_adjust_table, data_pack.level_adjust_table);
+    assert_eq!(
+        loaded.stellar_missions.len(),
+        data_pack.stellar_missions.len()
+    );
+    assert_eq!(
+        loaded.stellar_mission_names,
+        data_pack.stellar_mission_names
+    );
+    assert_eq!(
+        loaded.recipe_to_stellar_mission_links,
+        data_pack.recipe_to_stellar_mission_links
+    );
+}
+
+#[test]
+fn test_unsupported_format_version() {
+    let data_pack = DataPack {
+        format_version: DATA_PACK_FORMAT_VERSION + 1,
+        ..Default::default()
+    };
+    assert!(matches!(
+        load_data_pack(data_pack),
+        Err(DataPackError::UnsupportedFormatVersion(_))
+    ));
+}
+
+#[test]
+fn test_loaded_data_pack_replaces_embedded_data() {
+    let (recipe_id, recipe) = RECIPES.entries().next().unwrap();
+    let mut data_pack = DataPack::from_embedded("modified");
+    data_pack
+        .item_names
+        .get_mut(&Locale::EN)
+        .unwrap()
+        .insert(recipe.item_id, "Patch Day Special".to_owned());
+    data_pack
+        .recipes
+        .get_mut(&recipe_id)
+        .unwrap()
+        .durability_factor = 200;
+    let (mission_id, _) = STELLAR_MISSIONS.entries().next().unwrap();
+    data_pack
+        .stellar_mission_names
+        .get_mut(&Locale::EN)
+        .unwrap()
+        .insert(mission_id, "Patch Day Mission".to_owned());
+    let max_durability = get_game_settings(
+        Recipe {
+            durability_factor: 100,
+            ..*recipe
+        },
+        None,
+        CrafterStats::default(),
+        None,
+        None,
+    )
+    .max_durability;
+
+    load_data_pack(data_pack).unwrap();
+    assert!(is_data_pack_loaded());
+    assert_eq!(
+        get_raw_item_name(recipe.item_id, Locale::EN),
+        Some("Patch Day Special")
+    );
+    let matches: Vec<_> = find_recipes("Patch Day Special", Locale::EN).collect();
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0].0, recipe_id);
+    let settings = get_game_settings(*matches[0].1, None, CrafterStats::default(), None, None);
+    assert_eq!(settings.max_durability, max_durability * 2);
+    assert_eq!(
+        get_stellar_mission_name(mission_id, Locale::EN),
+        Some("Patch Day Mission")
+    );
+    assert!(
+        find_stellar_missions("Patch Day Mission", Locale::EN)
+            .any(|(matched_mission_id, _)| matched_mission_id == mission_id)
+    );
+
+    assert!(matches!(
+        load_data_pack(DataPack::from_embedded("second")),
+        Err(DataPackError::AlreadyLoaded)
+    ));
+}
@@ -0,0 +1,59 @@
+use raphael_data::*;
+
+// These tests use the embedded game data. Tests that load a custom data pack are in `test_gearset.rs`.
+
+#[test]
+fn test_gearset_without_gear_data() {
+    if has_gear_data() {
+        return;
+    }
+    let gearset = Gearset {
+        base_stats: GearStats::default(),
+        pieces: vec![GearPiece {
+            item_id: 1,
+            hq: false,
+            materia: vec![2],
+        }],
+    };
+    assert_eq!(gearset.stats(), Err(GearsetError::NoGearData));
+    assert_eq!(
+        gearset.crafter_stats(CrafterStats::default()),
+        Err(GearsetError::NoGearData)
+    );
+    // A gear set without any items does not need gear data.
+    let crafter_stats = Gearset::default()
+        .crafter_stats(CrafterStats::default())
+        .unwrap();
+    assert_eq!(
+        (
+            crafter_stats.craftsmanship,
+            crafter_stats.control,
+            crafter_stats.cp
+        ),
+        (0, 0, 0)
+    );
+}
+
+#[test]
+fn test_embedded_gear() {
+    assert_eq!(GEAR, game_data().gear());
+    for gear in GEAR {
+        assert_eq!(find_gear(gear.item_id), Some(gear));
+        assert!(gear.max_materia() <= MAX_MATERIA_SLOTS);
+        let piece = GearPiece {
+            item_id: gear.item_id,
+            hq: false,
+            materia: Vec::new(),
+        };
+        assert_eq!(piece.stats(), Ok(gear.stats));
+    }
+}
+
+#[test]
+fn test_embedded_materia() {
+    assert_eq!(MATERIA, game_data().materia());
+    for materia in MATERIA {
+        assert_eq!(find_materia(materia.item_id), Some(materia));
+        assert!(materia.value > 0);
+    }
+}
@@ -0,0 +1,182 @@
+use raphael_data::*;
+
+const TOOL: u32 = 1;
+const RING: u32 = 2;
+const HAT: u32 = 3;
+const CRAFTSMANSHIP_MATERIA: u32 = 10;
+const CONTROL_MATERIA: u32 = 11;
+
+fn stats(craftsmanship: u16, control: u16, cp: u16) -> GearStats {
+    GearStats {
+        craftsmanship,
+        control,
+        cp,
+    }
+}
+
+fn gear(item_id: u32, equip_slot: EquipSlot, base: GearStats, meld_cap: GearStats) -> Gear {
+    Gear {
+        item_id,
+        equip_slot,
+        equip_level: 100,
+        item_level: 710,
+        materia_slots: 2,
+        advanced_melding: item_id != TOOL,
+        stats: base,
+        hq_stats: stats(base.craftsmanship + 10, base.control + 10, base.cp),
+        meld_cap,
+    }
+}
+
+fn load_test_data() {
+    // Tests run in parallel in the same process, so only the first call loads the data pack.
+    let _ = load_data_pack(DataPack {
+        format_version: DATA_PACK_FORMAT_VERSION,
+        gear: vec![
+            gear(
+                TOOL,
+                EquipSlot::MainHand,
+                stats(500, 300, 0),
+                stats(600, 400, 0),
+            ),
+            gear(RING, EquipSlot::Ring, stats(100, 0, 0), stats(130, 130, 10)),
+            gear(HAT, EquipSlot::Head, stats(0, 0, 5), stats(50, 50, 10)),
+        ],
+        materia: vec![
+            Materia {
+                item_id: CRAFTSMANSHIP_MATERIA,
+                stat: CraftingStat::Craftsmanship,
+                grade: 12,
+                value: 54,
+            },
+            Materia {
+                item_id: CONTROL_MATERIA,
+                stat: CraftingStat::Control,
+                grade: 12,
+                value: 54,
+            },
+        ],
+        ..Default::default()
+    });
+}
+
+fn piece(item_id: u32, hq: bool, materia: &[u32]) -> GearPiece {
+    GearPiece {
+        item_id,
+        hq,
+        materia: materia.to_vec(),
+    }
+}
+
+#[test]
+fn test_gear_piece_stats() {
+    load_test_data();
+    assert_eq!(piece(TOOL, false, &[]).stats(), Ok(stats(500, 300, 0)));
+    assert_eq!(piece(TOOL, true, &[]).stats(), Ok(stats(510, 310, 0)));
+    assert_eq!(
+        piece(TOOL, true, &[CRAFTSMANSHIP_MATERIA, CONTROL_MATERIA]).stats(),
+        Ok(stats(564, 364, 0))
+    );
+}
+
+#[test]
+fn test_meld_cap() {
+    load_test_data();
+    // The second craftsmanship materia is capped at the meld cap of 130.
+    assert_eq!(
+        piece(
+            RING,
+            false,
+            &[CRAFTSMANSHIP_MATERIA, CONTROL_MATERIA, CONTROL_MATERIA]
+        )
+        .stats(),
+        Ok(stats(130, 108, 0))
+    );
+    assert_eq!(
+        piece(RING, false, &[CONTROL_MATERIA; 3]).stats(),
+        Ok(stats(100, 130, 0))
+    );
+}
+
+#[test]
+fn test_too_many_materia() {
+    load_test_data();
+    assert_eq!(
+        piece(TOOL, false, &[CONTROL_MATERIA; 3]).stats(),
+        Err(GearsetError::TooManyMateria {
+            item_id: TOOL,
+            materia_count: 3,
+            max_materia: 2,
+        })
+    );
+    // Items that allow advanced melding can have up to 5 materia.
+    assert!(piece(HAT, false, &[CONTROL_MATERIA; 5]).stats().is_ok());
+    assert!(piece(HAT, false, &[CONTROL_MATERIA; 6]).stats().is_err());
+}
+
+#[test]
+fn test_gearset_crafter_stats() {
+    load_test_data();
+    let gearset = Gearset {
+        base_stats: stats(0, 0, 180),
+        pieces: vec![
+            piece(TOOL, true, &[CRAFTSMANSHIP_MATERIA]),
+            piece(RING, false, &[]),
+            piece(RING, true, &[CONTROL_MATERIA]),
+            piece(HAT, false, &[]),
+        ],
+    };
+    let crafter_stats = CrafterStats {
+        level: 100,
+        manipulation: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        gearset.crafter_stats(crafter_stats),
+        Ok(CrafterStats {
+            craftsmanship: 564 + 100 + 110,
+            control: 310 + 64,
+            cp: 185,
+            ..crafter_stats
+        })
+    );
+    assert_eq!(
+        gearset.crafter_stats(CrafterStats {
+            level: 90,
+            ..crafter_stats
+        }),
+        Err(GearsetError::LevelTooLow {
+            item_id: TOOL,
+            equip_level: 100,
+        })
+    );
+}
+
+#[test]
+fn test_gearset_errors() {
+    load_test_data();
+    let gearset = Gearset {
+        base_stats: GearStats::default(),
+        pieces: vec![
+            piece(RING, false, &[]),
+            piece(RING, false, &[]),
+            piece(RING, false, &[]),
+        ],
+    };
+    assert_eq!(
+        gearset.stats(),
+        Err(GearsetError::SlotOccupied {
+            item_id: RING,
+            equip_slot: EquipSlot::Ring,
+        })
+    );
+    let gearset = Gearset {
+        base_stats: GearStats::default(),
+        pieces: vec![piece(4, false, &[])],
+    };
+    assert_eq!(gearset.stats(), Err(GearsetError::UnknownGear(4)));
+    assert_eq!(
+        piece(HAT, false, &[RING]).stats(),
+        Err(GearsetError::UnknownMateria(RING))
+    );
+}
@@ -0,0 +1,89 @@
+use raphael_data::*;
+
+const CRAFTER_STATS: CrafterStats = CrafterStats {
+    craftsmanship: 5000,
+    control: 5000,
+    cp: 600,
+    level: 100,
+    manipulation: true,
+    heart_and_soul: false,
+    quick_innovation: false,
+};
+
+/// All HQ ingredient amounts that are valid for the recipe.
+fn all_hq_ingredients(recipe: &Recipe) -> Vec<[u8; 6]> {
+    let max_amounts: Vec<u8> = recipe
+        .ingredients
+        .iter()
+        .filter(|ingredient| ITEMS.has_entry(ingredient.item_id))
+        .map(|ingredient| ingredient.amount as u8)
+        .collect();
+    let mut combinations = vec![[0; 6]];
+    for (index, max_amount) in max_amounts.into_iter().enumerate() {
+        combinations = combinations
+            .into_iter()
+            .flat_map(|combination| {
+                (0..=max_amount).map(move |amount| {
+                    let mut combination = combination;
+                    combination[index] = amount;
+                    combination
+                })
+            })
+            .collect();
+    }
+    combinations
+}
+
+fn total_cost(hq_ingredients: [u8; 6], costs: [u32; 6]) -> u32 {
+    hq_ingredients
+        .iter()
+        .zip(costs)
+        .map(|(amount, cost)| u32::from(*amount) * cost)
+        .sum()
+}
+
+#[test]
+fn test_optimize_hq_ingredients_matches_brute_force() {
+    let costs = [[1; 6], [5, 1, 3, 2, 4, 1]];
+    for recipe in RECIPES.values().step_by(100) {
+        let combinations = all_hq_ingredients(recipe);
+        if combinations.len() > 2000 {
+            continue;
+        }
+        let max_initial_quality = combinations
+            .iter()
+            .map(|hq_ingredients| get_initial_quality(CRAFTER_STATS, *recipe, *hq_ingredients))
+            .max()
+            .unwrap();
+        for target in [0, max_initial_quality / 3, max_initial_quality] {
+            for costs in costs {
+                let hq_ingredients =
+                    optimize_hq_ingredients(CRAFTER_STATS, *recipe, target, costs).unwrap();
+                assert!(get_initial_quality(CRAFTER_STATS, *recipe, hq_ingredients) >= target);
+                let cheapest = combinations
+                    .iter()
+                    .filter(|hq_ingredients| {
+                        get_initial_quality(CRAFTER_STATS, *recipe, **hq_ingredients) >= target
+                    })
+                    .map(|hq_ingredients| total_cost(*hq_ingredients, costs))
+                    .min()
+                    .unwrap();
+                assert_eq!(total_cost(hq_ingredients, costs), cheapest);
+            }
+        }
+        assert_eq!(
+            optimize_hq_ingredients(CRAFTER_STATS, *recipe, max_initial_quality + 1, [1; 6]),
+            None
+        );
+    }
+}
+
+#[test]
+fn test_optimize_hq_ingredients_zero_target() {
+    for recipe in RECIPES.values().step_by(100) {
+        assert_eq!(
+            optimize_hq_ingredients(CRAFTER_STATS, *recipe, 0, [1; 6]),
+            Some([0; 6])
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use raphael_data::*;
+use raphael_sim::Action;
+
+#[test]
+fn test_parse_macro_en() {
+    let text = r#"/macrolock
+/ac "Muscle Memory" <wait.3>
+/ac Manipulation <wait.2>
+/ac "Master's Mend" <wait.3>
+/echo Macro #1 complete <se.1>
+
+/action "Basic Synthesis" <wait.3>"#;
+    assert_eq!(
+        parse_macro(text),
+        Ok(vec![
+            Action::MuscleMemory,
+            Action::Manipulation,
+            Action::MasterMend,
+            Action::BasicSynthesis,
+        ])
+    );
+}
+
+#[test]
+fn test_parse_macro_other_locales() {
+    let text =
+        "/ac \"Bearbeiten\" <wait.3>\n/ac 作業 <wait.3>\n/ac \"加工\"\n/ac 中级加工 <wait.3>";
+    assert_eq!(
+        parse_macro(text),
+        Ok(vec![
+            Action::BasicSynthesis,
+            Action::BasicSynthesis,
+            Action::BasicTouch,
+            Action::StandardTouch,
+        ])
+    );
+}
+
+#[test]
+fn test_parse_macro_case_insensitive() {
+    let text = "/AC \"basic synthesis\" <wait.3>";
+    assert_eq!(parse_macro(text), Ok(vec![Action::BasicSynthesis]));
+}
+
+#[test]
+fn test_parse_macro_stellar_actions() {
+    let actions = [
+        Action::StellarSteadyHand,
+        Action::HastyTouch,
+        Action::DaringTouch,
+        Action::RapidSynthesis,
+    ];
+    for locale in [Locale::EN, Locale::JP, Locale::KR] {
+        let text: String = actions
+            .iter()
+            .map(|action| format!("/ac \"{}\" <wait.3>\n", macro_name(*action, locale)))
+            .collect();
+        assert_eq!(parse_macro(&text), Ok(actions.to_vec()));
+    }
+}
+
+#[test]
+fn test_parse_macro_errors() {
+    let text = "/ac \"Basic Synthesis\" <wait.3>\n/ac \"Not An Action\" <wait.3>\nBasic Touch\n/ac \"Basic Touch <wait.3>";
+    assert_eq!(
+        parse_macro(text),
+        Err(vec![
+            MacroParseError {
+                line_number: 2,
+                line: "/ac \"Not An Action\" <wait.3>".to_string(),
+            },
+            MacroParseError {
+                line_number: 3,
+                line: "Basic Touch".to_string(),
+            },
+            MacroParseError {
+                line_number: 4,
+                line: "/ac \"Basic Touch <wait.3>".to_string(),
+            },
+        ])
+    );
+}
+
+#[test]
+fn test_parse_macro_roundtrip() {
+    let actions = [
+        Action::Reflect,
+        Action::WasteNot2,
+        Action::Veneration,
+        Action::Groundwork,
+        Action::Innovation,
+        Action::PreparatoryTouch,
+        Action::TrainedPerfection,
+        Action::ByregotsBlessing,
+        Action::CarefulSynthesis,
+    ];
+    for locale in [
+        Locale::EN,
+        Locale::DE,
+        Locale::FR,
+        Locale::JP,
+        Locale::CN,
+        Locale::KR,
+        Locale::TW,
+    ] {
+        let text: String = actions
+            .iter()
+            .map(|action| format!("/ac \"{}\" <wait.3>\n", macro_name(*action, locale)))
+            .collect();
+        assert_eq!(parse_macro(&text), Ok(actions.to_vec()), "{locale:?}");
+    }
+}
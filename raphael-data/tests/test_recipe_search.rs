@@ -0,0 +1,124 @@
+use raphael_data::*;
+
+#[test]
+fn test_default_filter_matches_everything() {
+    let filter = RecipeFilter::default();
+    assert_eq!(
+        find_recipes_filtered("", Locale::EN, &filter).len(),
+        RECIPES.entries().count()
+    );
+    let matches: Vec<_> = find_recipes("Fiberboard", Locale::EN).collect();
+    assert_eq!(
+        find_recipes_filtered("Fiberboard", Locale::EN, &filter),
+        matches
+    );
+}
+
+#[test]
+fn test_level_100_expert_carpenter_recipes() {
+    let filter = RecipeFilter {
+        job_id: Some(0),
+        job_level: Some(100),
+        is_expert: Some(true),
+        ..Default::default()
+    };
+    let matches = find_recipes_filtered("", Locale::EN, &filter);
+    assert!(!matches.is_empty());
+    for (_, recipe) in &matches {
+        assert_eq!(recipe.job_id, 0);
+        assert_eq!(get_recipe_job_level(recipe), 100);
+        assert!(recipe.is_expert);
+    }
+    let expected_count = RECIPES
+        .values()
+        .filter(|recipe| {
+            recipe.job_id == 0 && recipe.is_expert && get_recipe_job_level(recipe) == 100
+        })
+        .count();
+    assert_eq!(matches.len(), expected_count);
+}
+
+#[test]
+fn test_recipe_level_range_and_sorting() {
+    let filter = RecipeFilter {
+        min_recipe_level: Some(500),
+        max_recipe_level: Some(700),
+        sort_order: RecipeSortOrder::RecipeLevelDescending,
+        ..Default::default()
+    };
+    let matches = find_recipes_filtered("", Locale::EN, &filter);
+    assert!(!matches.is_empty());
+    assert!(
+        matches
+            .iter()
+            .all(|(_, recipe)| (500..=700).contains(&recipe.recipe_level))
+    );
+    assert!(matches.is_sorted_by_key(|(_, recipe)| std::cmp::Reverse(recipe.recipe_level)));
+
+    let filter = RecipeFilter {
+        sort_order: RecipeSortOrder::RecipeLevelAscending,
+        ..filter
+    };
+    let matches = find_recipes_filtered("", Locale::EN, &filter);
+    assert!(matches.is_sorted_by_key(|(_, recipe)| recipe.recipe_level));
+}
+
+#[test]
+fn test_stellar_mission_and_stat_requirement_filters() {
+    let filter = RecipeFilter {
+        is_stellar_mission_recipe: Some(true),
+        ..Default::default()
+    };
+    for (recipe_id, _) in find_recipes_filtered("", Locale::EN, &filter) {
+        assert!(RECIPE_TO_STELLAR_MISSION_LINKS.has_entry(recipe_id));
+    }
+
+    let filter = RecipeFilter {
+        has_max_level_scaling: Some(false),
+        craftsmanship: Some(4000),
+        control: Some(3800),
+        ..Default::default()
+    };
+    for (_, recipe) in find_recipes_filtered("", Locale::EN, &filter) {
+        assert_eq!(recipe.max_level_scaling, 0);
+        assert!(recipe.req_craftsmanship <= 4000);
+        assert!(recipe.req_control <= 3800);
+    }
+}
+
+#[test]
+fn test_find_recipes_any_locale() {
+    for (recipe_id, recipe) in RECIPES.entries().step_by(500) {
+        for locale in [Locale::EN, Locale::DE, Locale::JP] {
+            let item_name = get_raw_item_name(recipe.item_id, locale).unwrap();
+            let matches: Vec<_> = find_recipes_any_locale(item_name).collect();
+            assert!(
+                matches
+                    .iter()
+                    .any(|(matched_recipe_id, _, _)| *matched_recipe_id == recipe_id),
+                "Recipe {recipe_id} not found when searching for {item_name:?}"
+            );
+            let mut recipe_ids: Vec<u32> =
+                matches.iter().map(|(recipe_id, _, _)| *recipe_id).collect();
+            recipe_ids.sort_unstable();
+            recipe_ids.dedup();
+            assert_eq!(recipe_ids.len(), matches.len());
+        }
+    }
+}
+
+#[test]
+fn test_find_stellar_missions_any_locale() {
+    for (mission_id, _) in STELLAR_MISSIONS.entries().step_by(20) {
+        for locale in [Locale::EN, Locale::DE, Locale::JP] {
+            let Some(mission_name) = get_stellar_mission_name(mission_id, locale) else {
+                continue;
+            };
+            assert!(
+                find_stellar_missions_any_locale(mission_name)
+                    .any(|(matched_mission_id, _, _)| matched_mission_id == mission_id),
+                "Mission {mission_id} not found when searching for {mission_name:?}"
+            );
+        }
+    }
+}
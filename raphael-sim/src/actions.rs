@@ -1312,6 +1312,47 @@ impl Action {
         }
     }
 
+    /// Minimum job level that is required to use the action.
+    pub const fn level_requirement(self) -> u8 {
+        match self {
+            Self::BasicSynthesis => BasicSynthesis::LEVEL_REQUIREMENT,
+            Self::BasicTouch => BasicTouch::LEVEL_REQUIREMENT,
+            Self::MasterMend => MasterMend::LEVEL_REQUIREMENT,
+            Self::Observe => Observe::LEVEL_REQUIREMENT,
+            Self::TricksOfTheTrade => TricksOfTheTrade::LEVEL_REQUIREMENT,
+            Self::WasteNot => WasteNot::LEVEL_REQUIREMENT,
+            Self::Veneration => Veneration::LEVEL_REQUIREMENT,
+            Self::StandardTouch => StandardTouch::LEVEL_REQUIREMENT,
+            Self::GreatStrides => GreatStrides::LEVEL_REQUIREMENT,
+            Self::Innovation => Innovation::LEVEL_REQUIREMENT,
+            Self::WasteNot2 => WasteNot2::LEVEL_REQUIREMENT,
+            Self::ByregotsBlessing => ByregotsBlessing::LEVEL_REQUIREMENT,
+            Self::PreciseTouch => PreciseTouch::LEVEL_REQUIREMENT,
+            Self::MuscleMemory => MuscleMemory::LEVEL_REQUIREMENT,
+            Self::CarefulSynthesis => CarefulSynthesis::LEVEL_REQUIREMENT,
+            Self::Manipulation => Manipulation::LEVEL_REQUIREMENT,
+            Self::PrudentTouch => PrudentTouch::LEVEL_REQUIREMENT,
+            Self::Reflect => Reflect::LEVEL_REQUIREMENT,
+            Self::PreparatoryTouch => PreparatoryTouch::LEVEL_REQUIREMENT,
+            Self::Groundwork => Groundwork::LEVEL_REQUIREMENT,
+            Self::DelicateSynthesis => DelicateSynthesis::LEVEL_REQUIREMENT,
+            Self::IntensiveSynthesis => IntensiveSynthesis::LEVEL_REQUIREMENT,
+            Self::AdvancedTouch => AdvancedTouch::LEVEL_REQUIREMENT,
+            Self::HeartAndSoul => HeartAndSoul::LEVEL_REQUIREMENT,
+            Self::PrudentSynthesis => PrudentSynthesis::LEVEL_REQUIREMENT,
+            Self::TrainedFinesse => TrainedFinesse::LEVEL_REQUIREMENT,
+            Self::RefinedTouch => RefinedTouch::LEVEL_REQUIREMENT,
+            Self::ImmaculateMend => ImmaculateMend::LEVEL_REQUIREMENT,
+            Self::TrainedPerfection => TrainedPerfection::LEVEL_REQUIREMENT,
+            Self::TrainedEye => TrainedEye::LEVEL_REQUIREMENT,
+            Self::QuickInnovation => QuickInnovation::LEVEL_REQUIREMENT,
+            Self::StellarSteadyHand => StellarSteadyHand::LEVEL_REQUIREMENT,
+            Self::RapidSynthesis => RapidSynthesis::LEVEL_REQUIREMENT,
+            Self::HastyTouch => HastyTouch::LEVEL_REQUIREMENT,
+            Self::DaringTouch => DaringTouch::LEVEL_REQUIREMENT,
+        }
+    }
+
     pub const fn action_id(self) -> u32 {
         match self {
             Self::BasicSynthesis => 100001,
@@ -70,6 +70,16 @@ impl<'a> MacroSolver<'a> {
     }
 
     pub fn solve(&mut self) -> Result<Vec<Action>, SolverException> {
+        let initial_state = SimulationState::new(&self.settings.simulator_settings);
+        self.solve_from(initial_state)
+    }
+
+    /// Finds the best continuation of a partial rotation that results in `initial_state`.
+    /// The returned actions do not include the actions of the partial rotation.
+    pub fn solve_from(
+        &mut self,
+        initial_state: SimulationState,
+    ) -> Result<Vec<Action>, SolverException> {
         log::debug!(
             "rayon::current_num_threads() = {}",
             rayon::current_num_threads()
@@ -84,8 +94,6 @@ impl<'a> MacroSolver<'a> {
 
         let _total_time = ScopedTimer::new("Total Time");
 
-        let initial_state = SimulationState::new(&self.settings.simulator_settings);
-
         let timer = ScopedTimer::new("Finish Solver");
         self.finish_solver.precompute()?;
         if !self.finish_solver.can_finish(&initial_state)? {
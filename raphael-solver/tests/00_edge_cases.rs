@@ -615,3 +615,37 @@ fn solution_must_reach_target_quality() {
     "#]];
     test_with_settings(solver_settings, expected_score, expected_runtime_stats);
 }
+
+#[test]
+fn solve_from_partial_rotation() {
+    let simulator_settings = Settings {
+        max_cp: 400,
+        max_durability: 60,
+        max_progress: 2000,
+        max_quality: 1000,
+        base_progress: 100,
+        base_quality: 100,
+        job_level: 90,
+        allowed_actions: ActionMask::regular(),
+        adversarial: false,
+        backload_progress: false,
+        stellar_steady_hand_charges: 0,
+    };
+    let solver_settings = SolverSettings {
+        simulator_settings,
+        allow_non_max_quality_solutions: true,
+    };
+    let partial_rotation = [Action::Reflect, Action::Manipulation];
+    let initial_state =
+        SimulationState::from_macro(&simulator_settings, &partial_rotation).unwrap();
+    let mut solver = MacroSolver::new(
+        solver_settings,
+        Box::new(|_| {}),
+        Box::new(|_| {}),
+        AtomicFlag::new(),
+    );
+    let actions = solver.solve_from(initial_state).unwrap();
+    let rotation = [partial_rotation.as_slice(), actions.as_slice()].concat();
+    let final_state = SimulationState::from_macro(&simulator_settings, &rotation).unwrap();
+    assert!(final_state.progress >= simulator_settings.max_progress);
+}
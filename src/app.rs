@@ -7,7 +7,9 @@ use raphael_translations::{t, t_format};
 use egui::{Align, CursorIcon, Id, Layout, TextStyle};
 use raphael_data::{Locale, action_name, get_job_name};
 
-use raphael_sim::{Action, ActionImpl, HeartAndSoul, Manipulation, QuickInnovation};
+use raphael_sim::{
+    Action, ActionError, ActionImpl, HeartAndSoul, Manipulation, QuickInnovation, SimulationState,
+};
 
 use crate::config::{QualitySource, QualityTarget};
 use crate::context::AppContext;
@@ -35,14 +37,20 @@ pub struct MacroSolverApp {
 
     stats_edit_window_open: bool,
     saved_rotations_window_open: bool,
+    rotation_editor_window_open: bool,
+    rotation_comparison_window_open: bool,
     missing_stats_error_window_open: bool,
 
     actions: Vec<Action>,
+    /// Actions the solver continues from instead of solving the rotation from the start.
+    partial_rotation: Vec<Action>,
     solver_pending: bool,
     solver_progress: usize,
     start_time: web_time::Instant,
     duration: web_time::Duration,
     solver_error: Option<SolverException>,
+    /// Step and error of the first action of `partial_rotation` that cannot be used.
+    partial_rotation_error: Option<(usize, ActionError)>,
 
     solver_events: Arc<Mutex<VecDeque<SolverEvent>>>,
     solver_interrupt: raphael_solver::AtomicFlag,
@@ -51,7 +59,7 @@ pub struct MacroSolverApp {
 impl MacroSolverApp {
     /// Called once before the first frame.
     pub fn new(cc: &eframe::CreationContext<'_>) -> Self {
-        let app_context = AppContext::new(cc);
+        let mut app_context = AppContext::new(cc);
         cc.egui_ctx
             .set_zoom_factor(f32::from(app_context.app_config.zoom_percentage) * 0.01);
 
@@ -70,6 +78,18 @@ impl MacroSolverApp {
         #[cfg(not(target_arch = "wasm32"))]
         crate::update::check_for_update();
 
+        #[cfg(target_arch = "wasm32")]
+        let shared_rotation = rotation_from_link(cc);
+        #[cfg(not(target_arch = "wasm32"))]
+        let shared_rotation: Option<Rotation> = None;
+        // Open the saved rotations so that the shared rotation can be loaded.
+        let saved_rotations_window_open = shared_rotation.is_some();
+        if let Some(rotation) = shared_rotation {
+            app_context
+                .saved_rotations_data
+                .add_pinned_rotation(rotation);
+        }
+
         Self {
             app_context,
 
@@ -77,15 +97,19 @@ impl MacroSolverApp {
             dev_panel_state: DevPanelState::default(),
 
             stats_edit_window_open: false,
-            saved_rotations_window_open: false,
+            saved_rotations_window_open,
+            rotation_editor_window_open: false,
+            rotation_comparison_window_open: false,
             missing_stats_error_window_open: false,
 
             actions: Vec::new(),
+            partial_rotation: Vec::new(),
             solver_pending: false,
             solver_progress: 0,
             start_time: web_time::Instant::now(),
             duration: web_time::Duration::ZERO,
             solver_error: None,
+            partial_rotation_error: None,
 
             solver_events: Arc::new(Mutex::new(VecDeque::new())),
             solver_interrupt: raphael_solver::AtomicFlag::new(),
@@ -102,6 +126,16 @@ impl eframe::App for MacroSolverApp {
 
         self.process_solver_events();
 
+        // Rotations files can be dropped onto the window even if the saved rotations are closed.
+        if import_dropped_files(
+            ui.ctx(),
+            locale,
+            &self.app_context.saved_rotations_config,
+            &mut self.app_context.saved_rotations_data,
+        ) {
+            self.saved_rotations_window_open = true;
+        }
+
         #[cfg(not(target_arch = "wasm32"))]
         crate::update::show_dialogues(ui, locale);
 
@@ -138,7 +172,8 @@ impl eframe::App for MacroSolverApp {
                         ui.label(egui::RichText::new(t!(locale, "No solution")).strong());
                         ui.separator();
                         ui.label(t!(locale, "Cannot complete synthesis."));
-                        self.actions.clear();
+                        // Keep the actions that were entered by hand.
+                        self.actions.clone_from(&self.partial_rotation);
                         if self.app_context.solver_config.must_reach_target_quality
                             && self.app_context.game_settings().max_quality != 0
                         {
@@ -176,6 +211,28 @@ impl eframe::App for MacroSolverApp {
                 ui.vertical_centered_justified(|ui| {
                     if ui.button(t!(locale, "Close")).clicked() {
                         self.solver_error = None;
+                        self.partial_rotation.clear();
+                    }
+                });
+            });
+        }
+
+        if let Some((step, error)) = self.partial_rotation_error {
+            egui::Modal::new(egui::Id::new("partial_rotation_error")).show(ui, |ui| {
+                ui.style_mut().spacing.item_spacing = egui::vec2(8.0, 3.0);
+                ui.set_width(480.0f32.min(ui.content_rect().width() - 32.0));
+                ui.label(egui::RichText::new(t!(locale, "Cannot continue the rotation")).strong());
+                ui.separator();
+                ui.label(t_format!(
+                    locale,
+                    "Step {step} cannot be used: {error}",
+                    step = step + 1,
+                    error = action_error_text(error, locale)
+                ));
+                ui.separator();
+                ui.vertical_centered_justified(|ui| {
+                    if ui.button(t!(locale, "Close")).clicked() {
+                        self.partial_rotation_error = None;
                     }
                 });
             });
@@ -413,6 +470,45 @@ impl eframe::App for MacroSolverApp {
                 &mut self.actions,
             ));
         });
+
+        let mut resolve_from = None;
+        egui::Window::new(
+            egui::RichText::new(t!(locale, "Edit rotation"))
+                .strong()
+                .text_style(TextStyle::Body),
+        )
+        .id(egui::Id::new("ROTATION_EDITOR"))
+        .open(&mut self.rotation_editor_window_open)
+        .collapsible(false)
+        .default_size((400.0, 400.0))
+        .show(ui, |ui| {
+            ui.style_mut().spacing.item_spacing = egui::vec2(8.0, 3.0);
+            ui.add_enabled(
+                !self.solver_pending,
+                RotationEditor::new(&self.app_context, &mut self.actions, &mut resolve_from),
+            );
+        });
+
+        egui::Window::new(
+            egui::RichText::new(t!(locale, "Compare rotations"))
+                .strong()
+                .text_style(TextStyle::Body),
+        )
+        .id(egui::Id::new("ROTATION_COMPARISON"))
+        .open(&mut self.rotation_comparison_window_open)
+        .collapsible(false)
+        .default_size((600.0, 400.0))
+        .show(ui, |ui| {
+            ui.style_mut().spacing.item_spacing = egui::vec2(8.0, 3.0);
+            ui.add(RotationComparison::new(&self.app_context, &self.actions));
+        });
+
+        if let Some(step_count) = resolve_from {
+            self.partial_rotation = self.actions[..step_count].to_vec();
+            ui.ctx().data_mut(|data| {
+                data.insert_temp(Id::new("SOLVE_INITIATED"), true);
+            });
+        }
     }
 
     fn save(&mut self, storage: &mut dyn eframe::Storage) {
@@ -436,14 +532,19 @@ impl MacroSolverApp {
                     self.duration = self.start_time.elapsed();
                     self.solver_pending = false;
                     self.solver_interrupt.clear();
-                    if exception.is_none() {
-                        let new_rotation = Rotation::new(&self.app_context, self.actions.clone());
-                        self.app_context.saved_rotations_data.add_solved_rotation(
-                            new_rotation,
-                            &self.app_context.saved_rotations_config,
-                        );
-                    } else {
-                        self.solver_error = exception;
+                    match exception {
+                        // Rotations that were started by hand are not necessarily optimal,
+                        // so they are not added to the solve history.
+                        None if self.partial_rotation.is_empty() => {
+                            let new_rotation =
+                                Rotation::new(&self.app_context, self.actions.clone());
+                            self.app_context.saved_rotations_data.add_solved_rotation(
+                                new_rotation,
+                                &self.app_context.saved_rotations_config,
+                            );
+                        }
+                        None => self.partial_rotation.clear(),
+                        Some(_) => self.solver_error = exception,
                     }
                 }
             }
@@ -587,6 +688,22 @@ impl MacroSolverApp {
                         self.saved_rotations_window_open = true;
                     }
                     ui.add_space(-5.0);
+                    if ui
+                        .button("✏")
+                        .on_hover_text(t!(locale, "Edit rotation"))
+                        .clicked()
+                    {
+                        self.rotation_editor_window_open = true;
+                    }
+                    ui.add_space(-5.0);
+                    if ui
+                        .button("⚖")
+                        .on_hover_text(t!(locale, "Compare rotations"))
+                        .clicked()
+                    {
+                        self.rotation_comparison_window_open = true;
+                    }
+                    ui.add_space(-5.0);
                     ui.vertical_centered_justified(|ui| {
                         let text_color = ui.global_style().visuals.selection.stroke.color;
                         let text = egui::RichText::new(t!(locale, "Solve")).color(text_color);
@@ -600,6 +717,7 @@ impl MacroSolverApp {
                             egui::Button::new(text).fill(fill_color),
                         );
                         if button.clicked() {
+                            self.partial_rotation.clear();
                             ui.ctx().data_mut(|data| {
                                 data.insert_temp(id, true);
                             });
@@ -788,6 +906,32 @@ impl MacroSolverApp {
         }
         if !has_hq_ingredient {
             ui.label(t!(locale, "None"));
+        } else if !self.actions.is_empty() && !self.solver_pending {
+            let response = ui
+                .button(t!(locale, "Use fewest HQ materials"))
+                .on_hover_text(t!(
+                    locale,
+                    "Use the fewest HQ materials with which the current rotation still reaches the target quality."
+                ));
+            if response.clicked() {
+                let game_settings = self.app_context.game_settings();
+                let target_quality = self
+                    .app_context
+                    .solver_config
+                    .quality_target
+                    .get_target(game_settings.max_quality);
+                let (state, _) =
+                    SimulationState::from_macro_continue_on_error(&game_settings, &self.actions);
+                if let Some(hq_materials) = raphael_data::optimize_hq_ingredients(
+                    *self.app_context.active_stats(),
+                    *self.app_context.recipe_config.recipe(),
+                    target_quality.saturating_sub(state.quality),
+                    [1; 6],
+                ) {
+                    self.app_context.recipe_config.quality_source =
+                        QualitySource::HqMaterialList(hq_materials);
+                }
+            }
         }
         ui.separator();
 
@@ -1001,7 +1145,7 @@ impl MacroSolverApp {
         self.solver_pending = true;
         self.solver_interrupt.clear();
 
-        let mut game_settings = self.app_context.game_settings();
+        let game_settings = self.app_context.game_settings();
         let initial_quality = self.app_context.initial_quality();
         ctx.data_mut(|data| {
             data.insert_temp(
@@ -1014,10 +1158,11 @@ impl MacroSolverApp {
             );
         });
 
-        if self
-            .app_context
-            .saved_rotations_config
-            .load_from_saved_rotations
+        if self.partial_rotation.is_empty()
+            && self
+                .app_context
+                .saved_rotations_config
+                .load_from_saved_rotations
             && let Some(actions) = self.app_context.saved_rotations_data.find_solved_rotation(
                 &game_settings,
                 initial_quality,
@@ -1029,13 +1174,19 @@ impl MacroSolverApp {
             solver_events.push_back(SolverEvent::LoadedFromHistory());
             solver_events.push_back(SolverEvent::Finished(None));
         } else {
-            let target_quality = self
-                .app_context
-                .solver_config
-                .quality_target
-                .get_target(game_settings.max_quality);
-            game_settings.max_quality = target_quality.saturating_sub(initial_quality);
-            self.actions = Vec::new();
+            let game_settings = self.app_context.solver_game_settings();
+            let (initial_state, errors) = SimulationState::from_macro_continue_on_error(
+                &game_settings,
+                &self.partial_rotation,
+            );
+            if let Some(step) = errors.iter().position(Result::is_err) {
+                // Keep the current rotation so that it can be fixed in the rotation editor.
+                self.partial_rotation_error = errors[step].err().map(|error| (step, error));
+                self.partial_rotation.clear();
+                self.solver_pending = false;
+                return;
+            }
+            self.actions = self.partial_rotation.clone();
             self.solver_progress = 0;
             self.start_time = web_time::Instant::now();
             let solver_settings = raphael_solver::SolverSettings {
@@ -1045,8 +1196,22 @@ impl MacroSolverApp {
                     .solver_config
                     .must_reach_target_quality,
             };
+            if initial_state.is_final(&game_settings) {
+                // There is nothing left to solve, the rotation is only a solution if it reaches the required quality.
+                let is_solution = initial_state.progress >= game_settings.max_progress
+                    && (solver_settings.allow_non_max_quality_solutions
+                        || initial_state.quality >= game_settings.max_quality);
+                let mut solver_events = self.solver_events.lock().unwrap();
+                solver_events.push_back(SolverEvent::Actions(self.partial_rotation.clone()));
+                solver_events.push_back(SolverEvent::Finished(
+                    (!is_solution).then_some(SolverException::NoSolution),
+                ));
+                return;
+            }
             spawn_solver(
                 solver_settings,
+                initial_state,
+                self.partial_rotation.clone(),
                 self.solver_events.clone(),
                 self.solver_interrupt.clone(),
             );
@@ -1127,6 +1292,27 @@ fn load_font_dyn(ctx: &egui::Context, font_name: &str, uri: &str) {
     }
 }
 
+/// Reads the rotation of a rotation link, e.g. `https://.../#rotation=1.eJyr...`.
+/// Also stores the URL of the page so that rotations can be shared as links.
+#[cfg(target_arch = "wasm32")]
+fn rotation_from_link(cc: &eframe::CreationContext<'_>) -> Option<Rotation> {
+    let location = &cc.integration_info.web_info.location;
+    let link_base = location
+        .url
+        .split('#')
+        .next()
+        .unwrap_or_default()
+        .to_owned();
+    cc.egui_ctx
+        .data_mut(|data| data.insert_temp(Id::new(ROTATION_LINK_BASE_ID), link_base));
+    if !location.hash.contains(ROTATION_CODE_URL_KEY) {
+        return None;
+    }
+    Rotation::from_code(&location.hash)
+        .inspect_err(|error| log::warn!("Unable to open rotation link: {error}"))
+        .ok()
+}
+
 fn load_fonts(ctx: &egui::Context) {
     use egui::epaint::text::{FontInsert, FontPriority, InsertFontFamily};
     ctx.add_font(FontInsert::new(
@@ -1215,14 +1401,19 @@ fn load_fonts(ctx: &egui::Context) {
     ));
 }
 
+/// Solves the rotation starting from `initial_state`, which is the result of `partial_rotation`.
+/// The actions sent to `solver_events` include the actions of `partial_rotation`.
 fn spawn_solver(
     solver_settings: raphael_solver::SolverSettings,
+    initial_state: SimulationState,
+    partial_rotation: Vec<Action>,
     solver_events: Arc<Mutex<VecDeque<SolverEvent>>>,
     solver_interrupt: raphael_solver::AtomicFlag,
 ) {
     let events = solver_events.clone();
+    let prefix = partial_rotation.clone();
     let solution_callback = move |actions: &[raphael_sim::Action]| {
-        let event = SolverEvent::Actions(actions.to_vec());
+        let event = SolverEvent::Actions([prefix.as_slice(), actions].concat());
         events.lock().unwrap().push_back(event);
     };
     let events = solver_events.clone();
@@ -1238,10 +1429,12 @@ fn spawn_solver(
             Box::new(progress_callback),
             solver_interrupt,
         );
-        match macro_solver.solve() {
+        match macro_solver.solve_from(initial_state) {
             Ok(actions) => {
                 let mut solver_events = solver_events.lock().unwrap();
-                solver_events.push_back(SolverEvent::Actions(actions));
+                solver_events.push_back(SolverEvent::Actions(
+                    [partial_rotation.as_slice(), actions.as_slice()].concat(),
+                ));
                 solver_events.push_back(SolverEvent::Finished(None));
             }
             Err(exception) => solver_events
@@ -1,9 +1,6 @@
 use std::num::NonZeroUsize;
 
-use raphael_data::{
-    CrafterStats, CustomRecipeOverrides, Ingredient, LEVEL_ADJUST_TABLE, Locale,
-    RECIPE_TO_STELLAR_MISSION_LINKS, Recipe, STELLAR_MISSIONS,
-};
+use raphael_data::{CrafterStats, CustomRecipeOverrides, Ingredient, Locale, Recipe};
 use raphael_sim::Settings;
 use raphael_translations::t;
 use serde::{Deserialize, Serialize};
@@ -49,7 +46,8 @@ impl RecipeSource {
 
                 if data.max_level_scaling != 0 {
                     let job_level = std::cmp::min(data.max_level_scaling, job_level);
-                    data.recipe_level = LEVEL_ADJUST_TABLE[job_level as usize];
+                    data.recipe_level =
+                        raphael_data::game_data().level_adjust_table()[job_level as usize];
                 }
 
                 data.req_craftsmanship = 0;
@@ -75,13 +73,12 @@ impl RecipeSource {
 
     pub fn max_stellar_steady_hand_charges(&self) -> u8 {
         match self {
-            Self::Normal { id, .. } => {
-                RECIPE_TO_STELLAR_MISSION_LINKS
-                    .get(*id)
-                    .map_or(0, |stellar_mission_id| {
-                        STELLAR_MISSIONS[*stellar_mission_id].stellar_steady_hand_charges
-                    })
-            }
+            Self::Normal { id, .. } => raphael_data::game_data()
+                .recipe_stellar_mission(*id)
+                .and_then(raphael_data::get_stellar_mission)
+                .map_or(0, |stellar_mission| {
+                    stellar_mission.stellar_steady_hand_charges
+                }),
             Self::Custom { .. } => 3,
         }
     }
@@ -96,8 +93,8 @@ pub struct RecipeConfiguration {
 impl Default for RecipeConfiguration {
     fn default() -> Self {
         Self {
-            recipe_source: raphael_data::RECIPES
-                .entries()
+            recipe_source: raphael_data::game_data()
+                .recipes()
                 .map(|(id, data)| RecipeSource::Normal { id, data: *data })
                 .next()
                 .unwrap(),
@@ -1,4 +1,4 @@
-use raphael_data::{Consumable, CrafterStats, Locale};
+use raphael_data::{Consumable, CrafterStats, Gearset, Locale};
 use serde::{Deserialize, Serialize, de::DeserializeOwned};
 
 use crate::{
@@ -31,6 +31,8 @@ pub struct AppContext {
     pub selected_food: Option<Consumable>,
     pub selected_potion: Option<Consumable>,
     pub crafter_config: CrafterConfig,
+    /// Gear set of each job, used to calculate the crafter stats of the job.
+    pub gearsets: [Option<Gearset>; 8],
     pub solver_config: SolverConfig,
     pub macro_view_config: MacroViewConfig,
     pub saved_rotations_config: SavedRotationsConfig,
@@ -46,6 +48,7 @@ impl AppContext {
             selected_food: load(cc, "SELECTED_FOOD", None),
             selected_potion: load(cc, "SELECTED_POTION", None),
             crafter_config: load(cc, "CRAFTER_CONFIG", CrafterConfig::default()),
+            gearsets: load(cc, "GEARSETS", Default::default()),
             solver_config: load(cc, "SOLVER_CONFIG", SolverConfig::default()),
             macro_view_config: load(cc, "MACRO_VIEW_CONFIG", MacroViewConfig::default()),
             saved_rotations_config: load(
@@ -64,6 +67,7 @@ impl AppContext {
         eframe::set_value(storage, "SELECTED_FOOD", &self.selected_food);
         eframe::set_value(storage, "SELECTED_POTION", &self.selected_potion);
         eframe::set_value(storage, "CRAFTER_CONFIG", &self.crafter_config);
+        eframe::set_value(storage, "GEARSETS", &self.gearsets);
         eframe::set_value(storage, "SOLVER_CONFIG", &self.solver_config);
         eframe::set_value(storage, "MACRO_VIEW_CONFIG", &self.macro_view_config);
         eframe::set_value(
@@ -116,6 +120,17 @@ impl AppContext {
         game_settings
     }
 
+    /// The settings passed to the solver, where `max_quality` is the quality that is still needed to reach the target quality.
+    pub fn solver_game_settings(&self) -> raphael_sim::Settings {
+        let mut game_settings = self.game_settings();
+        let target_quality = self
+            .solver_config
+            .quality_target
+            .get_target(game_settings.max_quality);
+        game_settings.max_quality = target_quality.saturating_sub(self.initial_quality());
+        game_settings
+    }
+
     pub fn selected_job_mut(&mut self) -> &mut u8 {
         &mut self.crafter_config.selected_job
     }
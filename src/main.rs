@@ -46,9 +46,32 @@ fn init_logging() {
         .init();
 }
 
+/// Replaces the built-in game data with the data pack at `RAPHAEL_DATA_PACK`, if set.
+/// The built-in game data is used if the data pack cannot be loaded.
+#[cfg(not(target_arch = "wasm32"))]
+fn load_data_pack() {
+    let Ok(path) = std::env::var("RAPHAEL_DATA_PACK") else {
+        return;
+    };
+    let data_pack = std::fs::File::open(&path)
+        .map_err(|error| error.to_string())
+        .and_then(|file| {
+            raphael_data::DataPack::from_json_reader(std::io::BufReader::new(file))
+                .map_err(|error| error.to_string())
+        })
+        .and_then(|data_pack| {
+            raphael_data::load_data_pack(data_pack).map_err(|error| error.to_string())
+        });
+    match data_pack {
+        Ok(()) => log::info!("Using data pack {path}"),
+        Err(error) => log::error!("Unable to load data pack {path}: {error}"),
+    }
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 fn main() -> eframe::Result<()> {
     init_logging();
+    load_data_pack();
 
     let present_mode = std::env::var("RAPHAEL_PRESENT_MODE").map_or(
         eframe::wgpu::PresentMode::default(),
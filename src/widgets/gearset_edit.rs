@@ -0,0 +1,202 @@
+use egui::Widget;
+use raphael_data::{CrafterStats, GearPiece, Gearset, Locale, find_gear, game_data, get_item_name};
+use raphael_translations::t;
+
+use crate::widgets::{GameDataNameLabel, NameSource, util::add_sized_labeled_widget};
+
+/// Calculates the stats of a job from its equipped items and materia.
+/// The stats are recalculated whenever the gear set is changed.
+pub struct GearsetEdit<'a> {
+    locale: Locale,
+    job_id: u8,
+    gearset: &'a mut Option<Gearset>,
+    crafter_stats: &'a mut CrafterStats,
+}
+
+impl<'a> GearsetEdit<'a> {
+    pub fn new(
+        locale: Locale,
+        job_id: u8,
+        gearset: &'a mut Option<Gearset>,
+        crafter_stats: &'a mut CrafterStats,
+    ) -> Self {
+        Self {
+            locale,
+            job_id,
+            gearset,
+            crafter_stats,
+        }
+    }
+}
+
+fn item_name(item_id: u32, hq: bool, locale: Locale) -> String {
+    get_item_name(item_id, hq, locale).unwrap_or_else(|| t!(locale, "Unknown item").to_owned())
+}
+
+fn materia_select(
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    materia: &mut Vec<u32>,
+    slot: usize,
+    locale: Locale,
+) -> bool {
+    let mut changed = false;
+    let selected_text = materia.get(slot).map_or_else(
+        || t!(locale, "No materia").to_owned(),
+        |item_id| item_name(*item_id, false, locale),
+    );
+    egui::ComboBox::from_id_salt(id.with(slot))
+        .selected_text(selected_text)
+        .show_ui(ui, |ui| {
+            if ui
+                .selectable_label(slot >= materia.len(), t!(locale, "No materia"))
+                .clicked()
+                && slot < materia.len()
+            {
+                materia.remove(slot);
+                changed = true;
+            }
+            for entry in game_data().materia() {
+                let selected = materia.get(slot) == Some(&entry.item_id);
+                if ui
+                    .selectable_label(selected, item_name(entry.item_id, false, locale))
+                    .clicked()
+                    && !selected
+                {
+                    match materia.get_mut(slot) {
+                        Some(item_id) => *item_id = entry.item_id,
+                        None => materia.push(entry.item_id),
+                    }
+                    changed = true;
+                }
+            }
+        });
+    changed
+}
+
+impl Widget for GearsetEdit<'_> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let locale = self.locale;
+        let id = egui::Id::new(("GEARSET_EDIT", self.job_id));
+        egui::CollapsingHeader::new(t!(locale, "Gear set"))
+            .id_salt(id)
+            .show(ui, |ui| {
+                let Some(gearset) = self.gearset else {
+                    if ui.button(t!(locale, "Calculate stats from gear")).clicked() {
+                        *self.gearset = Some(Gearset::default());
+                    }
+                    return;
+                };
+
+                let previous_base_stats = gearset.base_stats;
+                ui.label(t!(locale, "Stats without gear"));
+                ui.horizontal_wrapped(|ui| {
+                    let drag_value_size = ui.spacing().interact_size;
+                    let base_stats = &mut gearset.base_stats;
+                    add_sized_labeled_widget(
+                        ui,
+                        t!(locale, "Craftsmanship"),
+                        drag_value_size,
+                        egui::DragValue::new(&mut base_stats.craftsmanship).range(0..=9999),
+                    );
+                    add_sized_labeled_widget(
+                        ui,
+                        t!(locale, "Control"),
+                        drag_value_size,
+                        egui::DragValue::new(&mut base_stats.control).range(0..=9999),
+                    );
+                    add_sized_labeled_widget(
+                        ui,
+                        t!(locale, "CP"),
+                        drag_value_size,
+                        egui::DragValue::new(&mut base_stats.cp).range(0..=999),
+                    );
+                });
+                let mut changed = gearset.base_stats != previous_base_stats;
+
+                let mut removed_piece = None;
+                for (index, piece) in gearset.pieces.iter_mut().enumerate() {
+                    ui.separator();
+                    ui.horizontal_wrapped(|ui| {
+                        if ui.button("🗑").clicked() {
+                            removed_piece = Some(index);
+                        }
+                        ui.add(GameDataNameLabel::new(
+                            NameSource::Item {
+                                item_id: piece.item_id,
+                                hq: piece.hq,
+                            },
+                            locale,
+                        ));
+                        if let Some(gear) = find_gear(piece.item_id)
+                            && gear.hq_stats != gear.stats
+                        {
+                            changed |= ui.checkbox(&mut piece.hq, t!(locale, "HQ")).changed();
+                        }
+                    });
+                    let max_materia = find_gear(piece.item_id)
+                        .map_or(piece.materia.len(), |gear| usize::from(gear.max_materia()));
+                    ui.horizontal_wrapped(|ui| {
+                        // One additional select to meld the next materia.
+                        let slots = std::cmp::min(piece.materia.len() + 1, max_materia);
+                        for slot in 0..slots {
+                            changed |= materia_select(
+                                ui,
+                                id.with(index),
+                                &mut piece.materia,
+                                slot,
+                                locale,
+                            );
+                        }
+                    });
+                }
+                if let Some(index) = removed_piece {
+                    gearset.pieces.remove(index);
+                    changed = true;
+                }
+
+                ui.separator();
+                let mut remove_gearset = false;
+                ui.horizontal_wrapped(|ui| {
+                    egui::ComboBox::from_id_salt(id.with("ADD_GEAR"))
+                        .selected_text(t!(locale, "Add gear"))
+                        .show_ui(ui, |ui| {
+                            for gear in game_data()
+                                .gear()
+                                .iter()
+                                .filter(|gear| gear.equip_level <= self.crafter_stats.level)
+                            {
+                                if ui
+                                    .selectable_label(false, item_name(gear.item_id, false, locale))
+                                    .clicked()
+                                {
+                                    gearset.pieces.push(GearPiece {
+                                        item_id: gear.item_id,
+                                        hq: gear.hq_stats != gear.stats,
+                                        materia: Vec::new(),
+                                    });
+                                    changed = true;
+                                }
+                            }
+                        });
+                    remove_gearset = ui.button(t!(locale, "Remove gear set")).clicked();
+                });
+                if remove_gearset {
+                    *self.gearset = None;
+                    return;
+                }
+
+                match gearset.crafter_stats(*self.crafter_stats) {
+                    Ok(crafter_stats) => {
+                        if changed {
+                            *self.crafter_stats = crafter_stats;
+                        }
+                    }
+                    Err(error) => {
+                        ui.colored_label(ui.visuals().warn_fg_color, error.to_string());
+                    }
+                }
+            })
+            .header_response
+    }
+}
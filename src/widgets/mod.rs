@@ -7,6 +7,12 @@ pub use macro_view::{MacroView, MacroViewConfig};
 mod simulator;
 pub use simulator::Simulator;
 
+mod rotation_editor;
+pub use rotation_editor::{RotationEditor, action_error_text};
+
+mod rotation_comparison;
+pub use rotation_comparison::RotationComparison;
+
 mod recipe_select;
 pub use recipe_select::RecipeSelect;
 
@@ -19,6 +25,9 @@ pub use potion_select::PotionSelect;
 mod stats_edit;
 pub use stats_edit::StatsEdit;
 
+mod gearset_edit;
+pub use gearset_edit::GearsetEdit;
+
 mod help_text;
 pub use help_text::HelpText;
 
@@ -28,9 +37,13 @@ pub use drop_down::DropDown;
 mod game_data_name_label;
 pub use game_data_name_label::{GameDataNameLabel, NameSource};
 
+mod rotation_library;
+mod rotation_validation;
 mod saved_rotations;
+#[cfg(target_arch = "wasm32")]
+pub use saved_rotations::{ROTATION_CODE_URL_KEY, ROTATION_LINK_BASE_ID};
 pub use saved_rotations::{
-    Rotation, SavedRotationsConfig, SavedRotationsData, SavedRotationsWidget,
+    Rotation, SavedRotationsConfig, SavedRotationsData, SavedRotationsWidget, import_dropped_files,
 };
 
 #[cfg(any(debug_assertions, feature = "dev-panel"))]
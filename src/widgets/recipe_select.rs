@@ -4,8 +4,8 @@ use egui::{
 };
 use egui_extras::Column;
 use raphael_data::{
-    Consumable, Locale, RLVLS, Recipe, find_recipes, find_stellar_missions, get_game_settings,
-    get_job_name,
+    Consumable, Locale, Recipe, RecipeFilter, RecipeSortOrder, find_recipes_filtered,
+    find_stellar_missions, game_data, get_game_settings, get_job_name,
 };
 use raphael_translations::{t, t_format};
 
@@ -39,9 +39,14 @@ impl SearchDomain {
 #[derive(Default)]
 struct RecipeFinder {}
 
-impl ComputerMut<(&str, Locale), Vec<raphael_data::RecipeSearchEntry>> for RecipeFinder {
-    fn compute(&mut self, (text, locale): (&str, Locale)) -> Vec<raphael_data::RecipeSearchEntry> {
-        find_recipes(text, locale).collect::<Vec<_>>()
+impl ComputerMut<(&str, Locale, RecipeFilter), Vec<raphael_data::RecipeSearchEntry>>
+    for RecipeFinder
+{
+    fn compute(
+        &mut self,
+        (text, locale, filter): (&str, Locale, RecipeFilter),
+    ) -> Vec<raphael_data::RecipeSearchEntry> {
+        find_recipes_filtered(text, locale, &filter)
     }
 }
 
@@ -112,10 +117,16 @@ impl<'a> RecipeSelect<'a> {
         let locale = self.locale;
         let mut search_text = String::new();
         let mut search_domain = SearchDomain::default();
+        let mut recipe_filter = RecipeFilter::default();
         ui.ctx().data_mut(|data| {
             if let Some(text) = data.get_persisted::<String>(Id::new("RECIPE_SEARCH_TEXT")) {
                 search_text = text;
             }
+            if let Some(filter) =
+                data.get_persisted::<RecipeFilter>(Id::new("RECIPE_SEARCH_FILTER"))
+            {
+                recipe_filter = filter;
+            }
             if let Some(domain) =
                 data.get_persisted::<SearchDomain>(Id::new("RECIPE_SEARCH_DOMAIN"))
             {
@@ -140,6 +151,10 @@ impl<'a> RecipeSelect<'a> {
             }
         });
 
+        if search_domain == SearchDomain::Recipes {
+            self.draw_recipe_filter(ui, &mut recipe_filter);
+        }
+
         ui.separator();
 
         match search_domain {
@@ -147,7 +162,8 @@ impl<'a> RecipeSelect<'a> {
                 let search_result = ui.ctx().memory_mut(|mem| {
                     mem.caches
                         .cache::<RecipeSearchCache<'_>>()
-                        .get((&search_text, locale)).clone()
+                        .get((&search_text, locale, recipe_filter))
+                        .clone()
                 });
                 self.draw_recipe_select_table(ui, search_result);
             }
@@ -155,7 +171,8 @@ impl<'a> RecipeSelect<'a> {
                 let search_result = ui.ctx().memory_mut(|mem| {
                     mem.caches
                         .cache::<StellarMissionSearchCache<'_>>()
-                        .get((&search_text, locale)).clone()
+                        .get((&search_text, locale))
+                        .clone()
                 });
                 self.draw_mission_recipe_select(ui, search_result);
             }
@@ -164,9 +181,149 @@ impl<'a> RecipeSelect<'a> {
         ui.ctx().data_mut(|data| {
             data.insert_persisted(Id::new("RECIPE_SEARCH_TEXT"), search_text);
             data.insert_persisted(Id::new("RECIPE_SEARCH_DOMAIN"), search_domain);
+            data.insert_persisted(Id::new("RECIPE_SEARCH_FILTER"), recipe_filter);
         });
     }
 
+    fn draw_recipe_filter(&self, ui: &mut egui::Ui, filter: &mut RecipeFilter) {
+        let locale = self.locale;
+        let any_or = |value: Option<bool>| match value {
+            None => t!(locale, "Any"),
+            Some(true) => t!(locale, "Yes"),
+            Some(false) => t!(locale, "No"),
+        };
+        // Zero is used to represent "no filter" for numeric filters.
+        let any_formatter = move |value: f64, _| {
+            if value == 0.0 {
+                t!(locale, "Any").to_owned()
+            } else {
+                value.to_string()
+            }
+        };
+
+        egui::CollapsingHeader::new(t!(locale, "Filters"))
+            .id_salt("RECIPE_SEARCH_FILTERS")
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(t!(locale, "Job"));
+                    ui.add(DropDown::new(
+                        "RECIPE_SEARCH_FILTER_JOB",
+                        &mut filter.job_id,
+                        [
+                            None,
+                            Some(0),
+                            Some(1),
+                            Some(2),
+                            Some(3),
+                            Some(4),
+                            Some(5),
+                            Some(6),
+                            Some(7),
+                        ],
+                        |job_id: Option<u8>| match job_id {
+                            Some(job_id) => get_job_name(job_id, locale),
+                            None => t!(locale, "Any"),
+                        },
+                    ));
+                    ui.label(t!(locale, "Job level"));
+                    let mut job_level = filter.job_level.unwrap_or(0);
+                    ui.add(
+                        egui::DragValue::new(&mut job_level)
+                            .range(0..=100)
+                            .custom_formatter(any_formatter),
+                    );
+                    filter.job_level = Some(job_level).filter(|job_level| *job_level != 0);
+                });
+                ui.horizontal(|ui| {
+                    ui.label(t!(locale, "Recipe Level:"));
+                    let mut min_recipe_level = filter.min_recipe_level.unwrap_or(0);
+                    let mut max_recipe_level = filter.max_recipe_level.unwrap_or(0);
+                    ui.add(
+                        egui::DragValue::new(&mut min_recipe_level)
+                            .range(0..=game_data().rlvls().len() - 1)
+                            .custom_formatter(any_formatter),
+                    );
+                    ui.label("-");
+                    ui.add(
+                        egui::DragValue::new(&mut max_recipe_level)
+                            .range(0..=game_data().rlvls().len() - 1)
+                            .custom_formatter(any_formatter),
+                    );
+                    filter.min_recipe_level = Some(min_recipe_level).filter(|rlvl| *rlvl != 0);
+                    filter.max_recipe_level = Some(max_recipe_level).filter(|rlvl| *rlvl != 0);
+                });
+                ui.horizontal(|ui| {
+                    ui.label(t!(locale, "Expert recipe"));
+                    ui.add(DropDown::new(
+                        "RECIPE_SEARCH_FILTER_EXPERT",
+                        &mut filter.is_expert,
+                        [None, Some(true), Some(false)],
+                        any_or,
+                    ));
+                    ui.label(t!(locale, "Level scaling"));
+                    ui.add(DropDown::new(
+                        "RECIPE_SEARCH_FILTER_LEVEL_SCALING",
+                        &mut filter.has_max_level_scaling,
+                        [None, Some(true), Some(false)],
+                        any_or,
+                    ));
+                    ui.label(t!(locale, "Missions"));
+                    ui.add(DropDown::new(
+                        "RECIPE_SEARCH_FILTER_STELLAR_MISSION",
+                        &mut filter.is_stellar_mission_recipe,
+                        [None, Some(true), Some(false)],
+                        any_or,
+                    ));
+                });
+                ui.horizontal(|ui| {
+                    let mut meets_stat_requirements = filter.craftsmanship.is_some();
+                    ui.checkbox(
+                        &mut meets_stat_requirements,
+                        t!(locale, "Only recipes that meet the stat requirements"),
+                    );
+                    if meets_stat_requirements {
+                        let stats = self.crafter_config.active_stats();
+                        let consumables = [self.selected_food, self.selected_potion];
+                        filter.craftsmanship = Some(
+                            stats.craftsmanship
+                                + raphael_data::craftsmanship_bonus(
+                                    stats.craftsmanship,
+                                    &consumables,
+                                ),
+                        );
+                        filter.control = Some(
+                            stats.control
+                                + raphael_data::control_bonus(stats.control, &consumables),
+                        );
+                    } else {
+                        filter.craftsmanship = None;
+                        filter.control = None;
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.label(t!(locale, "Sort by"));
+                    ui.add(DropDown::new(
+                        "RECIPE_SEARCH_FILTER_SORT_ORDER",
+                        &mut filter.sort_order,
+                        [
+                            RecipeSortOrder::Relevance,
+                            RecipeSortOrder::RecipeLevelAscending,
+                            RecipeSortOrder::RecipeLevelDescending,
+                        ],
+                        |sort_order: RecipeSortOrder| match sort_order {
+                            RecipeSortOrder::Relevance => t!(locale, "Relevance"),
+                            RecipeSortOrder::RecipeLevelAscending => {
+                                t!(locale, "Recipe level (ascending)")
+                            }
+                            RecipeSortOrder::RecipeLevelDescending => {
+                                t!(locale, "Recipe level (descending)")
+                            }
+                        },
+                    ));
+                });
+            });
+    }
+
     fn draw_recipe_select_table(
         &mut self,
         ui: &mut egui::Ui,
@@ -258,7 +415,9 @@ impl<'a> RecipeSelect<'a> {
                         locale,
                     ));
                     for (index, recipe_id) in mission.recipe_ids.iter().enumerate() {
-                        let recipe = &raphael_data::RECIPES[*recipe_id];
+                        let Some(recipe) = raphael_data::get_recipe(*recipe_id) else {
+                            continue;
+                        };
                         const DARKENING_COLOR_DARK_MODE: egui::Color32 =
                             egui::Color32::from_black_alpha(25);
                         const DARKENING_COLOR_LIGHT_MODE: egui::Color32 =
@@ -313,14 +472,15 @@ impl<'a> RecipeSelect<'a> {
         ui.separator();
         ui.horizontal_top(|ui| {
             ui.vertical(|ui| {
-                let mut recipe_job_level = RLVLS[recipe.recipe_level as usize].job_level;
+                let mut recipe_job_level =
+                    game_data().rlvls()[recipe.recipe_level as usize].job_level;
                 ui.horizontal(|ui| {
                     ui.label(t!(locale, "Level:"));
                     ui.add_enabled_ui(use_base_increase_overrides, |ui| {
                         ui.add(egui::DragValue::new(&mut recipe_job_level).range(1..=100));
                         if use_base_increase_overrides {
                             recipe.recipe_level =
-                                raphael_data::LEVEL_ADJUST_TABLE[recipe_job_level as usize];
+                                game_data().level_adjust_table()[recipe_job_level as usize];
                         }
                     });
                 });
@@ -329,7 +489,7 @@ impl<'a> RecipeSelect<'a> {
                         ui.label(t!(locale, "Recipe Level:"));
                         let mut rlvl_drag_value_widget =
                             egui::DragValue::new(&mut recipe.recipe_level)
-                                .range(1..=RLVLS.len() - 1);
+                                .range(1..=game_data().rlvls().len() - 1);
                         if use_base_increase_overrides && recipe_job_level >= 50 {
                             rlvl_drag_value_widget = rlvl_drag_value_widget.suffix("+");
                         }
@@ -367,7 +527,7 @@ impl<'a> RecipeSelect<'a> {
             });
             ui.separator();
             ui.vertical(|ui| {
-                let mut rlvl = RLVLS[recipe.recipe_level as usize];
+                let mut rlvl = game_data().rlvls()[recipe.recipe_level as usize];
                 ui.add_enabled_ui(!use_base_increase_overrides, |ui| {
                     ui.horizontal(|ui| {
                         ui.label(t!(locale, "Progress divider"));
@@ -0,0 +1,273 @@
+use egui_extras::Column;
+use raphael_data::{Locale, action_name};
+use raphael_sim::{Action, ActionError, Condition, Settings, SimulationState};
+use raphael_translations::t;
+
+use crate::context::AppContext;
+
+use super::{SavedRotationsData, simulator::simulate_steps, util};
+
+/// Rotations that are selected for comparison.
+#[derive(Debug, Clone, Default)]
+struct ComparisonSelection {
+    current_rotation: bool,
+    /// Unique IDs of the selected saved rotations.
+    saved_rotations: Vec<u64>,
+}
+
+struct ComparedRotation<'a> {
+    name: String,
+    actions: &'a [Action],
+    /// State after each action. Actions that cannot be used leave the state unchanged.
+    states: Vec<SimulationState>,
+    errors: Vec<Result<(), ActionError>>,
+    final_state: SimulationState,
+}
+
+impl<'a> ComparedRotation<'a> {
+    fn new(name: String, actions: &'a [Action], settings: &Settings) -> Self {
+        let conditions = vec![Condition::Normal; actions.len()];
+        let (states, errors) = simulate_steps(settings, actions, &conditions);
+        let final_state = states
+            .last()
+            .copied()
+            .unwrap_or_else(|| SimulationState::new(settings));
+        Self {
+            name,
+            actions,
+            states,
+            errors,
+            final_state,
+        }
+    }
+
+    fn state_after(&self, step_index: usize) -> SimulationState {
+        self.states
+            .get(step_index)
+            .copied()
+            .unwrap_or(self.final_state)
+    }
+}
+
+/// Compares the current rotation and saved rotations using the current settings.
+pub struct RotationComparison<'a> {
+    locale: Locale,
+    job_id: u8,
+    settings: Settings,
+    initial_quality: u16,
+    current_actions: &'a [Action],
+    saved_rotations: &'a SavedRotationsData,
+}
+
+impl<'a> RotationComparison<'a> {
+    pub fn new(app_context: &'a AppContext, current_actions: &'a [Action]) -> Self {
+        Self {
+            locale: app_context.locale,
+            job_id: app_context.crafter_config.selected_job,
+            settings: app_context.game_settings(),
+            initial_quality: app_context.initial_quality(),
+            current_actions,
+            saved_rotations: &app_context.saved_rotations_data,
+        }
+    }
+
+    fn draw_selection(&self, ui: &mut egui::Ui, selection: &mut ComparisonSelection) {
+        let locale = self.locale;
+        ui.label(egui::RichText::new(t!(locale, "Rotations")).strong());
+        egui::ScrollArea::vertical()
+            .id_salt("ROTATION_COMPARISON_SELECTION")
+            .max_height(120.0)
+            .show(ui, |ui| {
+                ui.checkbox(
+                    &mut selection.current_rotation,
+                    t!(locale, "Current rotation"),
+                );
+                for rotation in self.saved_rotations.rotations() {
+                    let mut selected = selection.saved_rotations.contains(&rotation.unique_id);
+                    let label = format!(
+                        "{} ({}, {} steps)",
+                        rotation.name,
+                        rotation.solver,
+                        rotation.actions.len()
+                    );
+                    if ui.checkbox(&mut selected, label).changed() {
+                        if selected {
+                            selection.saved_rotations.push(rotation.unique_id);
+                        } else {
+                            selection
+                                .saved_rotations
+                                .retain(|unique_id| *unique_id != rotation.unique_id);
+                        }
+                    }
+                }
+            });
+    }
+
+    fn draw_summary(&self, ui: &mut egui::Ui, rotations: &[ComparedRotation]) {
+        let locale = self.locale;
+        egui::Grid::new("ROTATION_COMPARISON_SUMMARY")
+            .striped(true)
+            .show(ui, |ui| {
+                for header in [
+                    t!(locale, "Rotation"),
+                    t!(locale, "Progress"),
+                    t!(locale, "Quality"),
+                    t!(locale, "Steps"),
+                    t!(locale, "Duration"),
+                    t!(locale, "CP left"),
+                    t!(locale, "Durability left"),
+                ] {
+                    ui.strong(header);
+                }
+                ui.end_row();
+                for rotation in rotations {
+                    let final_state = &rotation.final_state;
+                    let duration: u32 = rotation
+                        .actions
+                        .iter()
+                        .map(|action| u32::from(action.time_cost()))
+                        .sum();
+                    ui.label(&rotation.name);
+                    ui.label(format!(
+                        "{}/{}",
+                        final_state.progress, self.settings.max_progress
+                    ));
+                    ui.label(format!(
+                        "{}/{}",
+                        self.initial_quality.saturating_add(final_state.quality),
+                        self.settings.max_quality
+                    ));
+                    ui.label(rotation.actions.len().to_string());
+                    ui.label(format!("{duration}s"));
+                    ui.label(final_state.cp.to_string());
+                    ui.label(final_state.durability.to_string());
+                    ui.end_row();
+                }
+            });
+    }
+
+    fn draw_step_diff(&self, ui: &mut egui::Ui, rotations: &[ComparedRotation]) {
+        let locale = self.locale;
+        let max_steps = rotations
+            .iter()
+            .map(|rotation| rotation.actions.len())
+            .max()
+            .unwrap_or_default();
+        let line_height = ui.spacing().interact_size.y;
+        ui.label(
+            egui::RichText::new(t!(
+                locale,
+                "Progress / Quality / Durability / CP after each step. Highlighted values differ from the first rotation."
+            ))
+            .small(),
+        );
+        egui::ScrollArea::horizontal()
+            .id_salt("ROTATION_COMPARISON_STEPS")
+            .show(ui, |ui| {
+                egui_extras::TableBuilder::new(ui)
+                    .id_salt("ROTATION_COMPARISON_TABLE")
+                    .striped(true)
+                    .column(Column::auto())
+                    .columns(Column::auto().at_least(160.0), rotations.len())
+                    .max_scroll_height(400.0)
+                    .header(line_height, |mut header| {
+                        header.col(|ui| {
+                            ui.strong("#");
+                        });
+                        for rotation in rotations {
+                            header.col(|ui| {
+                                ui.strong(&rotation.name);
+                            });
+                        }
+                    })
+                    .body(|body| {
+                        body.rows(line_height, max_steps, |mut row| {
+                            let step_index = row.index();
+                            row.col(|ui| {
+                                ui.label((step_index + 1).to_string());
+                            });
+                            let reference_state = rotations[0].state_after(step_index);
+                            for rotation in rotations {
+                                row.col(|ui| {
+                                    let Some(action) = rotation.actions.get(step_index) else {
+                                        return;
+                                    };
+                                    let state = rotation.states[step_index];
+                                    let action_used = rotation.errors[step_index].is_ok();
+                                    ui.add(
+                                        util::get_action_icon(*action, self.job_id)
+                                            .fit_to_exact_size(egui::Vec2::new(18.0, 18.0))
+                                            .corner_radius(2.0),
+                                    )
+                                    .on_hover_text(action_name(*action, locale));
+                                    let text = egui::RichText::new(format!(
+                                        "{} / {} / {} / {}",
+                                        state.progress,
+                                        self.initial_quality.saturating_add(state.quality),
+                                        state.durability,
+                                        state.cp
+                                    ));
+                                    let text = if !action_used {
+                                        text.strikethrough().color(ui.visuals().error_fg_color)
+                                    } else if state != reference_state {
+                                        text.color(ui.visuals().warn_fg_color)
+                                    } else {
+                                        text
+                                    };
+                                    ui.label(text);
+                                });
+                            }
+                        });
+                    });
+            });
+    }
+}
+
+impl egui::Widget for RotationComparison<'_> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let locale = self.locale;
+        let id = egui::Id::new("ROTATION_COMPARISON_SELECTION");
+        let mut selection = ui.ctx().data(|data| {
+            data.get_temp::<ComparisonSelection>(id)
+                .unwrap_or(ComparisonSelection {
+                    current_rotation: true,
+                    saved_rotations: Vec::new(),
+                })
+        });
+        let response = ui
+            .vertical(|ui| {
+                self.draw_selection(ui, &mut selection);
+                ui.separator();
+
+                let mut rotations = Vec::new();
+                if selection.current_rotation {
+                    rotations.push(ComparedRotation::new(
+                        t!(locale, "Current rotation").to_owned(),
+                        self.current_actions,
+                        &self.settings,
+                    ));
+                }
+                for rotation in self
+                    .saved_rotations
+                    .rotations()
+                    .filter(|rotation| selection.saved_rotations.contains(&rotation.unique_id))
+                {
+                    rotations.push(ComparedRotation::new(
+                        rotation.name.clone(),
+                        &rotation.actions,
+                        &self.settings,
+                    ));
+                }
+                if rotations.len() < 2 {
+                    ui.label(t!(locale, "Select two or more rotations to compare them."));
+                    return;
+                }
+                self.draw_summary(ui, &rotations);
+                ui.separator();
+                self.draw_step_diff(ui, &rotations);
+            })
+            .response;
+        ui.ctx().data_mut(|data| data.insert_temp(id, selection));
+        response
+    }
+}
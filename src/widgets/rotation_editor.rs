@@ -0,0 +1,243 @@
+use raphael_data::{Locale, action_name};
+use raphael_sim::{Action, ActionError, Settings, SimulationState};
+use raphael_translations::{t, t_format};
+use strum::IntoEnumIterator;
+
+use crate::context::AppContext;
+
+use super::util;
+
+const ICON_SIZE: egui::Vec2 = egui::Vec2::new(30.0, 30.0);
+
+/// Source of an action that is dragged onto the rotation.
+#[derive(Debug, Clone, Copy)]
+enum DragPayload {
+    Palette(Action),
+    Step(usize),
+}
+
+/// Edits the actions of the current rotation by hand.
+/// Actions are added from the palette and can be reordered or removed by dragging them.
+pub struct RotationEditor<'a> {
+    locale: Locale,
+    job_id: u8,
+    settings: Settings,
+    actions: &'a mut Vec<Action>,
+    /// Set to the number of actions to keep when the user wants the solver to complete the rotation.
+    resolve_from: &'a mut Option<usize>,
+}
+
+impl<'a> RotationEditor<'a> {
+    pub fn new(
+        app_context: &AppContext,
+        actions: &'a mut Vec<Action>,
+        resolve_from: &'a mut Option<usize>,
+    ) -> Self {
+        Self {
+            locale: app_context.locale,
+            job_id: app_context.crafter_config.selected_job,
+            // The same settings as the solver, so that the rotation can be re-solved after any step without errors.
+            settings: app_context.solver_game_settings(),
+            actions,
+            resolve_from,
+        }
+    }
+}
+
+pub fn action_error_text(error: ActionError, locale: Locale) -> &'static str {
+    match error {
+        ActionError::Disabled => t!(locale, "The action is disabled"),
+        ActionError::StateIsFinal => t!(locale, "The synthesis is already complete"),
+        ActionError::InsufficientLevels => t!(locale, "Level too low"),
+        ActionError::InsufficientCP => t!(locale, "Not enough CP"),
+        ActionError::UnreliableAction => t!(locale, "The action is not 100% reliable"),
+        ActionError::NoRemainingUses => t!(locale, "No remaining uses"),
+        ActionError::QualityAfterProgress => {
+            t!(
+                locale,
+                "Quality actions cannot be used after Progress has increased"
+            )
+        }
+        ActionError::ComboRequirementNotMet => t!(locale, "Combo requirement not met"),
+        ActionError::SpecialConditionNotMet => t!(locale, "Condition not met"),
+        _ => t!(locale, "The action cannot be used"),
+    }
+}
+
+/// Moves or inserts the dragged action so that it ends up at `index`.
+fn apply_drop(actions: &mut Vec<Action>, payload: DragPayload, index: usize) {
+    match payload {
+        DragPayload::Palette(action) => actions.insert(index, action),
+        DragPayload::Step(from) => {
+            let action = actions.remove(from);
+            let index = if from < index { index - 1 } else { index };
+            actions.insert(index, action);
+        }
+    }
+}
+
+impl RotationEditor<'_> {
+    fn draw_palette(&mut self, ui: &mut egui::Ui) {
+        let locale = self.locale;
+        ui.label(egui::RichText::new(t!(locale, "Actions")).strong());
+        ui.horizontal_wrapped(|ui| {
+            ui.style_mut().spacing.item_spacing = egui::vec2(3.0, 3.0);
+            for action in Action::iter().filter(|action| {
+                self.settings.allowed_actions.has(*action)
+                    && action.level_requirement() <= self.settings.job_level
+            }) {
+                let response = ui
+                    .add(
+                        util::get_action_icon(action, self.job_id)
+                            .fit_to_exact_size(ICON_SIZE)
+                            .corner_radius(4.0)
+                            .sense(egui::Sense::click_and_drag()),
+                    )
+                    .on_hover_text(action_name(action, locale));
+                response.dnd_set_drag_payload(DragPayload::Palette(action));
+                if response.clicked() {
+                    self.actions.push(action);
+                }
+            }
+        });
+    }
+
+    fn draw_rotation(&mut self, ui: &mut egui::Ui) {
+        let locale = self.locale;
+        let (_, errors) =
+            SimulationState::from_macro_continue_on_error(&self.settings, self.actions);
+        let first_error = errors.iter().position(Result::is_err);
+
+        ui.horizontal(|ui| {
+            ui.label(egui::RichText::new(t!(locale, "Rotation")).strong());
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui
+                    .add_enabled(
+                        !self.actions.is_empty(),
+                        egui::Button::new(t!(locale, "Clear")),
+                    )
+                    .clicked()
+                {
+                    self.actions.clear();
+                }
+            });
+        });
+
+        let mut dropped = None;
+        let mut removed_step = None;
+        let frame = egui::Frame::group(ui.style()).inner_margin(4.0);
+        let (_, end_payload) = ui.dnd_drop_zone::<DragPayload, _>(frame, |ui| {
+            ui.set_width(ui.available_width());
+            ui.set_min_height(ICON_SIZE.y);
+            ui.horizontal_wrapped(|ui| {
+                ui.style_mut().spacing.item_spacing = egui::vec2(3.0, 3.0);
+                for (index, (action, error)) in self.actions.iter().zip(&errors).enumerate() {
+                    let response = ui.add(
+                        util::get_action_icon(*action, self.job_id)
+                            .fit_to_exact_size(ICON_SIZE)
+                            .corner_radius(4.0)
+                            .tint(match error {
+                                Ok(()) => egui::Color32::WHITE,
+                                Err(_) => egui::Color32::DARK_GRAY,
+                            })
+                            .sense(egui::Sense::click_and_drag()),
+                    );
+                    let response = match error {
+                        Ok(()) => response.on_hover_text(format!(
+                            "{}. {}",
+                            index + 1,
+                            action_name(*action, locale)
+                        )),
+                        Err(error) => {
+                            ui.painter().rect_stroke(
+                                response.rect,
+                                4.0,
+                                egui::Stroke::new(2.0, ui.visuals().error_fg_color),
+                                egui::StrokeKind::Inside,
+                            );
+                            response.on_hover_text(format!(
+                                "{}. {}\n{}",
+                                index + 1,
+                                action_name(*action, locale),
+                                action_error_text(*error, locale)
+                            ))
+                        }
+                    };
+                    response.dnd_set_drag_payload(DragPayload::Step(index));
+                    if response.dnd_hover_payload::<DragPayload>().is_some() {
+                        let rect = response.rect;
+                        ui.painter().vline(
+                            rect.left() - 2.0,
+                            rect.y_range(),
+                            egui::Stroke::new(2.0, ui.visuals().selection.bg_fill),
+                        );
+                    }
+                    if let Some(payload) = response.dnd_release_payload::<DragPayload>() {
+                        dropped = Some((*payload, index));
+                    }
+                    response.context_menu(|ui| {
+                        if ui.button(t!(locale, "Remove")).clicked() {
+                            removed_step = Some(index);
+                        }
+                        // The solver can only continue from a rotation without errors.
+                        let can_resolve = first_error.is_none_or(|first_error| first_error > index);
+                        if ui
+                            .add_enabled(
+                                can_resolve,
+                                egui::Button::new(t!(locale, "Re-solve after this step")),
+                            )
+                            .clicked()
+                        {
+                            *self.resolve_from = Some(index + 1);
+                        }
+                    });
+                }
+            });
+        });
+
+        if let Some((payload, index)) = dropped {
+            apply_drop(self.actions, payload, index);
+        } else if let Some(payload) = end_payload {
+            apply_drop(self.actions, *payload, self.actions.len());
+        }
+        if let Some(index) = removed_step {
+            self.actions.remove(index);
+        }
+
+        ui.label(
+            egui::RichText::new(t!(
+                locale,
+                "Click or drag actions from above to add them. Drag actions to reorder them, right-click an action to remove it or to let the solver complete the rotation after it."
+            ))
+            .small(),
+        );
+        if let Some(index) = first_error {
+            ui.label(
+                egui::RichText::new(t_format!(
+                    locale,
+                    "⚠ Step {step} and some of the following steps cannot be used.",
+                    step = index + 1
+                ))
+                .small()
+                .color(ui.visuals().warn_fg_color),
+            );
+        } else if !self.actions.is_empty()
+            && ui
+                .button(t!(locale, "Re-solve after the last step"))
+                .clicked()
+        {
+            *self.resolve_from = Some(self.actions.len());
+        }
+    }
+}
+
+impl egui::Widget for RotationEditor<'_> {
+    fn ui(mut self, ui: &mut egui::Ui) -> egui::Response {
+        ui.vertical(|ui| {
+            self.draw_palette(ui);
+            ui.separator();
+            self.draw_rotation(ui);
+        })
+        .response
+    }
+}
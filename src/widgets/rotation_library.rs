@@ -0,0 +1,92 @@
+use raphael_data::{Locale, game_data, get_job_name, get_raw_item_name};
+use raphael_translations::t;
+use serde::{Deserialize, Serialize};
+
+use super::Rotation;
+
+/// How pinned rotations are grouped in the saved rotations window.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum LibraryGrouping {
+    None,
+    #[default]
+    Folder,
+    Job,
+    Expansion,
+}
+
+impl LibraryGrouping {
+    pub const ALL: [Self; 4] = [Self::None, Self::Folder, Self::Job, Self::Expansion];
+
+    pub fn display_name(self, locale: Locale) -> &'static str {
+        match self {
+            Self::None => t!(locale, "No grouping"),
+            Self::Folder => t!(locale, "Folder"),
+            Self::Job => t!(locale, "Job"),
+            Self::Expansion => t!(locale, "Expansion"),
+        }
+    }
+}
+
+/// Index and name of the expansion of a recipe with the given job level.
+fn expansion(job_level: u8, locale: Locale) -> (u8, &'static str) {
+    match job_level {
+        0..=50 => (0, t!(locale, "A Realm Reborn")),
+        51..=60 => (1, t!(locale, "Heavensward")),
+        61..=70 => (2, t!(locale, "Stormblood")),
+        71..=80 => (3, t!(locale, "Shadowbringers")),
+        81..=90 => (4, t!(locale, "Endwalker")),
+        _ => (5, t!(locale, "Dawntrail")),
+    }
+}
+
+/// Group of a rotation. Groups are sorted by the first element, then by name.
+pub fn rotation_group(
+    rotation: &Rotation,
+    grouping: LibraryGrouping,
+    locale: Locale,
+) -> (u8, String) {
+    let recipe = rotation.recipe();
+    // Rotations without recipe data are grouped last.
+    let unknown_group = || (u8::MAX, t!(locale, "Unknown").to_owned());
+    match grouping {
+        LibraryGrouping::None => (0, String::new()),
+        LibraryGrouping::Folder if rotation.folder.is_empty() => {
+            (u8::MAX, t!(locale, "No folder").to_owned())
+        }
+        LibraryGrouping::Folder => (0, rotation.folder.clone()),
+        LibraryGrouping::Job => recipe.map_or_else(unknown_group, |recipe| {
+            (
+                recipe.job_id,
+                get_job_name(recipe.job_id, locale).to_owned(),
+            )
+        }),
+        LibraryGrouping::Expansion => recipe
+            .and_then(|recipe| game_data().rlvls().get(usize::from(recipe.recipe_level)))
+            .map_or_else(unknown_group, |rlvl| {
+                let (index, name) = expansion(rlvl.job_level, locale);
+                (index, name.to_owned())
+            }),
+    }
+}
+
+/// Returns true if all words of the query are contained in the name, folder, tags or notes of the
+/// rotation, or in the name of the crafted item in any language.
+pub fn rotation_matches_search(rotation: &Rotation, query: &str) -> bool {
+    let item_names: Vec<String> = rotation.recipe().map_or_else(Vec::new, |recipe| {
+        Locale::ALL
+            .into_iter()
+            .filter_map(|locale| get_raw_item_name(recipe.item_id, locale))
+            .map(|item_name| item_name.to_lowercase())
+            .collect()
+    });
+    let texts: Vec<String> = [&rotation.name, &rotation.folder, &rotation.notes]
+        .into_iter()
+        .chain(&rotation.tags)
+        .map(|text| text.to_lowercase())
+        .chain(item_names)
+        .collect();
+    query
+        .to_lowercase()
+        .split_whitespace()
+        .all(|word| texts.iter().any(|text| text.contains(word)))
+}
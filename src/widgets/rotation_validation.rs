@@ -0,0 +1,538 @@
+use std::{
+    num::NonZeroUsize,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use raphael_data::{CrafterStats, Locale};
+use raphael_sim::{Action, Settings, SimulationState};
+use raphael_translations::{t, t_format};
+
+use crate::{context::SolverConfig, thread_pool};
+
+use super::{
+    Rotation, SavedRotationsData,
+    saved_rotations::{RecipeInfo, SolveInfo},
+};
+
+/// Result of simulating a saved rotation with the current game data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RotationValidation {
+    /// The rotation finishes and reaches the same quality as when it was solved.
+    Valid,
+    /// The rotation finishes but reaches less quality than when it was solved.
+    QualityLost {
+        saved_quality: u16,
+        current_quality: u16,
+    },
+    /// The rotation no longer reaches max progress or contains actions that cannot be used.
+    Unfinished,
+    /// The rotation has no recipe data or its recipe no longer exists.
+    MissingRecipe,
+}
+
+impl RotationValidation {
+    fn needs_resolve(self) -> bool {
+        matches!(self, Self::QualityLost { .. } | Self::Unfinished)
+    }
+
+    fn description(self, locale: Locale) -> String {
+        match self {
+            Self::Valid => t!(locale, "Valid").to_owned(),
+            Self::QualityLost {
+                saved_quality,
+                current_quality,
+            } => t_format!(
+                locale,
+                "Quality decreased from {saved_quality} to {current_quality}"
+            ),
+            Self::Unfinished => t!(locale, "No longer finishes the craft").to_owned(),
+            Self::MissingRecipe => t!(locale, "Recipe data is missing").to_owned(),
+        }
+    }
+}
+
+/// Parameters with which a rotation is validated and re-solved.
+#[derive(Debug, Clone, Copy)]
+struct ValidationParams {
+    settings: Settings,
+    initial_quality: u16,
+    solver_config: SolverConfig,
+    crafter_stats: CrafterStats,
+}
+
+/// Calculates the game settings of a rotation using the current game data.
+/// Uses the current stats of the job of the recipe instead of the saved stats if `current_stats`
+/// is set.
+fn validation_params(
+    rotation: &Rotation,
+    current_stats: Option<&[CrafterStats; 8]>,
+) -> Option<ValidationParams> {
+    let (recipe, custom_recipe_overrides) = match rotation.recipe_info.as_ref()? {
+        RecipeInfo::NormalRecipe(recipe_id) => (*raphael_data::get_recipe(*recipe_id)?, None),
+        RecipeInfo::CustomRecipe(recipe, overrides) => {
+            (*recipe, Some(overrides.custom_recipe_overrides))
+        }
+    };
+    let crafter_stats = current_stats
+        .and_then(|current_stats| current_stats.get(usize::from(recipe.job_id)))
+        .copied()
+        .unwrap_or(rotation.crafter_stats);
+    let (initial_quality, solver_config) = rotation
+        .solve_info
+        .as_ref()
+        .map_or((0, SolverConfig::default()), |solve_info| {
+            (solve_info.initial_quality, solve_info.solver_config)
+        });
+    let (food, potion) = rotation.consumables();
+    let mut settings = raphael_data::get_game_settings(
+        recipe,
+        custom_recipe_overrides,
+        crafter_stats,
+        food,
+        potion,
+    );
+    settings.adversarial = solver_config.adversarial;
+    settings.backload_progress = solver_config.backload_progress;
+    settings.stellar_steady_hand_charges = solver_config.stellar_steady_hand_charges;
+    Some(ValidationParams {
+        settings,
+        initial_quality,
+        solver_config,
+        crafter_stats,
+    })
+}
+
+/// Final quality including the initial quality, or `None` if the rotation does not finish.
+fn final_quality(settings: &Settings, initial_quality: u16, actions: &[Action]) -> Option<u16> {
+    let (state, errors) = SimulationState::from_macro_continue_on_error(settings, actions);
+    if errors.iter().any(Result::is_err) || state.progress < settings.max_progress {
+        return None;
+    }
+    Some(std::cmp::min(
+        initial_quality.saturating_add(state.quality),
+        settings.max_quality,
+    ))
+}
+
+fn validate_rotation(rotation: &Rotation, params: Option<&ValidationParams>) -> RotationValidation {
+    let Some(params) = params else {
+        return RotationValidation::MissingRecipe;
+    };
+    let Some(current_quality) =
+        final_quality(&params.settings, params.initial_quality, &rotation.actions)
+    else {
+        return RotationValidation::Unfinished;
+    };
+    // Rotations saved before v0.21.0 don't contain the settings they were solved with.
+    let saved_quality = rotation.solve_info.as_ref().and_then(|solve_info| {
+        final_quality(
+            &solve_info.game_settings,
+            solve_info.initial_quality,
+            &rotation.actions,
+        )
+    });
+    match saved_quality {
+        // Reaching the new max quality is fine if the max quality of the recipe has decreased.
+        Some(saved_quality)
+            if current_quality < std::cmp::min(saved_quality, params.settings.max_quality) =>
+        {
+            RotationValidation::QualityLost {
+                saved_quality,
+                current_quality,
+            }
+        }
+        _ => RotationValidation::Valid,
+    }
+}
+
+/// Re-solves rotations one after another on the solver thread pool.
+#[derive(Debug, Clone)]
+struct BatchResolve {
+    /// Solved actions of each rotation, or `None` if no solution was found.
+    results: Arc<Mutex<Vec<(u64, ValidationParams, Option<Vec<Action>>)>>>,
+    interrupt: raphael_solver::AtomicFlag,
+    /// Set by the worker after it has pushed its last result.
+    worker_exited: Arc<AtomicBool>,
+    total: usize,
+    finished: usize,
+    failed: usize,
+}
+
+impl BatchResolve {
+    fn spawn(jobs: Vec<(u64, ValidationParams)>) -> Self {
+        let batch = Self {
+            results: Arc::default(),
+            interrupt: raphael_solver::AtomicFlag::new(),
+            worker_exited: Arc::default(),
+            total: jobs.len(),
+            finished: 0,
+            failed: 0,
+        };
+        let results = batch.results.clone();
+        let interrupt = batch.interrupt.clone();
+        let worker_exited = batch.worker_exited.clone();
+        rayon::spawn(move || {
+            for (unique_id, params) in jobs {
+                if interrupt.is_set() {
+                    break;
+                }
+                let mut simulator_settings = params.settings;
+                let target_quality = params
+                    .solver_config
+                    .quality_target
+                    .get_target(simulator_settings.max_quality);
+                simulator_settings.max_quality =
+                    target_quality.saturating_sub(params.initial_quality);
+                let solver_settings = raphael_solver::SolverSettings {
+                    simulator_settings,
+                    allow_non_max_quality_solutions: !params
+                        .solver_config
+                        .must_reach_target_quality,
+                };
+                let mut solver = raphael_solver::MacroSolver::new(
+                    solver_settings,
+                    Box::new(|_| {}),
+                    Box::new(|_| {}),
+                    interrupt.clone(),
+                );
+                let actions = solver.solve().ok();
+                results.lock().unwrap().push((unique_id, params, actions));
+            }
+            worker_exited.store(true, Ordering::Release);
+        });
+        batch
+    }
+
+    /// Whether the worker has exited. Cancelling only stops the worker after the current solve.
+    fn is_finished(&self) -> bool {
+        self.worker_exited.load(Ordering::Acquire)
+    }
+}
+
+/// State of the validation section that is kept between frames.
+#[derive(Debug, Clone, Default)]
+struct ValidationState {
+    use_current_stats: bool,
+    /// `None` until the rotations have been validated.
+    results: Option<Vec<(u64, RotationValidation)>>,
+    batch_resolve: Option<BatchResolve>,
+    message: Option<String>,
+}
+
+/// Re-simulates all saved rotations with the current game data and re-solves the rotations that
+/// no longer finish or reach less quality than when they were solved.
+pub fn show_rotation_validation(
+    ui: &mut egui::Ui,
+    locale: Locale,
+    current_stats: &[CrafterStats; 8],
+    num_threads: Option<NonZeroUsize>,
+    rotations: &mut SavedRotationsData,
+) {
+    let id = egui::Id::new("SAVED_ROTATIONS_VALIDATION");
+    let mut state = ui
+        .ctx()
+        .data(|data| data.get_temp::<ValidationState>(id))
+        .unwrap_or_default();
+    let current_stats = state.use_current_stats.then_some(current_stats);
+
+    if let Some(mut batch) = state.batch_resolve.take() {
+        // Checked before taking the results so that the last result is never left behind.
+        let finished = batch.is_finished();
+        let results: Vec<_> = batch.results.lock().unwrap().drain(..).collect();
+        for (unique_id, params, actions) in results {
+            batch.finished += 1;
+            let Some(actions) = actions else {
+                batch.failed += 1;
+                continue;
+            };
+            let Some(rotation) = rotations
+                .rotations_mut()
+                .find(|rotation| rotation.unique_id == unique_id)
+            else {
+                continue;
+            };
+            rotation.actions = actions;
+            rotation.solver = Rotation::solver_description(&params.solver_config);
+            rotation.solve_info = Some(SolveInfo::new(
+                &params.settings,
+                params.initial_quality,
+                &params.solver_config,
+            ));
+            rotation.crafter_stats = params.crafter_stats;
+            rotation.imported = false;
+            let validation = validate_rotation(rotation, Some(&params));
+            if let Some(results) = &mut state.results
+                && let Some(result) = results.iter_mut().find(|(id, _)| *id == unique_id)
+            {
+                result.1 = validation;
+            }
+        }
+        if finished {
+            state.message = Some(t_format!(
+                locale,
+                "Re-solved {resolved} of {total} rotations",
+                resolved = batch.finished - batch.failed,
+                total = batch.total
+            ));
+        } else {
+            state.batch_resolve = Some(batch);
+            ui.ctx().request_repaint();
+        }
+    }
+
+    ui.horizontal(|ui| {
+        ui.label(t!(locale, "Crafter stats:"));
+        ui.radio_value(
+            &mut state.use_current_stats,
+            false,
+            t!(locale, "Saved with rotation"),
+        );
+        ui.radio_value(
+            &mut state.use_current_stats,
+            true,
+            t!(locale, "Current stats"),
+        );
+    });
+    let batch_running = state.batch_resolve.is_some();
+    ui.horizontal(|ui| {
+        if ui
+            .add_enabled(
+                !batch_running,
+                egui::Button::new(t!(locale, "Validate all rotations")),
+            )
+            .clicked()
+        {
+            state.results = Some(
+                rotations
+                    .rotations()
+                    .map(|rotation| {
+                        let params = validation_params(rotation, current_stats);
+                        (
+                            rotation.unique_id,
+                            validate_rotation(rotation, params.as_ref()),
+                        )
+                    })
+                    .collect(),
+            );
+            state.message = None;
+        }
+        if let Some(batch) = &state.batch_resolve {
+            ui.add(egui::Spinner::new());
+            ui.label(t_format!(
+                locale,
+                "Re-solving {finished}/{total}",
+                finished = batch.finished,
+                total = batch.total
+            ));
+            if ui.button(t!(locale, "Cancel")).clicked() {
+                batch.interrupt.set();
+            }
+        }
+    });
+
+    if let Some(results) = &state.results {
+        let flagged: Vec<(&Rotation, RotationValidation)> = rotations
+            .rotations()
+            .filter_map(|rotation| {
+                let (_, validation) = results
+                    .iter()
+                    .find(|(unique_id, _)| *unique_id == rotation.unique_id)?;
+                (*validation != RotationValidation::Valid).then_some((rotation, *validation))
+            })
+            .collect();
+        let valid_count = results
+            .iter()
+            .filter(|(_, validation)| *validation == RotationValidation::Valid)
+            .count();
+        ui.label(t_format!(
+            locale,
+            "{valid_count} of {total} rotations are valid",
+            total = results.len()
+        ));
+        for (rotation, validation) in &flagged {
+            ui.horizontal(|ui| {
+                let color = match validation.needs_resolve() {
+                    true => ui.visuals().warn_fg_color,
+                    false => ui.visuals().weak_text_color(),
+                };
+                ui.label(egui::RichText::new(&rotation.name).strong());
+                if !rotation.folder.is_empty() {
+                    ui.label(egui::RichText::new(&rotation.folder).small().weak());
+                }
+                ui.label(egui::RichText::new(validation.description(locale)).color(color));
+            });
+        }
+
+        let jobs: Vec<(u64, ValidationParams)> = flagged
+            .iter()
+            .filter(|(_, validation)| validation.needs_resolve())
+            .filter_map(|(rotation, _)| {
+                validation_params(rotation, current_stats)
+                    .map(|params| (rotation.unique_id, params))
+            })
+            .collect();
+        if !jobs.is_empty() {
+            let thread_pool_ready = thread_pool::is_initialized();
+            if ui
+                .add_enabled(
+                    !batch_running && thread_pool_ready,
+                    egui::Button::new(t_format!(
+                        locale,
+                        "Re-solve {count} rotations",
+                        count = jobs.len()
+                    )),
+                )
+                .clicked()
+            {
+                state.batch_resolve = Some(BatchResolve::spawn(jobs));
+                state.message = None;
+            }
+            if !thread_pool_ready {
+                thread_pool::attempt_initialization(num_threads);
+                ui.ctx().request_repaint();
+            }
+            ui.label(
+                egui::RichText::new(t!(
+                    locale,
+                    "Re-solving replaces the actions of the rotations and may take a long time."
+                ))
+                .small(),
+            );
+        }
+    }
+
+    if let Some(message) = &state.message {
+        ui.label(egui::RichText::new(message).small());
+    }
+    ui.ctx().data_mut(|data| data.insert_temp(id, state));
+}
+
+#[cfg(test)]
+mod tests {
+    use raphael_sim::ActionMask;
+
+    use super::*;
+
+    const SETTINGS: Settings = Settings {
+        max_cp: 250,
+        max_durability: 60,
+        max_progress: 200,
+        max_quality: 1000,
+        base_progress: 100,
+        base_quality: 100,
+        job_level: 100,
+        allowed_actions: ActionMask::all(),
+        adversarial: false,
+        backload_progress: false,
+        stellar_steady_hand_charges: 0,
+    };
+
+    const ACTIONS: [Action; 3] = [
+        Action::BasicTouch,
+        Action::BasicSynthesis,
+        Action::BasicSynthesis,
+    ];
+
+    /// A rotation that was solved with `SETTINGS`.
+    fn test_rotation() -> Rotation {
+        Rotation {
+            unique_id: 1,
+            name: "Test Item".to_string(),
+            solver: "Raphael v0.0.0".to_string(),
+            actions: ACTIONS.to_vec(),
+            recipe_info: None,
+            solve_info: Some(SolveInfo::new(&SETTINGS, 0, &SolverConfig::default())),
+            food: None,
+            potion: None,
+            crafter_stats: CrafterStats::default(),
+            folder: String::new(),
+            tags: Vec::new(),
+            notes: String::new(),
+            imported: false,
+        }
+    }
+
+    fn validate(settings: Settings) -> RotationValidation {
+        let params = ValidationParams {
+            settings,
+            initial_quality: 0,
+            solver_config: SolverConfig::default(),
+            crafter_stats: CrafterStats::default(),
+        };
+        validate_rotation(&test_rotation(), Some(&params))
+    }
+
+    #[test]
+    fn final_quality_includes_initial_quality() {
+        let quality = final_quality(&SETTINGS, 0, &ACTIONS).unwrap();
+        assert!(quality > 0);
+        assert_eq!(final_quality(&SETTINGS, 50, &ACTIONS), Some(quality + 50));
+        // The final quality is capped at the max quality.
+        assert_eq!(
+            final_quality(&SETTINGS, SETTINGS.max_quality, &ACTIONS),
+            Some(SETTINGS.max_quality)
+        );
+    }
+
+    #[test]
+    fn final_quality_unfinished() {
+        assert_eq!(final_quality(&SETTINGS, 0, &ACTIONS[..2]), None);
+        // Muscle Memory can only be used on the first step.
+        let actions = [
+            Action::BasicSynthesis,
+            Action::MuscleMemory,
+            Action::BasicSynthesis,
+        ];
+        assert_eq!(final_quality(&SETTINGS, 0, &actions), None);
+    }
+
+    #[test]
+    fn validate_unchanged_settings() {
+        assert_eq!(validate(SETTINGS), RotationValidation::Valid);
+    }
+
+    #[test]
+    fn validate_lowered_max_quality() {
+        let saved_quality = final_quality(&SETTINGS, 0, &ACTIONS).unwrap();
+        // Less quality than when the rotation was solved, even though the max quality was lowered.
+        let settings = Settings {
+            base_quality: SETTINGS.base_quality / 2,
+            max_quality: saved_quality - 1,
+            ..SETTINGS
+        };
+        let current_quality = final_quality(&settings, 0, &ACTIONS).unwrap();
+        assert_eq!(
+            validate(settings),
+            RotationValidation::QualityLost {
+                saved_quality,
+                current_quality,
+            }
+        );
+        // Reaching the lowered max quality is valid.
+        let settings = Settings {
+            max_quality: current_quality,
+            ..settings
+        };
+        assert_eq!(validate(settings), RotationValidation::Valid);
+    }
+
+    #[test]
+    fn validate_unfinished() {
+        let settings = Settings {
+            max_progress: SETTINGS.max_progress * 2,
+            ..SETTINGS
+        };
+        assert_eq!(validate(settings), RotationValidation::Unfinished);
+    }
+
+    #[test]
+    fn validate_missing_recipe() {
+        assert_eq!(
+            validate_rotation(&test_rotation(), None),
+            RotationValidation::MissingRecipe
+        );
+    }
+}
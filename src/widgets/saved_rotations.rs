@@ -1,8 +1,9 @@
 use std::{
-    collections::VecDeque,
+    collections::{BTreeMap, VecDeque},
     hash::{Hash, Hasher},
 };
 
+use base64::Engine;
 use raphael_data::{Consumable, CrafterStats, CustomRecipeOverrides, Locale, Recipe};
 use raphael_sim::*;
 use raphael_translations::{t, t_format};
@@ -14,7 +15,12 @@ use crate::{
     widgets::util::max_text_width,
 };
 
-use super::util;
+use super::{
+    DropDown,
+    rotation_library::{LibraryGrouping, rotation_group, rotation_matches_search},
+    rotation_validation::show_rotation_validation,
+    util,
+};
 
 fn generate_unique_rotation_id() -> u64 {
     let mut hasher = std::hash::DefaultHasher::new();
@@ -82,6 +88,17 @@ pub struct Rotation {
     pub food: Option<(u32, bool)>,
     pub potion: Option<(u32, bool)>,
     pub crafter_stats: CrafterStats,
+    /// Folder in the saved rotations. Empty if the rotation is not in a folder.
+    #[serde(default)]
+    pub folder: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub notes: String,
+    /// Set if the rotation was decoded from a rotation code or imported from a rotations file instead of being solved by this app.
+    /// Imported rotations are never returned as solver results, as their `solve_info` cannot be trusted.
+    #[serde(default)]
+    pub imported: bool,
 }
 
 impl Rotation {
@@ -99,22 +116,10 @@ impl Rotation {
         let initial_quality = app_context.initial_quality();
         let name = raphael_data::get_item_name(recipe_config.recipe().item_id, false, *locale)
             .unwrap_or(t!(locale, "Unknown item").to_owned());
-        let solver_params = format!(
-            "Raphael v{}{}{}",
-            env!("CARGO_PKG_VERSION"),
-            match solver_config.backload_progress {
-                true => " +backload",
-                false => "",
-            },
-            match solver_config.adversarial {
-                true => " +adversarial",
-                false => "",
-            },
-        );
         Self {
             unique_id: generate_unique_rotation_id(),
             name,
-            solver: solver_params,
+            solver: Self::solver_description(solver_config),
             actions,
             recipe_info: Some(RecipeInfo::from(&recipe_config.recipe_source)),
             solve_info: Some(SolveInfo::new(
@@ -125,7 +130,161 @@ impl Rotation {
             food: food.map(|consumable| (consumable.item_id, consumable.hq)),
             potion: potion.map(|consumable| (consumable.item_id, consumable.hq)),
             crafter_stats: *crafter_config.active_stats(),
+            folder: String::new(),
+            tags: Vec::new(),
+            notes: String::new(),
+            imported: false,
+        }
+    }
+
+    /// Version of the solver and the solver options, e.g. `Raphael v0.28.2 +backload`.
+    pub fn solver_description(solver_config: &SolverConfig) -> String {
+        format!(
+            "Raphael v{}{}{}",
+            env!("CARGO_PKG_VERSION"),
+            match solver_config.backload_progress {
+                true => " +backload",
+                false => "",
+            },
+            match solver_config.adversarial {
+                true => " +adversarial",
+                false => "",
+            },
+        )
+    }
+
+    /// Food and potion of the rotation. `None` if the consumable is not in the game data.
+    pub fn consumables(&self) -> (Option<Consumable>, Option<Consumable>) {
+        let find_consumable = |consumables: &[Consumable], (item_id, hq): (u32, bool)| {
+            consumables
+                .iter()
+                .find(|consumable| consumable.item_id == item_id && consumable.hq == hq)
+                .copied()
+        };
+        let game_data = raphael_data::game_data();
+        (
+            self.food
+                .and_then(|food| find_consumable(game_data.meals(), food)),
+            self.potion
+                .and_then(|potion| find_consumable(game_data.potions(), potion)),
+        )
+    }
+
+    pub fn recipe(&self) -> Option<&Recipe> {
+        self.recipe_info
+            .as_ref()
+            .and_then(|recipe_info| match recipe_info {
+                RecipeInfo::NormalRecipe(recipe_id) => raphael_data::get_recipe(*recipe_id),
+                RecipeInfo::CustomRecipe(recipe, _) => Some(recipe),
+            })
+    }
+}
+
+/// Version of the rotation code format.
+const ROTATION_CODE_VERSION: u32 = 1;
+
+/// Key of the URL fragment that contains a rotation code, e.g. `#rotation=1.eJyr...`.
+pub const ROTATION_CODE_URL_KEY: &str = "rotation=";
+
+/// Temporary data ID of the URL of the web version, used to create rotation links.
+/// Not set in the native version, which shares rotation codes instead.
+pub const ROTATION_LINK_BASE_ID: &str = "ROTATION_LINK_BASE";
+
+/// Contents of a rotation code. Same as [`Rotation`] without the `unique_id` and the folder.
+#[derive(Serialize, Deserialize)]
+struct SharedRotation {
+    name: String,
+    solver: String,
+    actions: Vec<Action>,
+    recipe_info: Option<RecipeInfo>,
+    solve_info: Option<SolveInfo>,
+    food: Option<(u32, bool)>,
+    potion: Option<(u32, bool)>,
+    crafter_stats: CrafterStats,
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default)]
+    notes: String,
+}
+
+impl Rotation {
+    /// Encodes the rotation as a URL-safe string that can be shared in chat or as part of a link.
+    /// The code consists of the format version and the compressed rotation, e.g. `1.eJyr...`.
+    pub fn to_code(&self) -> String {
+        let shared_rotation = SharedRotation {
+            name: self.name.clone(),
+            solver: self.solver.clone(),
+            actions: self.actions.clone(),
+            recipe_info: self.recipe_info.clone(),
+            solve_info: self.solve_info.clone(),
+            food: self.food,
+            potion: self.potion,
+            crafter_stats: self.crafter_stats,
+            tags: self.tags.clone(),
+            notes: self.notes.clone(),
+        };
+        let json = serde_json::to_vec(&shared_rotation).expect("Failed to serialize rotation");
+        let compressed = miniz_oxide::deflate::compress_to_vec(&json, 9);
+        format!(
+            "{}.{}",
+            ROTATION_CODE_VERSION,
+            base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(compressed)
+        )
+    }
+
+    /// Decodes a rotation code. Also accepts a link that contains a rotation code.
+    pub fn from_code(text: &str) -> Result<Self, String> {
+        let text = text.trim();
+        let code = match text.find(ROTATION_CODE_URL_KEY) {
+            Some(index) => &text[index + ROTATION_CODE_URL_KEY.len()..],
+            None => text,
+        };
+        let code = code.split('&').next().unwrap_or_default();
+        let (version, payload) = code
+            .split_once('.')
+            .ok_or_else(|| String::from("Not a rotation code"))?;
+        match version.parse::<u32>() {
+            Ok(ROTATION_CODE_VERSION) => (),
+            Ok(version) if version > ROTATION_CODE_VERSION => {
+                return Err(format!(
+                    "Rotation code version {version} is not supported by this version of Raphael"
+                ));
+            }
+            _ => return Err(String::from("Not a rotation code")),
         }
+        let compressed = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(payload)
+            .map_err(|err| format!("Invalid rotation code: {err}"))?;
+        // Limit the decompressed size to guard against malicious codes.
+        let json = miniz_oxide::inflate::decompress_to_vec_with_limit(&compressed, 1 << 16)
+            .map_err(|_| String::from("Invalid rotation code: decompression failed"))?;
+        let shared_rotation: SharedRotation =
+            serde_json::from_slice(&json).map_err(|err| format!("Invalid rotation code: {err}"))?;
+        Ok(Self {
+            unique_id: generate_unique_rotation_id(),
+            name: shared_rotation.name,
+            solver: shared_rotation.solver,
+            actions: shared_rotation.actions,
+            recipe_info: shared_rotation.recipe_info,
+            solve_info: shared_rotation.solve_info,
+            food: shared_rotation.food,
+            potion: shared_rotation.potion,
+            crafter_stats: shared_rotation.crafter_stats,
+            folder: String::new(),
+            tags: shared_rotation.tags,
+            notes: shared_rotation.notes,
+            imported: true,
+        })
+    }
+}
+
+/// Text that is copied to the clipboard to share a rotation.
+fn rotation_share_text(ctx: &egui::Context, rotation: &Rotation) -> String {
+    let code = rotation.to_code();
+    let link_base = ctx.data(|data| data.get_temp::<String>(egui::Id::new(ROTATION_LINK_BASE_ID)));
+    match link_base {
+        Some(link_base) => format!("{link_base}#{ROTATION_CODE_URL_KEY}{code}"),
+        None => code,
     }
 }
 
@@ -141,13 +300,28 @@ impl Clone for Rotation {
             food: self.food,
             potion: self.potion,
             crafter_stats: self.crafter_stats,
+            folder: self.folder.clone(),
+            tags: self.tags.clone(),
+            notes: self.notes.clone(),
+            imported: self.imported,
         }
     }
 }
 
+impl Rotation {
+    /// Same as `==`, but also compares the name, folder, tags and notes.
+    fn is_identical(&self, other: &Self) -> bool {
+        *self == *other
+            && self.name == other.name
+            && self.folder == other.folder
+            && self.tags == other.tags
+            && self.notes == other.notes
+    }
+}
+
 impl PartialEq for Rotation {
     fn eq(&self, other: &Self) -> bool {
-        // unique_id & name are skipped
+        // unique_id, name, folder, tags, notes & imported are skipped
         self.solver == other.solver
             && self.actions == other.actions
             && self.recipe_info == other.recipe_info
@@ -194,11 +368,34 @@ impl std::fmt::Display for LoadOperationDisplay {
     }
 }
 
+/// What to do with an imported rotation that has the same `unique_id` as an existing rotation.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ImportConflictResolution {
+    #[default]
+    Skip,
+    Replace,
+    KeepBoth,
+}
+
+impl ImportConflictResolution {
+    fn display_name(self, locale: Locale) -> &'static str {
+        match self {
+            Self::Skip => t!(locale, "Keep existing rotation"),
+            Self::Replace => t!(locale, "Replace existing rotation"),
+            Self::KeepBoth => t!(locale, "Keep both"),
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SavedRotationsConfig {
     pub load_from_saved_rotations: bool,
     pub default_load_operation: LoadOperation,
     pub max_history_size: usize,
+    #[serde(default)]
+    pub library_grouping: LibraryGrouping,
+    #[serde(default)]
+    pub import_conflict_resolution: ImportConflictResolution,
 }
 
 impl Default for SavedRotationsConfig {
@@ -207,10 +404,55 @@ impl Default for SavedRotationsConfig {
             load_from_saved_rotations: false,
             default_load_operation: LoadOperation::Rotation,
             max_history_size: 50,
+            library_grouping: LibraryGrouping::Folder,
+            import_conflict_resolution: ImportConflictResolution::Skip,
         }
     }
 }
 
+/// Version of the rotations file format.
+/// Files with a newer version are rejected on import.
+pub const ROTATIONS_FILE_VERSION: u32 = 1;
+
+/// Rotations exported to a file, e.g. to share them or to back them up.
+/// The `raphael-cli rotations` command reads the same format.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RotationsFile {
+    pub version: u32,
+    pub pinned: Vec<Rotation>,
+    pub solve_history: Vec<Rotation>,
+}
+
+impl RotationsFile {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Failed to serialize rotations")
+    }
+
+    pub fn from_json(text: &str) -> Result<Self, String> {
+        #[derive(Deserialize)]
+        struct Header {
+            version: u32,
+        }
+        let header: Header =
+            serde_json::from_str(text).map_err(|err| format!("Not a rotations file: {err}"))?;
+        if header.version > ROTATIONS_FILE_VERSION {
+            return Err(format!(
+                "Unsupported rotations file version {} (latest supported version is {})",
+                header.version, ROTATIONS_FILE_VERSION
+            ));
+        }
+        serde_json::from_str(text).map_err(|err| format!("Invalid rotations file: {err}"))
+    }
+}
+
+/// Number of rotations that were added, replaced, or skipped when importing a rotations file.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ImportSummary {
+    pub added: usize,
+    pub replaced: usize,
+    pub skipped: usize,
+}
+
 #[derive(Debug, Default, Serialize, Deserialize)]
 pub struct SavedRotationsData {
     pinned: Vec<Rotation>,
@@ -218,6 +460,114 @@ pub struct SavedRotationsData {
 }
 
 impl SavedRotationsData {
+    /// Pinned rotations followed by the solve history.
+    pub fn rotations(&self) -> impl Iterator<Item = &Rotation> {
+        self.pinned.iter().chain(self.solve_history.iter())
+    }
+
+    pub fn rotations_mut(&mut self) -> impl Iterator<Item = &mut Rotation> {
+        self.pinned.iter_mut().chain(self.solve_history.iter_mut())
+    }
+
+    /// Pins a rotation, e.g. one that was opened from a rotation code.
+    /// Returns false if an identical rotation is already saved.
+    pub fn add_pinned_rotation(&mut self, rotation: Rotation) -> bool {
+        if self
+            .rotations()
+            .any(|saved_rotation| *saved_rotation == rotation)
+        {
+            return false;
+        }
+        self.pinned.push(rotation);
+        true
+    }
+
+    /// Exports the rotations for which `filter` returns true, keeping their `unique_id`.
+    pub fn export(&self, filter: impl Fn(&Rotation) -> bool) -> RotationsFile {
+        let export_rotation = |rotation: &Rotation| Rotation {
+            unique_id: rotation.unique_id,
+            ..rotation.clone()
+        };
+        RotationsFile {
+            version: ROTATIONS_FILE_VERSION,
+            pinned: self
+                .pinned
+                .iter()
+                .filter(|rotation| filter(rotation))
+                .map(export_rotation)
+                .collect(),
+            solve_history: self
+                .solve_history
+                .iter()
+                .filter(|rotation| filter(rotation))
+                .map(export_rotation)
+                .collect(),
+        }
+    }
+
+    /// Imports the rotations of a rotations file.
+    /// Rotations that are identical to an existing rotation, including the name, folder, tags and notes, are always skipped.
+    /// Rotations of the solve history are skipped once the history has `max_history_size` rotations.
+    pub fn import(&mut self, file: RotationsFile, config: &SavedRotationsConfig) -> ImportSummary {
+        let mut summary = ImportSummary::default();
+        let imported_rotations = file
+            .pinned
+            .into_iter()
+            .map(|rotation| (rotation, true))
+            .chain(
+                file.solve_history
+                    .into_iter()
+                    .map(|rotation| (rotation, false)),
+            );
+        for (mut rotation, pinned) in imported_rotations {
+            rotation.imported = true;
+            if self
+                .rotations()
+                .any(|saved_rotation| saved_rotation.is_identical(&rotation))
+            {
+                summary.skipped += 1;
+                continue;
+            }
+            let existing_rotation = self
+                .pinned
+                .iter_mut()
+                .chain(self.solve_history.iter_mut())
+                .find(|saved_rotation| saved_rotation.unique_id == rotation.unique_id);
+            if let Some(existing_rotation) = existing_rotation {
+                match config.import_conflict_resolution {
+                    ImportConflictResolution::Skip => {
+                        summary.skipped += 1;
+                        continue;
+                    }
+                    ImportConflictResolution::Replace => {
+                        *existing_rotation = rotation;
+                        summary.replaced += 1;
+                        continue;
+                    }
+                    ImportConflictResolution::KeepBoth => {
+                        rotation.unique_id = generate_unique_rotation_id();
+                        while self
+                            .rotations()
+                            .any(|saved_rotation| saved_rotation.unique_id == rotation.unique_id)
+                        {
+                            rotation.unique_id = rotation.unique_id.wrapping_add(1);
+                        }
+                    }
+                }
+            }
+            if pinned {
+                self.pinned.push(rotation);
+            } else if self.solve_history.len() < config.max_history_size {
+                self.solve_history.push_back(rotation);
+            } else {
+                summary.skipped += 1;
+                continue;
+            }
+            summary.added += 1;
+        }
+        summary
+    }
+
     pub fn add_solved_rotation(&mut self, rotation: Rotation, config: &SavedRotationsConfig) {
         if let Some(index) = self
             .solve_history
@@ -243,6 +593,7 @@ impl SavedRotationsData {
         let find_and_map_rotation = |rotation: &Rotation| {
             if let (Some(saved_solver_version), Some(saved_solve_info)) =
                 (rotation.solver.split(' ').nth(1), &rotation.solve_info)
+                && !rotation.imported
                 && saved_solver_version == format!("v{}", env!("CARGO_PKG_VERSION"))
                 && *saved_solve_info == solve_info
             {
@@ -265,7 +616,7 @@ struct RotationWidget<'a> {
     default_load_operation: LoadOperation,
     pinned: &'a mut bool,
     deleted: &'a mut bool,
-    rotation: &'a Rotation,
+    rotation: &'a mut Rotation,
     actions: &'a mut Vec<Action>,
     crafter_config: &'a mut CrafterConfig,
     solver_config: &'a mut SolverConfig,
@@ -289,7 +640,7 @@ impl<'a> RotationWidget<'a> {
         limited_app_context: &'a mut LimitedAppContext,
         pinned: &'a mut bool,
         deleted: &'a mut bool,
-        rotation: &'a Rotation,
+        rotation: &'a mut Rotation,
         actions: &'a mut Vec<Action>,
     ) -> Self {
         Self {
@@ -316,6 +667,9 @@ impl<'a> RotationWidget<'a> {
         ui.horizontal(|ui| {
             util::collapse_temporary(ui, self.id_salt("collapsed").into(), collapsed);
             ui.label(egui::RichText::new(&self.rotation.name).strong());
+            for tag in &self.rotation.tags {
+                ui.label(egui::RichText::new(format!("#{tag}")).small().weak());
+            }
             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                 if ui.add(egui::Button::new("🗑")).clicked() {
                     *self.deleted = true;
@@ -328,6 +682,15 @@ impl<'a> RotationWidget<'a> {
                     *self.pinned = true;
                 }
                 ui.add_space(-3.0);
+                #[cfg(target_arch = "wasm32")]
+                let share_hover_text = t!(locale, "Copy a link to this rotation");
+                #[cfg(not(target_arch = "wasm32"))]
+                let share_hover_text = t!(locale, "Copy a code to share this rotation");
+                if ui.button("🔗").on_hover_text(share_hover_text).clicked() {
+                    ui.ctx()
+                        .copy_text(rotation_share_text(ui.ctx(), self.rotation));
+                }
+                ui.add_space(-3.0);
                 let load_button_response = ui.button(t!(locale, "Load"));
                 let mut selected_load_operation = None;
                 load_button_response.context_menu(|ui| {
@@ -396,7 +759,7 @@ impl<'a> RotationWidget<'a> {
         if let Some(recipe_configuration) = &self.rotation.recipe_info {
             match recipe_configuration {
                 RecipeInfo::NormalRecipe(recipe_id) => {
-                    if let Some(recipe) = raphael_data::RECIPES.get(*recipe_id) {
+                    if let Some(recipe) = raphael_data::get_recipe(*recipe_id) {
                         *self.recipe_config = RecipeConfiguration {
                             recipe_source: RecipeSource::Normal {
                                 id: *recipe_id,
@@ -426,18 +789,7 @@ impl<'a> RotationWidget<'a> {
     }
 
     fn load_saved_consumables(&mut self) {
-        *self.selected_food = self.rotation.food.and_then(|(item_id, hq)| {
-            raphael_data::MEALS
-                .iter()
-                .find(|food| food.item_id == item_id && food.hq == hq)
-                .copied()
-        });
-        *self.selected_potion = self.rotation.potion.and_then(|(item_id, hq)| {
-            raphael_data::POTIONS
-                .iter()
-                .find(|potion| potion.item_id == item_id && potion.hq == hq)
-                .copied()
-        });
+        (*self.selected_food, *self.selected_potion) = self.rotation.consumables();
     }
 
     fn show_info_row(
@@ -524,6 +876,81 @@ impl<'a> RotationWidget<'a> {
         );
     }
 
+    /// Folder, tags and notes of the rotation, which can be edited to organize the saved rotations.
+    fn show_library_info(&mut self, ui: &mut egui::Ui) {
+        let locale = self.locale;
+        let max_key_width = max_text_width(
+            ui,
+            [
+                t!(locale, "Folder"),
+                t!(locale, "Tags"),
+                t!(locale, "Notes"),
+            ],
+            egui::TextStyle::Body,
+        );
+        let folder_id_salt = self.id_salt("folder");
+        let new_tag_id_salt = self.id_salt("new_tag");
+        let notes_id_salt = self.id_salt("notes");
+        let rotation = &mut *self.rotation;
+        let add_key_space = |ui: &mut egui::Ui, key: &str| {
+            let used_width = ui.label(key).rect.width();
+            ui.add_space(max_key_width - used_width);
+        };
+        ui.horizontal(|ui| {
+            add_key_space(ui, t!(locale, "Folder"));
+            ui.add(
+                egui::TextEdit::singleline(&mut rotation.folder)
+                    .id_salt(folder_id_salt)
+                    .hint_text(t!(locale, "No folder")),
+            );
+        });
+        let new_tag_id = egui::Id::new(&new_tag_id_salt);
+        let mut new_tag = ui
+            .ctx()
+            .data(|data| data.get_temp::<String>(new_tag_id))
+            .unwrap_or_default();
+        ui.horizontal_wrapped(|ui| {
+            add_key_space(ui, t!(locale, "Tags"));
+            let mut removed_tag = None;
+            for (index, tag) in rotation.tags.iter().enumerate() {
+                if ui
+                    .small_button(format!("{tag} ✖"))
+                    .on_hover_text(t!(locale, "Remove tag"))
+                    .clicked()
+                {
+                    removed_tag = Some(index);
+                }
+            }
+            if let Some(index) = removed_tag {
+                rotation.tags.remove(index);
+            }
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut new_tag)
+                    .id_salt(new_tag_id_salt)
+                    .hint_text(t!(locale, "Add tag"))
+                    .desired_width(80.0),
+            );
+            if response.lost_focus() && ui.input(|input| input.key_pressed(egui::Key::Enter)) {
+                let tag = new_tag.trim();
+                if !tag.is_empty() && !rotation.tags.iter().any(|saved_tag| saved_tag == tag) {
+                    rotation.tags.push(tag.to_owned());
+                }
+                new_tag.clear();
+            }
+        });
+        ui.ctx()
+            .data_mut(|data| data.insert_temp(new_tag_id, new_tag));
+        ui.horizontal(|ui| {
+            add_key_space(ui, t!(locale, "Notes"));
+            ui.add(
+                egui::TextEdit::multiline(&mut rotation.notes)
+                    .id_salt(notes_id_salt)
+                    .desired_rows(2)
+                    .desired_width(f32::INFINITY),
+            );
+        });
+    }
+
     fn show_rotation_actions(&self, ui: &mut egui::Ui) {
         let job_id = self.get_recipe().map_or(0, |recipe| recipe.job_id);
         egui::ScrollArea::horizontal()
@@ -542,13 +969,7 @@ impl<'a> RotationWidget<'a> {
     }
 
     fn get_recipe(&self) -> Option<&Recipe> {
-        self.rotation
-            .recipe_info
-            .as_ref()
-            .and_then(|recipe_config| match recipe_config {
-                RecipeInfo::NormalRecipe(recipe_id) => raphael_data::RECIPES.get(*recipe_id),
-                RecipeInfo::CustomRecipe(recipe, _) => Some(recipe),
-            })
+        self.rotation.recipe()
     }
 }
 
@@ -562,6 +983,8 @@ impl egui::Widget for RotationWidget<'_> {
                 if !collapsed {
                     ui.separator();
                     self.show_rotation_info(ui);
+                    ui.separator();
+                    self.show_library_info(ui);
                 }
                 ui.separator();
                 self.show_rotation_actions(ui);
@@ -571,6 +994,239 @@ impl egui::Widget for RotationWidget<'_> {
     }
 }
 
+/// State of the import & export section that is kept between frames.
+#[derive(Debug, Clone, Default)]
+struct ImportExportState {
+    export_selected_only: bool,
+    selected_rotations: Vec<u64>,
+    #[cfg(not(target_arch = "wasm32"))]
+    path: String,
+    import_text: String,
+    status: Option<Result<String, String>>,
+    /// Set when a file is dropped onto the window so that the import & export section is opened to show the status.
+    reveal_status: bool,
+}
+
+const IMPORT_EXPORT_STATE_ID: &str = "SAVED_ROTATIONS_IMPORT_EXPORT";
+
+fn load_import_export_state(ctx: &egui::Context) -> ImportExportState {
+    ctx.data(|data| data.get_temp::<ImportExportState>(egui::Id::new(IMPORT_EXPORT_STATE_ID)))
+        .unwrap_or_else(|| ImportExportState {
+            #[cfg(not(target_arch = "wasm32"))]
+            path: String::from("raphael-rotations.json"),
+            ..Default::default()
+        })
+}
+
+/// Imports the files that were dropped onto the window, even if the saved rotations are not shown.
+/// Returns true if any file was dropped.
+pub fn import_dropped_files(
+    ctx: &egui::Context,
+    locale: Locale,
+    config: &SavedRotationsConfig,
+    rotations: &mut SavedRotationsData,
+) -> bool {
+    let dropped_files = ctx.input(|input| input.raw.dropped_files.clone());
+    if dropped_files.is_empty() {
+        return false;
+    }
+    let mut state = load_import_export_state(ctx);
+    for dropped_file in dropped_files {
+        let text = match (&dropped_file.bytes, &dropped_file.path) {
+            (Some(bytes), _) => String::from_utf8(bytes.to_vec()).map_err(|err| err.to_string()),
+            (None, Some(path)) => std::fs::read_to_string(path).map_err(|err| err.to_string()),
+            (None, None) => continue,
+        };
+        state.status =
+            Some(text.and_then(|text| import_rotations(rotations, &text, config, locale)));
+    }
+    state.reveal_status = true;
+    ctx.data_mut(|data| data.insert_temp(egui::Id::new(IMPORT_EXPORT_STATE_ID), state));
+    true
+}
+
+fn import_rotations(
+    rotations: &mut SavedRotationsData,
+    text: &str,
+    config: &SavedRotationsConfig,
+    locale: Locale,
+) -> Result<String, String> {
+    if !text.trim_start().starts_with('{') {
+        let rotation = Rotation::from_code(text)?;
+        let message = match rotations.add_pinned_rotation(rotation) {
+            true => t!(locale, "Added the shared rotation to the saved macros"),
+            false => t!(locale, "The shared rotation is already saved"),
+        };
+        return Ok(message.to_owned());
+    }
+    let file = RotationsFile::from_json(text)?;
+    let summary = rotations.import(file, config);
+    Ok(t_format!(
+        locale,
+        "Added {added} rotations, replaced {replaced}, skipped {skipped}",
+        added = summary.added,
+        replaced = summary.replaced,
+        skipped = summary.skipped,
+    ))
+}
+
+fn show_import_export(
+    ui: &mut egui::Ui,
+    locale: Locale,
+    config: &mut SavedRotationsConfig,
+    rotations: &mut SavedRotationsData,
+) {
+    let id = egui::Id::new(IMPORT_EXPORT_STATE_ID);
+    let mut state = load_import_export_state(ui.ctx());
+    state.reveal_status = false;
+
+    #[cfg(not(target_arch = "wasm32"))]
+    ui.horizontal(|ui| {
+        ui.label(t!(locale, "File"));
+        ui.add(egui::TextEdit::singleline(&mut state.path).desired_width(f32::INFINITY));
+    });
+    ui.label(egui::RichText::new(t!(locale, "Export")).strong());
+    ui.horizontal(|ui| {
+        ui.radio_value(
+            &mut state.export_selected_only,
+            false,
+            t!(locale, "All rotations"),
+        );
+        ui.radio_value(
+            &mut state.export_selected_only,
+            true,
+            t!(locale, "Selected rotations"),
+        );
+    });
+    if state.export_selected_only {
+        egui::ScrollArea::vertical()
+            .id_salt(id.with("selection"))
+            .max_height(120.0)
+            .show(ui, |ui| {
+                for rotation in rotations.rotations() {
+                    let mut selected = state.selected_rotations.contains(&rotation.unique_id);
+                    if ui.checkbox(&mut selected, &rotation.name).changed() {
+                        if selected {
+                            state.selected_rotations.push(rotation.unique_id);
+                        } else {
+                            state
+                                .selected_rotations
+                                .retain(|unique_id| *unique_id != rotation.unique_id);
+                        }
+                    }
+                }
+            });
+    }
+    let export = |state: &ImportExportState| {
+        rotations.export(|rotation| {
+            !state.export_selected_only || state.selected_rotations.contains(&rotation.unique_id)
+        })
+    };
+    ui.horizontal(|ui| {
+        #[cfg(not(target_arch = "wasm32"))]
+        if ui.button(t!(locale, "Save to file")).clicked() {
+            let file = export(&state);
+            state.status = Some(
+                std::fs::write(&state.path, file.to_json())
+                    .map(|()| {
+                        t_format!(
+                            locale,
+                            "Saved {count} rotations to {path}",
+                            count = file.pinned.len() + file.solve_history.len(),
+                            path = state.path
+                        )
+                    })
+                    .map_err(|err| err.to_string()),
+            );
+        }
+        if ui.button(t!(locale, "Copy to clipboard")).clicked() {
+            let file = export(&state);
+            ui.ctx().copy_text(file.to_json());
+            state.status = Some(Ok(t_format!(
+                locale,
+                "Copied {count} rotations to the clipboard",
+                count = file.pinned.len() + file.solve_history.len()
+            )));
+        }
+    });
+
+    ui.separator();
+    ui.label(egui::RichText::new(t!(locale, "Import")).strong());
+    ui.horizontal(|ui| {
+        ui.label(t!(locale, "Rotations with an existing ID:"));
+        egui::ComboBox::from_id_salt(id.with("conflict_resolution"))
+            .selected_text(config.import_conflict_resolution.display_name(locale))
+            .show_ui(ui, |ui| {
+                for conflict_resolution in [
+                    ImportConflictResolution::Skip,
+                    ImportConflictResolution::Replace,
+                    ImportConflictResolution::KeepBoth,
+                ] {
+                    ui.selectable_value(
+                        &mut config.import_conflict_resolution,
+                        conflict_resolution,
+                        conflict_resolution.display_name(locale),
+                    );
+                }
+            });
+    });
+    #[cfg(not(target_arch = "wasm32"))]
+    if ui.button(t!(locale, "Load from file")).clicked() {
+        state.status = Some(
+            std::fs::read_to_string(&state.path)
+                .map_err(|err| err.to_string())
+                .and_then(|text| import_rotations(rotations, &text, config, locale)),
+        );
+    }
+    ui.add(
+        egui::TextEdit::multiline(&mut state.import_text)
+            .hint_text(t!(
+                locale,
+                "Paste an exported rotations file, a rotation code or a rotation link here"
+            ))
+            .desired_rows(3)
+            .desired_width(f32::INFINITY),
+    );
+    if ui
+        .add_enabled(
+            !state.import_text.is_empty(),
+            egui::Button::new(t!(locale, "Import pasted rotations")),
+        )
+        .clicked()
+    {
+        state.status = Some(import_rotations(
+            rotations,
+            &state.import_text,
+            config,
+            locale,
+        ));
+        if matches!(state.status, Some(Ok(_))) {
+            state.import_text.clear();
+        }
+    }
+    ui.label(
+        egui::RichText::new(t!(
+            locale,
+            "Rotations files can also be imported by dropping them onto the window."
+        ))
+        .small(),
+    );
+    match &state.status {
+        Some(Ok(message)) => {
+            ui.label(egui::RichText::new(message).small());
+        }
+        Some(Err(error)) => {
+            ui.label(
+                egui::RichText::new(format!("⚠ {error}"))
+                    .small()
+                    .color(ui.visuals().error_fg_color),
+            );
+        }
+        None => (),
+    }
+    ui.ctx().data_mut(|data| data.insert_temp(id, state));
+}
+
 pub struct SavedRotationsWidget<'a> {
     app_context: &'a mut AppContext,
     actions: &'a mut Vec<Action>,
@@ -594,6 +1250,7 @@ impl egui::Widget for SavedRotationsWidget<'_> {
             selected_potion,
             crafter_config,
             solver_config,
+            app_config,
             saved_rotations_config: config,
             saved_rotations_data: rotations,
             ..
@@ -644,25 +1301,102 @@ impl egui::Widget for SavedRotationsWidget<'_> {
                     );
                 });
             });
+            let reveal_import_status = load_import_export_state(ui.ctx()).reveal_status;
+            egui::CollapsingHeader::new(t!(locale, "Import & export"))
+                .open(reveal_import_status.then_some(true))
+                .show(ui, |ui| {
+                    ui.style_mut().spacing.item_spacing = egui::vec2(8.0, 3.0);
+                    ui.vertical(|ui| {
+                        show_import_export(ui, *locale, config, rotations);
+                    });
+                });
+            ui.collapsing(t!(locale, "Validate rotations"), |ui| {
+                ui.style_mut().spacing.item_spacing = egui::vec2(8.0, 3.0);
+                ui.vertical(|ui| {
+                    show_rotation_validation(
+                        ui,
+                        *locale,
+                        &limited_app_context.crafter_config.crafter_stats,
+                        app_config.num_threads,
+                        rotations,
+                    );
+                });
+            });
             ui.separator();
+            let search_id = egui::Id::new("SAVED_ROTATIONS_SEARCH");
+            let mut search = ui
+                .ctx()
+                .data(|data| data.get_temp::<String>(search_id))
+                .unwrap_or_default();
+            ui.horizontal(|ui| {
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    let grouping_locale = *locale;
+                    ui.add(DropDown::new(
+                        "SAVED_ROTATIONS_GROUPING",
+                        &mut config.library_grouping,
+                        LibraryGrouping::ALL,
+                        move |grouping: LibraryGrouping| grouping.display_name(grouping_locale),
+                    ));
+                    ui.add(
+                        egui::TextEdit::singleline(&mut search)
+                            .hint_text(t!(locale, "🔍 Search item names, tags, folders and notes"))
+                            .desired_width(f32::INFINITY),
+                    );
+                });
+            });
+            ui.ctx()
+                .data_mut(|data| data.insert_temp(search_id, search.clone()));
+            let search = search.trim();
             egui::ScrollArea::vertical().show(ui, |ui| {
                 ui.group(|ui| {
                     ui.label(egui::RichText::new(t!(locale, "Saved macros")).strong());
                     ui.separator();
+                    let mut groups: BTreeMap<(u8, String), Vec<usize>> = BTreeMap::new();
+                    for (index, rotation) in rotations.pinned.iter().enumerate() {
+                        if rotation_matches_search(rotation, search) {
+                            let group = rotation_group(rotation, config.library_grouping, *locale);
+                            groups.entry(group).or_default().push(index);
+                        }
+                    }
                     if rotations.pinned.is_empty() {
                         ui.label(t!(locale, "No saved macros"));
+                    } else if groups.is_empty() {
+                        ui.label(t!(locale, "No matching saved macros"));
                     }
-                    rotations.pinned.retain(|rotation| {
+                    let mut deleted_rotations = Vec::new();
+                    let mut show_rotation = |ui: &mut egui::Ui, index: usize| {
                         let mut deleted = false;
                         ui.add(RotationWidget::new(
                             &mut limited_app_context,
                             &mut true,
                             &mut deleted,
-                            rotation,
+                            &mut rotations.pinned[index],
                             self.actions,
                         ));
-                        !deleted
-                    });
+                        if deleted {
+                            deleted_rotations.push(index);
+                        }
+                    };
+                    for ((_, group_name), indices) in groups {
+                        if config.library_grouping == LibraryGrouping::None {
+                            for index in indices {
+                                show_rotation(ui, index);
+                            }
+                            continue;
+                        }
+                        egui::CollapsingHeader::new(format!("{group_name} ({})", indices.len()))
+                            .id_salt(("SAVED_ROTATIONS_GROUP", &group_name))
+                            .default_open(true)
+                            .show(ui, |ui| {
+                                for index in indices {
+                                    show_rotation(ui, index);
+                                }
+                            });
+                    }
+                    deleted_rotations.sort_unstable();
+                    for index in deleted_rotations.into_iter().rev() {
+                        rotations.pinned.remove(index);
+                    }
                 });
 
                 ui.add_space(5.0);
@@ -698,7 +1432,10 @@ impl egui::Widget for SavedRotationsWidget<'_> {
                     if rotations.solve_history.is_empty() {
                         ui.label(t!(locale, "No solve history"));
                     }
-                    rotations.solve_history.retain(|rotation| {
+                    rotations.solve_history.retain_mut(|rotation| {
+                        if !rotation_matches_search(rotation, search) {
+                            return true;
+                        }
                         let mut pinned = false;
                         let mut deleted = false;
                         ui.add(RotationWidget::new(
@@ -719,3 +1456,264 @@ impl egui::Widget for SavedRotationsWidget<'_> {
         .response
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_rotation() -> Rotation {
+        Rotation {
+            unique_id: 42,
+            name: "Test Item".to_string(),
+            solver: "Raphael v0.0.0".to_string(),
+            actions: vec![
+                Action::MuscleMemory,
+                Action::Veneration,
+                Action::Groundwork,
+                Action::BasicSynthesis,
+            ],
+            recipe_info: Some(RecipeInfo::NormalRecipe(36183)),
+            solve_info: None,
+            food: Some((44091, true)),
+            potion: None,
+            crafter_stats: raphael_data::CrafterStats::default(),
+            folder: "Endgame".to_string(),
+            tags: vec!["expert".to_string()],
+            notes: "Use HQ materials".to_string(),
+            imported: false,
+        }
+    }
+
+    #[test]
+    fn rotation_code_round_trip() {
+        let rotation = test_rotation();
+        let code = rotation.to_code();
+        assert!(code.starts_with("1."));
+        assert!(
+            code.chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_'))
+        );
+        let decoded = Rotation::from_code(&code).unwrap();
+        assert_eq!(decoded, rotation);
+        assert_eq!(decoded.name, rotation.name);
+        assert_eq!(decoded.tags, rotation.tags);
+        assert_eq!(decoded.notes, rotation.notes);
+        assert!(decoded.folder.is_empty());
+        assert!(decoded.imported);
+    }
+
+    #[test]
+    fn rotation_code_from_link() {
+        let rotation = test_rotation();
+        let link = format!("https://example.com/#rotation={}", rotation.to_code());
+        assert_eq!(Rotation::from_code(&link).unwrap(), rotation);
+    }
+
+    #[test]
+    fn find_solved_rotation_skips_imported_rotations() {
+        let settings = Settings {
+            max_cp: 250,
+            max_durability: 60,
+            max_progress: 200,
+            max_quality: 1000,
+            base_progress: 100,
+            base_quality: 100,
+            job_level: 100,
+            allowed_actions: ActionMask::all(),
+            adversarial: false,
+            backload_progress: false,
+            stellar_steady_hand_charges: 0,
+        };
+        let solver_config = SolverConfig::default();
+        let rotation = Rotation {
+            solver: Rotation::solver_description(&solver_config),
+            solve_info: Some(SolveInfo::new(&settings, 0, &solver_config)),
+            ..test_rotation()
+        };
+
+        let mut data = SavedRotationsData::default();
+        data.add_pinned_rotation(Rotation::from_code(&rotation.to_code()).unwrap());
+        assert_eq!(
+            data.find_solved_rotation(&settings, 0, &solver_config),
+            None
+        );
+
+        let mut data = SavedRotationsData::default();
+        data.add_pinned_rotation(rotation.clone());
+        assert_eq!(
+            data.find_solved_rotation(&settings, 0, &solver_config),
+            Some(rotation.actions)
+        );
+    }
+
+    #[test]
+    fn rotation_code_unsupported_version() {
+        let code = test_rotation().to_code();
+        let (_, payload) = code.split_once('.').unwrap();
+        assert!(Rotation::from_code(&format!("2.{payload}")).is_err());
+        assert!(Rotation::from_code("not a rotation code").is_err());
+    }
+
+    #[test]
+    fn rotations_file_fixture() {
+        // The same file is read by the `rotations` command of the CLI.
+        let file =
+            RotationsFile::from_json(include_str!("../../tests/fixtures/rotations.json")).unwrap();
+        assert_eq!(file.version, ROTATIONS_FILE_VERSION);
+        let [pinned] = file.pinned.as_slice() else {
+            panic!("Expected one pinned rotation");
+        };
+        assert_eq!(pinned.unique_id, 42);
+        assert_eq!(pinned.actions, test_rotation().actions);
+        assert_eq!(pinned.recipe_info, Some(RecipeInfo::NormalRecipe(36183)));
+        assert!(pinned.solve_info.is_some());
+        assert_eq!(pinned.folder, "Endgame");
+        assert_eq!(pinned.tags, ["expert"]);
+        // Rotations saved by older versions don't have the optional fields.
+        let [solved] = file.solve_history.as_slice() else {
+            panic!("Expected one rotation in the solve history");
+        };
+        assert_eq!(solved.unique_id, 7);
+        assert_eq!(solved.recipe_info, None);
+        assert_eq!(solved.solve_info, None);
+        assert!(solved.folder.is_empty() && solved.tags.is_empty() && solved.notes.is_empty());
+    }
+
+    fn import_rotation(
+        rotation: Rotation,
+        conflict_resolution: ImportConflictResolution,
+    ) -> (SavedRotationsData, ImportSummary) {
+        let mut data = SavedRotationsData::default();
+        data.add_pinned_rotation(test_rotation());
+        let file = RotationsFile {
+            version: ROTATIONS_FILE_VERSION,
+            pinned: vec![rotation],
+            solve_history: Vec::new(),
+        };
+        let config = SavedRotationsConfig {
+            import_conflict_resolution: conflict_resolution,
+            ..Default::default()
+        };
+        let summary = data.import(file, &config);
+        (data, summary)
+    }
+
+    /// A rotation with the same `unique_id` as [`test_rotation`], but different actions.
+    fn conflicting_rotation() -> Rotation {
+        Rotation {
+            actions: vec![Action::Reflect, Action::BasicSynthesis],
+            ..test_rotation()
+        }
+    }
+
+    #[test]
+    fn import_conflict_skip() {
+        let (data, summary) =
+            import_rotation(conflicting_rotation(), ImportConflictResolution::Skip);
+        assert_eq!(
+            summary,
+            ImportSummary {
+                skipped: 1,
+                ..Default::default()
+            }
+        );
+        let rotations: Vec<&Rotation> = data.rotations().collect();
+        assert_eq!(rotations, [&test_rotation()]);
+    }
+
+    #[test]
+    fn import_conflict_replace() {
+        let (data, summary) =
+            import_rotation(conflicting_rotation(), ImportConflictResolution::Replace);
+        assert_eq!(
+            summary,
+            ImportSummary {
+                replaced: 1,
+                ..Default::default()
+            }
+        );
+        let rotations: Vec<&Rotation> = data.rotations().collect();
+        assert_eq!(rotations, [&conflicting_rotation()]);
+        assert_eq!(rotations[0].unique_id, 42);
+    }
+
+    #[test]
+    fn import_conflict_keep_both() {
+        let (data, summary) =
+            import_rotation(conflicting_rotation(), ImportConflictResolution::KeepBoth);
+        assert_eq!(
+            summary,
+            ImportSummary {
+                added: 1,
+                ..Default::default()
+            }
+        );
+        let rotations: Vec<&Rotation> = data.rotations().collect();
+        assert_eq!(rotations, [&test_rotation(), &conflicting_rotation()]);
+        assert_eq!(rotations[0].unique_id, 42);
+        assert_ne!(rotations[1].unique_id, 42);
+        assert!(!rotations[0].imported);
+        assert!(rotations[1].imported);
+    }
+
+    #[test]
+    fn import_identical_rotation() {
+        // Identical rotations are skipped regardless of the conflict resolution.
+        let (data, summary) = import_rotation(test_rotation(), ImportConflictResolution::KeepBoth);
+        assert_eq!(
+            summary,
+            ImportSummary {
+                skipped: 1,
+                ..Default::default()
+            }
+        );
+        assert_eq!(data.rotations().count(), 1);
+    }
+
+    #[test]
+    fn import_identical_rotation_with_different_id() {
+        let rotation = Rotation {
+            unique_id: 43,
+            ..test_rotation()
+        };
+        let (data, summary) = import_rotation(rotation, ImportConflictResolution::KeepBoth);
+        assert_eq!(
+            summary,
+            ImportSummary {
+                skipped: 1,
+                ..Default::default()
+            }
+        );
+        assert_eq!(data.rotations().count(), 1);
+    }
+
+    #[test]
+    fn import_respects_max_history_size() {
+        let file = RotationsFile {
+            version: ROTATIONS_FILE_VERSION,
+            pinned: Vec::new(),
+            solve_history: (0..3)
+                .map(|unique_id| Rotation {
+                    unique_id,
+                    name: format!("Rotation {unique_id}"),
+                    ..test_rotation()
+                })
+                .collect(),
+        };
+        let config = SavedRotationsConfig {
+            max_history_size: 2,
+            ..Default::default()
+        };
+        let mut data = SavedRotationsData::default();
+        let summary = data.import(file, &config);
+        assert_eq!(
+            summary,
+            ImportSummary {
+                added: 2,
+                skipped: 1,
+                ..Default::default()
+            }
+        );
+        assert_eq!(data.rotations().count(), 2);
+    }
+}
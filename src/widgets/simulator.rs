@@ -1,5 +1,6 @@
+use egui_extras::Column;
 use raphael_data::Locale;
-use raphael_sim::{Action, ActionError, Settings, SimulationState};
+use raphael_sim::{Action, ActionError, Condition, Settings, SimulationState};
 use raphael_translations::{t, t_format};
 
 use crate::{
@@ -18,6 +19,40 @@ pub struct Simulator<'a> {
     item_always_collectable: bool,
     config_changed: bool,
     locale: Locale,
+    /// Condition of each step, used to see how the rotation behaves with other conditions than Normal.
+    conditions: Vec<Condition>,
+    /// The conditions of expert recipes (Centered, Sturdy, ...) are not simulated, so only Normal is available.
+    is_expert: bool,
+}
+
+const CONDITIONS: [Condition; 4] = [
+    Condition::Normal,
+    Condition::Good,
+    Condition::Excellent,
+    Condition::Poor,
+];
+
+fn condition_name(condition: Condition, locale: Locale) -> &'static str {
+    match condition {
+        Condition::Normal => t!(locale, "Normal"),
+        Condition::Good => t!(locale, "Good"),
+        Condition::Excellent => t!(locale, "Excellent"),
+        Condition::Poor => t!(locale, "Poor"),
+    }
+}
+
+fn conditions_id() -> egui::Id {
+    egui::Id::new("SIMULATOR_CONDITIONS")
+}
+
+/// Conditions that were selected for the given actions. Conditions are reset whenever the actions change.
+fn load_conditions(ctx: &egui::Context, actions: &[Action]) -> Vec<Condition> {
+    ctx.data(
+        |data| match data.get_temp::<(Vec<Action>, Vec<Condition>)>(conditions_id()) {
+            Some((saved_actions, conditions)) if saved_actions == actions => conditions,
+            _ => vec![Condition::Normal; actions.len()],
+        },
+    )
 }
 
 fn config_changed(
@@ -49,11 +84,16 @@ impl<'a> Simulator<'a> {
         } = app_context;
         let settings = app_context.game_settings();
         let initial_quality = app_context.initial_quality();
-        let item_always_collectable = raphael_data::ITEMS
-            .get(recipe_config.recipe().item_id)
+        let item_always_collectable = raphael_data::game_data()
+            .item(recipe_config.recipe().item_id)
             .map(|item| item.always_collectable)
             .unwrap_or_default();
         let config_changed = config_changed(&settings, initial_quality, solver_config, ctx);
+        let is_expert = recipe_config.recipe().is_expert;
+        let conditions = match is_expert {
+            true => vec![Condition::Normal; actions.len()],
+            false => load_conditions(ctx, actions),
+        };
         Self {
             settings,
             initial_quality,
@@ -62,11 +102,19 @@ impl<'a> Simulator<'a> {
             item_always_collectable,
             config_changed,
             locale: *locale,
+            conditions,
+            is_expert,
         }
     }
 }
 
 impl Simulator<'_> {
+    fn what_if(&self) -> bool {
+        self.conditions
+            .iter()
+            .any(|condition| *condition != Condition::Normal)
+    }
+
     fn draw_simulation(&self, ui: &mut egui::Ui, state: &SimulationState) {
         let locale = self.locale;
         ui.group(|ui| {
@@ -169,14 +217,18 @@ impl Simulator<'_> {
                 ui.horizontal(|ui| {
                     ui.with_layout(text_layout, |ui| {
                         ui.set_height(ui.style().spacing.interact_size.y);
-                        ui.add(HelpText::new(match self.settings.adversarial {
-                            true => t!(
+                        ui.add(HelpText::new(if self.what_if() {
+                            t!(
+                                locale,
+                                "Calculated with the conditions selected in the timeline"
+                            )
+                        } else if self.settings.adversarial {
+                            t!(
                                 locale,
                                 "Calculated assuming worst possible sequence of conditions"
-                            ),
-                            false => {
-                                t!(locale, "Calculated assuming Normal conditon on every step")
-                            }
+                            )
+                        } else {
+                            t!(locale, "Calculated assuming Normal conditon on every step")
                         }));
                         if !state.is_final(&self.settings) {
                             // do nothing
@@ -268,18 +320,207 @@ impl Simulator<'_> {
             });
         });
     }
+
+    fn draw_timeline(
+        &mut self,
+        ui: &mut egui::Ui,
+        states: &[SimulationState],
+        errors: &[Result<(), ActionError>],
+    ) {
+        let locale = self.locale;
+        ui.group(|ui| {
+            ui.set_width(ui.available_width());
+            egui::CollapsingHeader::new(egui::RichText::new(t!(locale, "Timeline")).strong())
+                .id_salt("SIMULATOR_TIMELINE")
+                .show(ui, |ui| {
+                    if self.actions.is_empty() {
+                        ui.label(t!(locale, "No actions"));
+                        return;
+                    }
+                    if self.is_expert {
+                        ui.label(t!(
+                            locale,
+                            "Normal conditions only: the conditions of expert recipes cannot be selected."
+                        ));
+                    } else {
+                        ui.horizontal(|ui| {
+                            ui.label(t!(
+                                locale,
+                                "Select the condition of each step to see how the rotation behaves with other conditions."
+                            ));
+                            if ui
+                                .add_enabled(
+                                    self.what_if(),
+                                    egui::Button::new(t!(locale, "Reset conditions")),
+                                )
+                                .clicked()
+                            {
+                                self.conditions.fill(Condition::Normal);
+                            }
+                        });
+                    }
+                    let header_labels = [
+                        ("#", t!(locale, "Step")),
+                        (t!(locale, "Action"), t!(locale, "Action")),
+                        (t!(locale, "Condition"), t!(locale, "Condition")),
+                        (t!(locale, "Progress"), t!(locale, "Progress")),
+                        (t!(locale, "Quality"), t!(locale, "Quality")),
+                        (t!(locale, "Durability"), t!(locale, "Durability")),
+                        (t!(locale, "CP"), t!(locale, "CP")),
+                        (t!(locale, "IQ"), t!(locale, "Inner Quiet stacks")),
+                        (t!(locale, "WN"), t!(locale, "Waste Not")),
+                        (t!(locale, "Inno"), t!(locale, "Innovation")),
+                        (t!(locale, "Ven"), t!(locale, "Veneration")),
+                        (t!(locale, "GS"), t!(locale, "Great Strides")),
+                        (t!(locale, "Manip"), t!(locale, "Manipulation")),
+                        (t!(locale, "MM"), t!(locale, "Muscle Memory")),
+                    ];
+                    let line_height = ui.spacing().interact_size.y;
+                    egui::ScrollArea::horizontal().show(ui, |ui| {
+                        egui_extras::TableBuilder::new(ui)
+                            .id_salt("SIMULATOR_TIMELINE_TABLE")
+                            .striped(true)
+                            .vscroll(false)
+                            .columns(Column::auto(), header_labels.len())
+                            .header(line_height, |mut header| {
+                                for (label, hover_text) in header_labels {
+                                    header.col(|ui| {
+                                        ui.strong(label).on_hover_text(hover_text);
+                                    });
+                                }
+                            })
+                            .body(|body| {
+                                body.rows(line_height, states.len(), |mut row| {
+                                    let step_index = row.index();
+                                    self.draw_timeline_row(
+                                        &mut row,
+                                        step_index,
+                                        &states[step_index],
+                                        &errors[step_index],
+                                    );
+                                });
+                            });
+                    });
+                });
+        });
+    }
+
+    fn draw_timeline_row(
+        &mut self,
+        row: &mut egui_extras::TableRow,
+        step_index: usize,
+        state: &SimulationState,
+        error: &Result<(), ActionError>,
+    ) {
+        let locale = self.locale;
+        row.col(|ui| {
+            ui.label((step_index + 1).to_string());
+        });
+        row.col(|ui| {
+            let action = self.actions[step_index];
+            ui.add(
+                util::get_action_icon(action, self.job_id)
+                    .fit_to_exact_size(egui::Vec2::new(18.0, 18.0))
+                    .corner_radius(2.0),
+            );
+            let name = raphael_data::action_name(action, locale);
+            match error {
+                Ok(()) => ui.label(name),
+                Err(_) => ui
+                    .label(
+                        egui::RichText::new(name)
+                            .strikethrough()
+                            .color(ui.visuals().warn_fg_color),
+                    )
+                    .on_hover_text(t!(locale, "The action cannot be used and is skipped")),
+            };
+        });
+        row.col(|ui| {
+            let condition = &mut self.conditions[step_index];
+            ui.add_enabled_ui(!self.is_expert, |ui| {
+                egui::ComboBox::from_id_salt(("SIMULATOR_CONDITION", step_index))
+                    .selected_text(condition_name(*condition, locale))
+                    .show_ui(ui, |ui| {
+                        for option in CONDITIONS {
+                            ui.selectable_value(condition, option, condition_name(option, locale));
+                        }
+                    });
+            });
+        });
+        let quality = self.initial_quality.saturating_add(state.quality);
+        for value in [state.progress, quality, state.durability, state.cp] {
+            row.col(|ui| {
+                ui.label(value.to_string());
+            });
+        }
+        let effects = state.effects;
+        for value in [
+            effects.inner_quiet(),
+            effects.waste_not(),
+            effects.innovation(),
+            effects.veneration(),
+            effects.great_strides(),
+            effects.manipulation(),
+            effects.muscle_memory(),
+        ] {
+            row.col(|ui| {
+                if value != 0 {
+                    ui.label(value.to_string());
+                }
+            });
+        }
+    }
 }
 
 impl egui::Widget for Simulator<'_> {
-    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
-        let (state, errors) =
-            SimulationState::from_macro_continue_on_error(&self.settings, self.actions);
-        ui.vertical(|ui| {
-            self.draw_simulation(ui, &state);
-            self.draw_actions(ui, &errors);
-        })
-        .response
+    fn ui(mut self, ui: &mut egui::Ui) -> egui::Response {
+        let mut settings = self.settings;
+        // Selected conditions show what happens in-game, which is not what the adversarial simulator calculates.
+        settings.adversarial &= !self.what_if();
+        let (states, errors) = simulate_steps(&settings, self.actions, &self.conditions);
+        let state = states
+            .last()
+            .copied()
+            .unwrap_or_else(|| SimulationState::new(&settings));
+        let conditions = self.conditions.clone();
+        let response = ui
+            .vertical(|ui| {
+                self.draw_simulation(ui, &state);
+                self.draw_actions(ui, &errors);
+                self.draw_timeline(ui, &states, &errors);
+            })
+            .response;
+        if self.conditions != conditions {
+            ui.ctx().request_repaint();
+        }
+        ui.ctx().data_mut(|data| {
+            data.insert_temp(conditions_id(), (self.actions.to_vec(), self.conditions));
+        });
+        response
+    }
+}
+
+/// State after each action, using the condition of the respective step.
+/// Actions that cannot be used leave the state unchanged.
+pub fn simulate_steps(
+    settings: &Settings,
+    actions: &[Action],
+    conditions: &[Condition],
+) -> (Vec<SimulationState>, Vec<Result<(), ActionError>>) {
+    let mut state = SimulationState::new(settings);
+    let mut states = Vec::with_capacity(actions.len());
+    let mut errors = Vec::with_capacity(actions.len());
+    for (action, condition) in actions.iter().zip(conditions) {
+        match state.use_action(*action, *condition, settings) {
+            Ok(new_state) => {
+                state = new_state;
+                errors.push(Ok(()));
+            }
+            Err(error) => errors.push(Err(error)),
+        }
+        states.push(state);
     }
+    (states, errors)
 }
 
 fn progress_bar_text<T: Copy + std::cmp::Ord + std::ops::Sub<Output = T> + std::fmt::Display>(
@@ -1,17 +1,21 @@
 use egui::Widget;
-use raphael_data::{Locale, action_name, get_job_name};
+use raphael_data::{Gearset, Locale, action_name, get_job_name, has_gear_data};
 use raphael_sim::Action;
 use raphael_translations::t;
 
 use crate::{
     config::CrafterConfig,
     context::AppContext,
-    widgets::util::{add_sized_labeled_widget, text_width},
+    widgets::{
+        GearsetEdit,
+        util::{add_sized_labeled_widget, text_width},
+    },
 };
 
 pub struct StatsEdit<'a> {
     locale: Locale,
     crafter_config: &'a mut CrafterConfig,
+    gearsets: &'a mut [Option<Gearset>; 8],
 }
 
 impl<'a> StatsEdit<'a> {
@@ -19,6 +23,7 @@ impl<'a> StatsEdit<'a> {
         Self {
             locale: app_context.locale,
             crafter_config: &mut app_context.crafter_config,
+            gearsets: &mut app_context.gearsets,
         }
     }
 }
@@ -82,6 +87,14 @@ impl Widget for StatsEdit<'_> {
                                 action_name(Action::QuickInnovation, locale),
                             );
                         });
+                        if has_gear_data() {
+                            ui.add(GearsetEdit::new(
+                                locale,
+                                job_id,
+                                &mut self.gearsets[job_id as usize],
+                                stats,
+                            ));
+                        }
                     }
 
                     ui.separator();